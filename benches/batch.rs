@@ -0,0 +1,59 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use primitives::foundation::colorspace::{
+    batch::{convert_slice, ColorPlanes},
+    Color, HslColor, HsvColor,
+};
+
+const PIXELS: usize = 64 * 1024;
+
+fn frame() -> Vec<Color> {
+    (0..PIXELS)
+        .map(|idx| Color::rgb(idx as u8, (idx >> 8) as u8, (idx * 7) as u8))
+        .collect()
+}
+
+fn to_hsv(c: &mut Criterion) {
+    let colors = frame();
+    let planes = ColorPlanes::from_colors(&colors);
+    let mut hsv = vec![HsvColor::new(0.0, 0.0, 0.0); PIXELS];
+
+    let mut group = c.benchmark_group("rgb_to_hsv");
+    group.bench_function("scalar_from", |b| {
+        b.iter(|| {
+            for (dst, src) in hsv.iter_mut().zip(&colors) {
+                *dst = HsvColor::from(*src);
+            }
+            black_box(&hsv);
+        })
+    });
+    group.bench_function("convert_slice", |b| {
+        b.iter(|| convert_slice(black_box(&colors), &mut hsv))
+    });
+    group.bench_function("planes", |b| b.iter(|| black_box(planes.to_hsv())));
+    group.bench_function("planes_with_split", |b| {
+        b.iter(|| black_box(ColorPlanes::from_colors(black_box(&colors)).to_hsv()))
+    });
+    group.finish();
+}
+
+fn hsl_roundtrip(c: &mut Criterion) {
+    let colors = frame();
+    let planes = ColorPlanes::from_colors(&colors);
+    let mut result = vec![Color::default(); PIXELS];
+
+    let mut group = c.benchmark_group("hsl_roundtrip");
+    group.bench_function("scalar_from", |b| {
+        b.iter(|| {
+            for (dst, src) in result.iter_mut().zip(&colors) {
+                *dst = HslColor::from(*src).into();
+            }
+            black_box(&result);
+        })
+    });
+    group.bench_function("planes", |b| b.iter(|| black_box(planes.to_hsl().to_rgb())));
+    group.finish();
+}
+
+criterion_group!(benches, to_hsv, hsl_roundtrip);
+criterion_main!(benches);
@@ -0,0 +1,234 @@
+use std::fmt::Write;
+
+use super::{
+    colorspace::{Color, OklabColor},
+    ImageData,
+};
+
+/// Escape sequence which resets terminal colors and attributes
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// Default colors of xterm for the 16 basic indices
+const XTERM_16: [u32; 16] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+/// Channel levels of the 6x6x6 color cube of 256-color terminals
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Color capabilities of terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnsiMode {
+    /// Basic 16 colors, supported everywhere
+    Ansi16,
+    /// xterm 256 colors
+    Ansi256,
+    /// 24-bit colors
+    #[default]
+    TrueColor,
+}
+
+/// Retrieve color of xterm palette index
+pub fn ansi_palette_color(index: u8) -> Color {
+    match index {
+        0..=15 => {
+            let value = XTERM_16[index as usize];
+            Color::rgb((value >> 16) as u8, (value >> 8) as u8, value as u8)
+        }
+        16..=231 => {
+            let cube = index - 16;
+            Color::rgb(
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            Color::rgb(level, level, level)
+        }
+    }
+}
+
+/// Find index with perceptually nearest color
+fn nearest(color: Color, indices: std::ops::RangeInclusive<u8>) -> u8 {
+    let lab = OklabColor::from(color);
+    indices
+        .map(|index| {
+            (
+                index,
+                lab.delta_e(&OklabColor::from(ansi_palette_color(index))),
+            )
+        })
+        .fold(
+            (0, f32::MAX),
+            |best, item| if item.1 < best.1 { item } else { best },
+        )
+        .0
+}
+
+impl Color {
+    /// Find nearest of 16 basic terminal colors, by perceptual distance to the xterm defaults
+    pub fn to_ansi16(&self) -> u8 {
+        nearest(*self, 0..=15)
+    }
+
+    /// Find nearest of 256 terminal colors.
+    ///
+    /// Only the color cube and the gray ramp are used, as basic 16 colors depend on terminal theme.
+    pub fn to_ansi256(&self) -> u8 {
+        nearest(*self, 16..=255)
+    }
+
+    /// Retrieve escape sequence which sets foreground color
+    pub fn ansi_fg(&self, mode: AnsiMode) -> String {
+        self.ansi_sequence(mode, 38)
+    }
+
+    /// Retrieve escape sequence which sets background color
+    pub fn ansi_bg(&self, mode: AnsiMode) -> String {
+        self.ansi_sequence(mode, 48)
+    }
+
+    fn ansi_sequence(&self, mode: AnsiMode, base: u8) -> String {
+        match mode {
+            AnsiMode::Ansi16 => {
+                let index = self.to_ansi16();
+                // 30-37 and 90-97 for foreground, 40-47 and 100-107 for background
+                let code = if index < 8 {
+                    base - 8 + index
+                } else {
+                    base + 52 + index - 8
+                };
+                format!("\x1b[{}m", code)
+            }
+            AnsiMode::Ansi256 => format!("\x1b[{};5;{}m", base, self.to_ansi256()),
+            AnsiMode::TrueColor => {
+                let [red, green, blue, _] = self.cast::<u8>().to_array();
+                format!("\x1b[{};2;{};{};{}m", base, red, green, blue)
+            }
+        }
+    }
+}
+
+/// Render pixels with half-block characters, every character shows two pixels stacked vertically.
+///
+/// Pixels with alpha below half keep the default terminal color.
+pub fn render_half_blocks<F>(width: u32, height: u32, mode: AnsiMode, pixel: F) -> String
+where
+    F: Fn(u32, u32) -> Color,
+{
+    let mut out = String::new();
+    for y in (0..height).step_by(2) {
+        for x in 0..width {
+            let top = pixel(x, y);
+            let bottom = if y + 1 < height {
+                pixel(x, y + 1)
+            } else {
+                Color::new(0.0, 0.0, 0.0, 0.0)
+            };
+
+            match (top.alpha >= 0.5, bottom.alpha >= 0.5) {
+                (true, true) => {
+                    let _ = write!(out, "{}{}\u{2580}", top.ansi_fg(mode), bottom.ansi_bg(mode));
+                }
+                (true, false) => {
+                    let _ = write!(out, "{}\x1b[49m\u{2580}", top.ansi_fg(mode));
+                }
+                (false, true) => {
+                    let _ = write!(out, "{}\x1b[49m\u{2584}", bottom.ansi_fg(mode));
+                }
+                (false, false) => {
+                    let _ = write!(out, "\x1b[39;49m ");
+                }
+            }
+        }
+        out.push_str(ANSI_RESET);
+        out.push('\n');
+    }
+    out
+}
+
+/// Render palette as row of swatches, each color takes `swatch` characters
+pub fn render_palette(colors: &[Color], swatch: u32, mode: AnsiMode) -> String {
+    let swatch = swatch.max(1);
+    render_half_blocks(colors.len() as u32 * swatch, 2, mode, |x, _| {
+        colors[(x / swatch) as usize]
+    })
+}
+
+/// Render image thumbnail at most `columns` characters wide, keeping aspect ratio
+pub fn render_image(image: &ImageData, columns: u32, mode: AnsiMode) -> String {
+    if image.width == 0 || image.height == 0 || columns == 0 {
+        return String::new();
+    }
+
+    let width = columns.min(image.width);
+    let height = ((image.height as u64 * width as u64 / image.width as u64) as u32).max(1);
+
+    render_half_blocks(width, height, mode, |x, y| {
+        let source_x = x * image.width / width;
+        let source_y = y * image.height / height;
+        image
+            .try_get_pixel(source_x, source_y)
+            .unwrap_or_else(|| Color::new(0.0, 0.0, 0.0, 0.0))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::PixelFormat;
+    use bytes::Bytes;
+
+    #[test]
+    fn nearest_indices() {
+        assert_eq!(Color::rgb(255, 0, 0).to_ansi16(), 9);
+        assert_eq!(Color::rgb(200, 10, 10).to_ansi16(), 1);
+        assert_eq!(Color::rgb(0, 0, 0).to_ansi256(), 16);
+        assert_eq!(Color::rgb(255, 255, 255).to_ansi256(), 231);
+        assert_eq!(Color::rgb(95, 135, 175).to_ansi256(), 67);
+        assert_eq!(Color::rgb(128, 128, 128).to_ansi256(), 244);
+
+        for index in 16..=255u8 {
+            assert_eq!(ansi_palette_color(index).to_ansi256(), index);
+        }
+    }
+
+    #[test]
+    fn escape_sequences() {
+        let color = Color::rgb(18, 184, 134);
+        assert_eq!(color.ansi_fg(AnsiMode::TrueColor), "\x1b[38;2;18;184;134m");
+        assert_eq!(color.ansi_bg(AnsiMode::Ansi256), "\x1b[48;5;36m");
+        assert_eq!(Color::rgb(205, 0, 0).ansi_fg(AnsiMode::Ansi16), "\x1b[31m");
+        assert_eq!(
+            Color::rgb(255, 255, 255).ansi_bg(AnsiMode::Ansi16),
+            "\x1b[107m"
+        );
+    }
+
+    #[test]
+    fn render() {
+        let palette = render_palette(
+            &[Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)],
+            1,
+            AnsiMode::TrueColor,
+        );
+        assert_eq!(
+            palette,
+            "\x1b[38;2;255;0;0m\x1b[48;2;255;0;0m\u{2580}\x1b[38;2;0;0;255m\x1b[48;2;0;0;255m\u{2580}\x1b[0m\n"
+        );
+
+        // 2x3 image, the last row has only top halves
+        let mut data = Vec::new();
+        for _ in 0..6 {
+            data.extend_from_slice(&0xff00_ff00u32.to_ne_bytes());
+        }
+        let image = ImageData::new(PixelFormat::ARgb32, 2, 3, Bytes::from(data));
+        let text = render_image(&image, 10, AnsiMode::Ansi256);
+        assert_eq!(text.lines().count(), 2);
+        assert_eq!(text.matches('\u{2580}').count(), 4);
+        assert!(text.contains("\x1b[38;5;46m\x1b[49m\u{2580}"));
+    }
+}
@@ -1,5 +1,8 @@
 use super::Point;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// A 2d axis aligned rectangle represented by its minimum and maximum coordinates.
 ///
 /// # Representation
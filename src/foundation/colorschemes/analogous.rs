@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 use crate::foundation::colorspace::{Color, HsvColor};
 
-use super::ryb_rotate;
+use super::{HueWheel, ColorScheme, SchemeKind};
 
 /// The analog colors are those colors which lie on either side of any given color. 
 ///
@@ -13,6 +13,7 @@ pub struct Analogous {
     angle: f32,
     contrast: f32,
 
+    wheel: HueWheel,
     colors: Vec<Color>,
     primary_color: Color,
 }
@@ -28,6 +29,7 @@ impl Analogous {
         let mut instance = Self {
             colors: Vec::new(),
             primary_color: primary,
+            wheel: HueWheel::default(),
             angle: angle.unwrap_or(10.0),
             contrast: contrast.unwrap_or(25.0),
         };
@@ -47,7 +49,7 @@ impl Analogous {
             let two = ARRAY[idx][1];
 
             // set hue
-            let mut new_hsb: HsvColor = ryb_rotate(primary_hsb.into(), self.angle * one);
+            let mut new_hsb: HsvColor = self.wheel.rotate(primary_hsb.into(), self.angle * one);
 
             // value is brightness
             let t: f32 = 0.44 - two * 0.1;
@@ -86,6 +88,17 @@ impl Analogous {
         self.generate();
     }
 
+    /// Retrieve hue wheel of scheme
+    pub fn wheel(&self) -> &HueWheel {
+        &self.wheel
+    }
+
+    /// Set the hue wheel of scheme
+    pub fn set_wheel(&mut self, value: HueWheel) {
+        self.wheel = value;
+        self.generate();
+    }
+
     /// Retrieve count colors of scheme
     pub fn num_of_colors(&self) -> usize {
         self.colors.len()
@@ -107,3 +120,21 @@ impl Analogous {
         self.generate();
     }
 }
+
+impl ColorScheme for Analogous {
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::Analogous
+    }
+
+    fn primary_color(&self) -> Color {
+        Analogous::primary_color(self)
+    }
+
+    fn set_primary_color(&mut self, value: Color) {
+        Analogous::set_primary_color(self, value)
+    }
+
+    fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 use crate::foundation::colorspace::{Color, HsvColor};
 
-use super::ryb_rotate;
+use super::{HueWheel, ColorScheme, SchemeKind};
 
 /// The complementary colors are the colors which are directly opposite from one another on the color wheel. 
 ///
@@ -9,6 +9,7 @@ use super::ryb_rotate;
 /// Often it is a good idea to use a complementary color as the highlight color, as described above.
 #[derive(Debug, Clone)]
 pub struct Complementary {
+    wheel: HueWheel,
     colors: Vec<Color>,
     primary_color: Color,
 }
@@ -19,6 +20,7 @@ impl Complementary {
         let mut instance = Self {
             colors: Vec::new(),
             primary_color: primary,
+            wheel: HueWheel::default(),
         };
         instance.generate();
 
@@ -48,7 +50,7 @@ impl Complementary {
         self.colors.push(supporting.into());
 
         // complement
-        let complement: HsvColor = ryb_rotate(self.primary_color, 180.0);
+        let complement: HsvColor = self.wheel.rotate(self.primary_color, 180.0);
         self.colors.push(complement.into());
 
         // contrasting complement
@@ -69,6 +71,17 @@ impl Complementary {
         self.colors.push(supporting_complement.into());
     }
 
+    /// Retrieve hue wheel of scheme
+    pub fn wheel(&self) -> &HueWheel {
+        &self.wheel
+    }
+
+    /// Set the hue wheel of scheme
+    pub fn set_wheel(&mut self, value: HueWheel) {
+        self.wheel = value;
+        self.generate();
+    }
+
     /// Retrieve count colors of scheme
     pub fn num_of_colors(&self) -> usize {
         self.colors.len()
@@ -90,3 +103,21 @@ impl Complementary {
         self.generate();
     }
 }
+
+impl ColorScheme for Complementary {
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::Complementary
+    }
+
+    fn primary_color(&self) -> Color {
+        Complementary::primary_color(self)
+    }
+
+    fn set_primary_color(&mut self, val: Color) {
+        Complementary::set_primary_color(self, val)
+    }
+
+    fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
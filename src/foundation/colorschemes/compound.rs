@@ -1,12 +1,14 @@
 #![allow(unused_imports)]
 use crate::foundation::colorspace::{Color, HsvColor};
 
-use super::{ryb_rotate, wrap};
+use super::{HueWheel, wrap, ColorScheme, SchemeKind};
 
 /// Compound schemes (aka Split Complementary) are almost the same as complementary schemes.
 ///
 /// Instead of using colors that are opposites, it uses colors on both sides of the opposite hue
+#[derive(Debug, Clone)]
 pub struct Compound {
+    wheel: HueWheel,
     colors: Vec<Color>,
     primary_color: Color,
 }
@@ -17,6 +19,7 @@ impl Compound {
         let mut instance = Self {
             colors: Vec::new(),
             primary_color: primary,
+            wheel: HueWheel::default(),
         };
 
         instance.generate();
@@ -29,31 +32,42 @@ impl Compound {
         let primary_hsb: HsvColor = self.primary_color.into();
 
         // value is brightness
-        let mut c1: HsvColor = ryb_rotate(self.primary_color, 30.0);
+        let mut c1: HsvColor = self.wheel.rotate(self.primary_color, 30.0);
         c1.value = wrap(primary_hsb.value, 25.0, 60.0, 25.0);
         self.colors.push(c1.into());
 
-        let mut c2: HsvColor = ryb_rotate(self.primary_color, 30.0);
+        let mut c2: HsvColor = self.wheel.rotate(self.primary_color, 30.0);
         c2.value = wrap(primary_hsb.value, 40.0, 10.0, 40.0);
         c2.saturation = wrap(primary_hsb.saturation, 40.0, 20.0, 40.0);
         self.colors.push(c2.into());
 
-        let mut c3: HsvColor = ryb_rotate(self.primary_color, 160.0);
+        let mut c3: HsvColor = self.wheel.rotate(self.primary_color, 160.0);
         c3.value = primary_hsb.value.max(20.0);
         c3.saturation = wrap(primary_hsb.saturation, 25.0, 10.0, 25.0);
         self.colors.push(c3.into());
 
-        let mut c4: HsvColor = ryb_rotate(self.primary_color, 150.0);
+        let mut c4: HsvColor = self.wheel.rotate(self.primary_color, 150.0);
         c4.value = wrap(primary_hsb.value, 30.0, 60.0, 30.0);
         c4.saturation = wrap(primary_hsb.saturation, 10.0, 80.0, 10.0);
         self.colors.push(c4.into());
 
-        let mut c5: HsvColor = ryb_rotate(self.primary_color, 150.0);
+        let mut c5: HsvColor = self.wheel.rotate(self.primary_color, 150.0);
         c5.value = wrap(primary_hsb.value, 40.0, 20.0, 40.0);
         c5.saturation = wrap(primary_hsb.saturation, 10.0, 80.0, 10.0);
         self.colors.push(c5.into());
     }
 
+    /// Retrieve hue wheel of scheme
+    pub fn wheel(&self) -> &HueWheel {
+        &self.wheel
+    }
+
+    /// Set the hue wheel of scheme
+    pub fn set_wheel(&mut self, value: HueWheel) {
+        self.wheel = value;
+        self.generate();
+    }
+
     /// Retrieve count colors of scheme
     pub fn num_of_colors(&self) -> usize {
         self.colors.len()
@@ -75,3 +89,21 @@ impl Compound {
         self.generate();
     }
 }
+
+impl ColorScheme for Compound {
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::Compound
+    }
+
+    fn primary_color(&self) -> Color {
+        Compound::primary_color(self)
+    }
+
+    fn set_primary_color(&mut self, val: Color) {
+        Compound::set_primary_color(self, val)
+    }
+
+    fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
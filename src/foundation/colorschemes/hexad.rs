@@ -0,0 +1,13 @@
+use crate::foundation::colorspace::Color;
+
+use super::{ColorScheme, HueWheel, SchemeKind};
+
+rotated_scheme!(
+    /// A hexad (six-tone) scheme uses six hues spaced evenly around the color wheel.
+    ///
+    /// It is the most colorful of the harmonies, so it is usually toned down
+    /// with contrast or used for charts and categorical data.
+    Hexad,
+    60.0,
+    |angle| (1..6).map(|idx| angle * idx as f32).collect()
+);
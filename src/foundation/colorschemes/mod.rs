@@ -4,6 +4,9 @@
 
 use crate::foundation::colorspace::{Color, HsvColor};
 
+#[macro_use]
+mod rotated;
+
 mod analogous;
 pub use self::analogous::*;
 
@@ -16,105 +19,45 @@ pub use self::compound::*;
 mod flipped_compound;
 pub use self::flipped_compound::*;
 
+mod hexad;
+pub use self::hexad::*;
+
 mod monochrome;
 pub use self::monochrome::*;
 
+mod random;
+pub use self::random::*;
+
+mod rectangle;
+pub use self::rectangle::*;
+
+mod scheme;
+pub use self::scheme::*;
+
+mod scale;
+pub use self::scale::*;
+
+mod shades;
+pub use self::shades::*;
+
 mod split_complementary;
 pub use self::split_complementary::*;
 
+mod square;
+pub use self::square::*;
+
 mod tetrad;
 pub use self::tetrad::*;
 
 mod triad;
 pub use self::triad::*;
 
+mod wheel;
+pub use self::wheel::*;
+
 /// Alias for `HsvColor` where `b` is for "brightness".
 pub type Hsb = HsvColor;
 
-fn ryb_rotate(color: Color, angle: f32) -> HsvColor {
-    const RYB_WHEEL: [[u16; 2]; 25] = [
-        [0, 0],
-        [15, 8],
-        [30, 17],
-        [45, 26],
-        [60, 34],
-        [75, 41],
-        [90, 48],
-        [105, 54],
-        [120, 60],
-        [135, 81],
-        [150, 103],
-        [165, 123],
-        [180, 138],
-        [195, 155],
-        [210, 171],
-        [225, 187],
-        [240, 204],
-        [255, 219],
-        [270, 234],
-        [285, 251],
-        [300, 267],
-        [315, 282],
-        [330, 298],
-        [345, 329],
-        [360, 0],
-    ];
-
-    let mut hsb: HsvColor = color.into();
-
-    let mut a: f32 = 0.0;
-    for idx in 0..RYB_WHEEL.len() {
-        let x0 = RYB_WHEEL[idx][0] as f32;
-        let y0 = RYB_WHEEL[idx][1] as f32;
-
-        let x1 = RYB_WHEEL[idx + 1][0] as f32;
-        let mut y1 = RYB_WHEEL[idx + 1][1] as f32;
-
-        if y1 < y0 {
-            y1 += 360.0;
-        }
-
-        if y0 <= hsb.hue && hsb.hue <= y1 {
-            a = 1.0 * x0 + (x1 - x0) * (hsb.hue - y0) / (y1 - y0);
-            break;
-        }
-    }
-
-    a += angle % 360.0;
-
-    if a < 0.0 {
-        a += 360.0;
-    }
-
-    if a > 360.0 {
-        a -= 360.0;
-    }
-
-    a %= 360.0;
-
-    let mut new_hue: f32 = 0.0;
-    for idx in 0..RYB_WHEEL.len() {
-        let xx0 = RYB_WHEEL[idx][0] as f32;
-        let yy0 = RYB_WHEEL[idx][1] as f32;
-
-        let xx1 = RYB_WHEEL[idx + 1][0] as f32;
-        let mut yy1 = RYB_WHEEL[idx + 1][1] as f32;
-
-        if yy1 < yy0 {
-            yy1 += 360.0;
-        }
-
-        if xx0 <= a && a <= xx1 {
-            new_hue = yy0 + (yy1 - yy0) * (a - xx0) / (xx1 - xx0);
-            break;
-        }
-    }
-
-    hsb.hue = new_hue;
-
-    hsb
-}
-
 #[inline]
 fn wrap(x: f32, min: f32, threshold: f32, plus: f32) -> f32 {
     if x - min < threshold {
@@ -123,3 +66,16 @@ fn wrap(x: f32, min: f32, threshold: f32, plus: f32) -> f32 {
         x - min
     }
 }
+
+/// Rotate primary color by every angle, every other color is darker by contrast percent
+fn rotate_hues(wheel: &HueWheel, primary: Color, angles: &[f32], contrast: f32) -> Vec<Color> {
+    let mut colors = vec![primary];
+    for (idx, angle) in angles.iter().enumerate() {
+        let mut hsb: HsvColor = wheel.rotate(primary, *angle);
+        if idx % 2 == 0 {
+            hsb.value = (hsb.value - contrast).clamp(0.0, 100.0);
+        }
+        colors.push(hsb.into());
+    }
+    colors
+}
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 use crate::foundation::colorspace::{Color, HsvColor};
 
-use super::{ryb_rotate, wrap};
+use super::{wrap, ColorScheme, SchemeKind};
 
 /// A monotone color scheme is just one single hue and its variations in terms of tints, shades and saturation. 
 ///
@@ -72,3 +72,21 @@ impl Monochrome {
         self.generate();
     }
 }
+
+impl ColorScheme for Monochrome {
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::Monochrome
+    }
+
+    fn primary_color(&self) -> Color {
+        Monochrome::primary_color(self)
+    }
+
+    fn set_primary_color(&mut self, val: Color) {
+        Monochrome::set_primary_color(self, val)
+    }
+
+    fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
@@ -0,0 +1,333 @@
+use crate::foundation::colorspace::{Color, HslColor, OklchColor};
+
+/// Golden ratio conjugate, spreads consecutive hues as far as possible
+const GOLDEN_RATIO: f32 = 0.618_034;
+
+/// Largest chroma of Oklch space reachable by sRGB colors
+const MAX_CHROMA: f32 = 0.37;
+
+/// Small deterministic random number generator (PCG32).
+///
+/// Uses integer arithmetic only, so the same seed produces the same sequence on every platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+    /// Create generator with seed
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, 0xda3e_39cb_94b9_5bdb)
+    }
+
+    /// Create generator with seed and stream, different streams give independent sequences
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    /// Generate next random number
+    pub fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+        self.state = state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rotation = (state >> 59) as u32;
+        xorshifted.rotate_right(rotation)
+    }
+
+    /// Generate next random number in `0..1`
+    pub fn next_f32(&mut self) -> f32 {
+        // 24 bits fit into mantissa exactly
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Generate next random number in `min..max`
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+/// Color space used to sample random colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SampleSpace {
+    /// Sample hue, saturation and lightness of Hsl space
+    #[default]
+    Hsl,
+    /// Sample hue, chroma and lightness of perceptual Oklch space,
+    /// colors with the same lightness look equally bright
+    Oklch,
+}
+
+/// Constraints of generated random colors.
+///
+/// Saturation and lightness are fractions in `0..1`, for Oklch space
+/// saturation is the fraction of the largest chroma reachable by sRGB colors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomConstraints {
+    /// Range of hues in degrees, may wrap around zero like `(330.0, 30.0)`
+    pub hue: (f32, f32),
+    /// Range of saturation
+    pub saturation: (f32, f32),
+    /// Range of lightness
+    pub lightness: (f32, f32),
+    /// Minimal WCAG contrast ratio against background color
+    pub contrast: Option<(Color, f32)>,
+}
+
+impl Default for RandomConstraints {
+    fn default() -> Self {
+        Self {
+            hue: (0.0, 360.0),
+            saturation: (0.5, 0.9),
+            lightness: (0.35, 0.65),
+            contrast: None,
+        }
+    }
+}
+
+/// Deterministic random color generator.
+///
+/// Colors are sampled inside constraints, candidates with too low contrast are
+/// rejected, so the generator gives up after a number of attempts and returns
+/// the most contrasting candidate.
+///
+/// ```
+/// use primitives::foundation::colorschemes::{RandomColor, RandomConstraints, SampleSpace};
+/// use primitives::prelude::color;
+///
+/// let constraints = RandomConstraints {
+///     hue: (180.0, 270.0),
+///     contrast: Some((color::WHITE, 4.5)),
+///     ..Default::default()
+/// };
+/// let mut random = RandomColor::with_parameters(42, SampleSpace::Oklch, constraints);
+///
+/// let color = random.next_color();
+/// assert!(color.contrast_ratio(&color::WHITE) >= 4.5);
+/// assert_eq!(RandomColor::new(42).distinct(5), RandomColor::new(42).distinct(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RandomColor {
+    rng: Pcg32,
+    space: SampleSpace,
+    constraints: RandomConstraints,
+    attempts: usize,
+}
+
+impl RandomColor {
+    /// Create generator with seed, sampling Hsl space with default constraints
+    pub fn new(seed: u64) -> Self {
+        Self::with_parameters(seed, SampleSpace::default(), RandomConstraints::default())
+    }
+
+    /// Create generator with seed, sample space and constraints
+    pub fn with_parameters(seed: u64, space: SampleSpace, constraints: RandomConstraints) -> Self {
+        Self {
+            rng: Pcg32::new(seed),
+            space,
+            constraints,
+            attempts: 64,
+        }
+    }
+
+    /// Retrieve sample space
+    pub fn space(&self) -> SampleSpace {
+        self.space
+    }
+
+    /// Set the sample space
+    pub fn set_space(&mut self, value: SampleSpace) {
+        self.space = value;
+    }
+
+    /// Retrieve constraints
+    pub fn constraints(&self) -> &RandomConstraints {
+        &self.constraints
+    }
+
+    /// Set the constraints
+    pub fn set_constraints(&mut self, value: RandomConstraints) {
+        self.constraints = value;
+    }
+
+    /// Retrieve count of attempts to satisfy contrast
+    pub fn attempts(&self) -> usize {
+        self.attempts
+    }
+
+    /// Set the count of attempts to satisfy contrast
+    pub fn set_attempts(&mut self, value: usize) {
+        self.attempts = value.max(1);
+    }
+
+    /// Generate next random color
+    pub fn next_color(&mut self) -> Color {
+        self.sample(|rng, _| rng.next_f32())
+    }
+
+    /// Generate `count` distinct colors, hues are spaced by golden ratio inside the hue range
+    pub fn distinct(&mut self, count: usize) -> Vec<Color> {
+        let offset = self.rng.next_f32();
+        (0..count)
+            .map(|idx| {
+                let position = (offset + GOLDEN_RATIO * idx as f32).fract();
+                self.sample(|rng, attempt| {
+                    if attempt == 0 {
+                        position
+                    } else {
+                        // nudge hue around the spaced position when contrast fails
+                        (position + rng.range(-0.05, 0.05)).rem_euclid(1.0)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Sample color with hue position in `0..1` of hue range from `hue`
+    fn sample<F>(&mut self, mut hue: F) -> Color
+    where
+        F: FnMut(&mut Pcg32, usize) -> f32,
+    {
+        let mut best = (Color::default(), f32::MIN);
+        for attempt in 0..self.attempts {
+            let position = hue(&mut self.rng, attempt);
+            let color = self.candidate(position);
+
+            let contrast = match self.constraints.contrast {
+                Some((background, minimal)) => {
+                    let ratio = color.contrast_ratio(&background);
+                    if ratio >= minimal {
+                        return color;
+                    }
+                    ratio
+                }
+                None => return color,
+            };
+            if contrast > best.1 {
+                best = (color, contrast);
+            }
+        }
+        best.0
+    }
+
+    fn candidate(&mut self, position: f32) -> Color {
+        let RandomConstraints {
+            hue: (hue_min, hue_max),
+            saturation: (saturation_min, saturation_max),
+            lightness: (lightness_min, lightness_max),
+            ..
+        } = self.constraints;
+
+        // ranges like 330..30 wrap around zero
+        let span = (hue_max - hue_min).rem_euclid(360.0);
+        let span = if span == 0.0 && hue_max != hue_min { 360.0 } else { span };
+        let hue = (hue_min + span * position).rem_euclid(360.0);
+
+        let saturation = self.rng.range(saturation_min, saturation_max).clamp(0.0, 1.0);
+        let lightness = self.rng.range(lightness_min, lightness_max).clamp(0.0, 1.0);
+
+        match self.space {
+            SampleSpace::Hsl => HslColor::new(hue, saturation * 100.0, lightness * 100.0).into(),
+            SampleSpace::Oklch => {
+                let color: Color = OklchColor::new(lightness, saturation * MAX_CHROMA, hue)
+                    .clip_to_gamut()
+                    .into();
+                Color::new(
+                    color.red.clamp(0.0, 1.0),
+                    color.green.clamp(0.0, 1.0),
+                    color.blue.clamp(0.0, 1.0),
+                    1.0,
+                )
+            }
+        }
+    }
+}
+
+impl Iterator for RandomColor {
+    type Item = Color;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_color())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::colorspace::HsvColor;
+    use crate::prelude::color;
+
+    #[test]
+    fn reproducible() {
+        // reference values of PCG32 for seed 42 and stream 54
+        let mut rng = Pcg32::with_stream(42, 54);
+        let values: Vec<u32> = (0..3).map(|_| rng.next_u32()).collect();
+        assert_eq!(values, vec![0xa15c_02b7, 0x7b47_f409, 0xba1d_3330]);
+
+        let first: Vec<Color> = RandomColor::new(7).take(8).collect();
+        let second: Vec<Color> = RandomColor::new(7).take(8).collect();
+        assert_eq!(first, second);
+        assert_ne!(first, RandomColor::new(8).take(8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn constraints() {
+        let constraints = RandomConstraints {
+            hue: (330.0, 30.0),
+            saturation: (0.6, 0.8),
+            lightness: (0.2, 0.4),
+            contrast: Some((color::WHITE, 7.0)),
+        };
+        let random = RandomColor::with_parameters(1, SampleSpace::Hsl, constraints);
+
+        for color in random.take(100) {
+            let hsl = HslColor::from(color);
+            assert!(hsl.hue >= 329.5 || hsl.hue <= 30.5, "{}", hsl);
+            assert!((59.0..=81.0).contains(&hsl.saturation), "{}", hsl);
+            assert!((19.0..=41.0).contains(&hsl.lightness), "{}", hsl);
+            assert!(color.contrast_ratio(&color::WHITE) >= 7.0);
+        }
+    }
+
+    #[test]
+    fn distinct() {
+        let mut random = RandomColor::with_parameters(3, SampleSpace::Oklch, Default::default());
+        let hues: Vec<f32> = random
+            .distinct(6)
+            .iter()
+            .map(|color| OklchColor::from(*color).hue)
+            .collect();
+
+        for (idx, hue) in hues.iter().enumerate() {
+            for other in hues.iter().skip(idx + 1) {
+                let distance = (hue - other).rem_euclid(360.0);
+                assert!(distance.min(360.0 - distance) > 20.0, "{:?}", hues);
+            }
+        }
+
+        let mut narrow = RandomColor::with_parameters(
+            3,
+            SampleSpace::Hsl,
+            RandomConstraints {
+                hue: (100.0, 140.0),
+                ..Default::default()
+            },
+        );
+        for color in narrow.distinct(4) {
+            let hue = HsvColor::from(color).hue;
+            assert!((99.5..=140.5).contains(&hue), "{}", hue);
+        }
+    }
+}
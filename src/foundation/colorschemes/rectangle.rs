@@ -0,0 +1,13 @@
+use crate::foundation::colorspace::Color;
+
+use super::{ColorScheme, HueWheel, SchemeKind};
+
+rotated_scheme!(
+    /// The rectangle (aka double complementary) scheme uses two pairs of complementary colors.
+    ///
+    /// The second pair is rotated from the first one by the angle.
+    /// It is rich in variations and is easier to balance with one dominant color.
+    Rectangle,
+    60.0,
+    |angle| vec![angle, 180.0, 180.0 + angle]
+);
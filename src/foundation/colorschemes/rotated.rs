@@ -0,0 +1,227 @@
+use crate::foundation::colorspace::Color;
+
+use super::{rotate_hues, HueWheel};
+
+/// Primary color followed by its hues rotated by angles derived from the scheme angle
+#[derive(Debug, Clone)]
+pub(super) struct Rotated {
+    pub angle: f32,
+    pub contrast: f32,
+    angles: fn(f32) -> Vec<f32>,
+
+    pub wheel: HueWheel,
+    pub colors: Vec<Color>,
+    pub primary_color: Color,
+}
+
+impl Rotated {
+    pub fn new(
+        primary: Color,
+        angles: fn(f32) -> Vec<f32>,
+        angle: f32,
+        contrast: Option<f32>,
+    ) -> Self {
+        let mut instance = Self {
+            colors: Vec::new(),
+            primary_color: primary,
+            wheel: HueWheel::default(),
+            angles,
+            angle,
+            contrast: contrast.unwrap_or(0.0),
+        };
+        instance.generate();
+
+        instance
+    }
+
+    pub fn generate(&mut self) {
+        let angles = (self.angles)(self.angle);
+        self.colors = rotate_hues(&self.wheel, self.primary_color, &angles, self.contrast);
+    }
+}
+
+/// Define public scheme rotating primary color by angles computed from the scheme angle
+macro_rules! rotated_scheme {
+    ($(#[$meta:meta])* $name:ident, $angle:expr, $angles:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub struct $name(super::rotated::Rotated);
+
+        impl $name {
+            #[doc = concat!(
+                "Generate ",
+                stringify!($name),
+                " scheme with your color, ",
+                stringify!($angle),
+                " degree angle and without contrast"
+            )]
+            pub fn new(primary: Color) -> Self {
+                Self::with_parameters(primary, Some($angle), Some(0.0))
+            }
+
+            #[doc = concat!("Generate ", stringify!($name), " scheme with specified parameters")]
+            pub fn with_parameters(
+                primary: Color,
+                angle: Option<f32>,
+                contrast: Option<f32>,
+            ) -> Self {
+                Self(super::rotated::Rotated::new(
+                    primary,
+                    $angles,
+                    angle.unwrap_or($angle),
+                    contrast,
+                ))
+            }
+
+            /// Retrieve angle of scheme
+            pub fn angle(&self) -> f32 {
+                self.0.angle
+            }
+
+            /// Set the angle of scheme
+            pub fn set_angle(&mut self, value: f32) {
+                self.0.angle = value;
+                self.0.generate();
+            }
+
+            /// Retrieve contrast of scheme
+            pub fn contrast(&self) -> f32 {
+                self.0.contrast
+            }
+
+            /// Set the contrast
+            pub fn set_contrast(&mut self, value: f32) {
+                self.0.contrast = value;
+                self.0.generate();
+            }
+
+            /// Retrieve hue wheel of scheme
+            pub fn wheel(&self) -> &HueWheel {
+                &self.0.wheel
+            }
+
+            /// Set the hue wheel of scheme
+            pub fn set_wheel(&mut self, value: HueWheel) {
+                self.0.wheel = value;
+                self.0.generate();
+            }
+
+            /// Retrieve count colors of scheme
+            pub fn num_of_colors(&self) -> usize {
+                self.0.colors.len()
+            }
+
+            /// Retrieve color by index
+            pub fn get_color(&self, index: usize) -> Option<Color> {
+                self.0.colors.get(index).copied()
+            }
+
+            /// Retrieve primary color of scheme
+            pub fn primary_color(&self) -> Color {
+                self.0.primary_color
+            }
+
+            /// Set the primary color of scheme
+            pub fn set_primary_color(&mut self, value: Color) {
+                self.0.primary_color = value;
+                self.0.generate();
+            }
+        }
+
+        impl ColorScheme for $name {
+            fn kind(&self) -> SchemeKind {
+                SchemeKind::$name
+            }
+
+            fn primary_color(&self) -> Color {
+                $name::primary_color(self)
+            }
+
+            fn set_primary_color(&mut self, value: Color) {
+                $name::set_primary_color(self, value)
+            }
+
+            fn colors(&self) -> &[Color] {
+                &self.0.colors
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::foundation::colorschemes::{Hexad, HueWheel, Rectangle, Square};
+    use crate::foundation::colorspace::{Color, HsvColor};
+
+    fn hues(colors: Vec<Color>) -> Vec<f32> {
+        colors
+            .into_iter()
+            .map(|color| HsvColor::from(color).hue.round())
+            .collect()
+    }
+
+    fn colors(count: usize, get_color: impl Fn(usize) -> Option<Color>) -> Vec<Color> {
+        assert_eq!(get_color(count), None);
+        (0..count).map(|idx| get_color(idx).unwrap()).collect()
+    }
+
+    #[test]
+    fn square() {
+        let mut scheme = Square::new(Color::rgb(255, 0, 0));
+        assert_eq!(scheme.angle(), 90.0);
+        assert_eq!(
+            hues(colors(4, |idx| scheme.get_color(idx))),
+            [0.0, 48.0, 138.0, 234.0]
+        );
+        scheme.set_wheel(HueWheel::Rgb);
+        assert_eq!(
+            hues(colors(4, |idx| scheme.get_color(idx))),
+            [0.0, 90.0, 180.0, 270.0]
+        );
+        scheme.set_angle(45.0);
+        assert_eq!(
+            hues(colors(4, |idx| scheme.get_color(idx))),
+            [0.0, 45.0, 90.0, 135.0]
+        );
+    }
+
+    #[test]
+    fn rectangle() {
+        let mut scheme = Rectangle::new(Color::rgb(255, 0, 0));
+        assert_eq!(scheme.angle(), 60.0);
+        assert_eq!(
+            hues(colors(4, |idx| scheme.get_color(idx))),
+            [0.0, 34.0, 138.0, 204.0]
+        );
+        scheme.set_wheel(HueWheel::Rgb);
+        assert_eq!(
+            hues(colors(4, |idx| scheme.get_color(idx))),
+            [0.0, 60.0, 180.0, 240.0]
+        );
+        scheme.set_angle(30.0);
+        assert_eq!(
+            hues(colors(4, |idx| scheme.get_color(idx))),
+            [0.0, 30.0, 180.0, 210.0]
+        );
+    }
+
+    #[test]
+    fn hexad() {
+        let mut scheme = Hexad::new(Color::rgb(255, 0, 0));
+        assert_eq!(scheme.angle(), 60.0);
+        assert_eq!(
+            hues(colors(6, |idx| scheme.get_color(idx))),
+            [0.0, 34.0, 60.0, 138.0, 204.0, 267.0]
+        );
+        scheme.set_wheel(HueWheel::Rgb);
+        assert_eq!(
+            hues(colors(6, |idx| scheme.get_color(idx))),
+            [0.0, 60.0, 120.0, 180.0, 240.0, 300.0]
+        );
+        scheme.set_angle(30.0);
+        assert_eq!(
+            hues(colors(6, |idx| scheme.get_color(idx))),
+            [0.0, 30.0, 60.0, 90.0, 120.0, 150.0]
+        );
+    }
+}
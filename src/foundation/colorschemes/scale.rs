@@ -0,0 +1,356 @@
+use crate::foundation::colorspace::{Color, OklabColor, OklchColor};
+
+use super::{ColorScheme, SchemeKind};
+
+/// Average Oklab lightness of the OpenColor ramps, from the lightest to the darkest shade.
+const OPEN_COLOR_LIGHTNESS: [f32; 10] = [
+    0.970, 0.936, 0.885, 0.821, 0.765, 0.718, 0.675, 0.633, 0.588, 0.538,
+];
+
+/// Average share of the peak chroma of the OpenColor ramps, from the lightest to the darkest shade.
+const OPEN_COLOR_CHROMA: [f32; 10] = [
+    0.128, 0.278, 0.476, 0.699, 0.861, 0.949, 0.980, 0.968, 0.920, 0.848,
+];
+
+/// Describes how the lightness is distributed over the steps of the scale.
+///
+/// Lightness is expressed in Oklab `l` units (0.0 - 1.0).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum LightnessCurve {
+    /// Evenly spaced lightness between the lightest and the darkest step
+    Linear {
+        /// Lightness of the first step
+        lightest: f32,
+        /// Lightness of the last step
+        darkest: f32,
+    },
+    /// Lightness spaced by `t.powf(exponent)`, values above one keep more light steps
+    Power {
+        /// Lightness of the first step
+        lightest: f32,
+        /// Lightness of the last step
+        darkest: f32,
+        /// Exponent of the curve
+        exponent: f32,
+    },
+    /// Lightness measured on the OpenColor palette
+    #[default]
+    OpenColor,
+    /// Explicit lightness points, resampled to the number of steps
+    Custom(Vec<f32>),
+}
+
+impl LightnessCurve {
+    /// Retrieve lightness at position `t` (0.0 is the lightest step, 1.0 the darkest)
+    pub fn lightness(&self, t: f32) -> f32 {
+        match self {
+            Self::Linear { lightest, darkest } => lightest + (darkest - lightest) * t,
+            Self::Power {
+                lightest,
+                darkest,
+                exponent,
+            } => lightest + (darkest - lightest) * t.powf(*exponent),
+            Self::OpenColor => resample(&OPEN_COLOR_LIGHTNESS, t),
+            Self::Custom(points) => resample(points, t),
+        }
+    }
+}
+
+/// Describes how the chroma of the primary color is eased over the steps of the scale.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ChromaEasing {
+    /// Every step keeps the chroma of the primary color
+    Constant,
+    /// Chroma grows linearly from the given share at the lightest step to the full chroma
+    Linear(f32),
+    /// Chroma peaks at `position` and falls off quadratically down to `floor` share at the ends
+    Peak {
+        /// Position of the peak (0.0 is the lightest step, 1.0 the darkest)
+        position: f32,
+        /// Share of the chroma kept at the farthest end
+        floor: f32,
+    },
+    /// Chroma distribution measured on the OpenColor palette
+    #[default]
+    OpenColor,
+}
+
+impl ChromaEasing {
+    /// Retrieve share of the chroma at position `t` (0.0 is the lightest step, 1.0 the darkest)
+    pub fn factor(&self, t: f32) -> f32 {
+        match *self {
+            Self::Constant => 1.0,
+            Self::Linear(lightest) => lightest + (1.0 - lightest) * t,
+            Self::Peak { position, floor } => {
+                let span = position.max(1.0 - position);
+                if span <= 0.0 {
+                    return 1.0;
+                }
+                let distance = (t - position) / span;
+                1.0 - (1.0 - floor) * distance * distance
+            }
+            Self::OpenColor => resample(&OPEN_COLOR_CHROMA, t),
+        }
+    }
+}
+
+/// Deviation of a scale from a reference ramp, in ΔE OK units
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScaleDeviation {
+    /// Mean deviation over all compared steps
+    pub mean: f32,
+    /// Largest deviation of a single step
+    pub max: f32,
+}
+
+/// A shade scale is a ramp of perceptually even steps from light to dark of a single hue.
+///
+/// Steps are produced in the Oklch color space, so equal lightness differences
+/// look equal regardless of the hue, like in the OpenColor palette.
+/// Chroma that does not fit into sRGB is reduced keeping lightness and hue.
+#[derive(Debug, Clone)]
+pub struct Scale {
+    steps: usize,
+    curve: LightnessCurve,
+    easing: ChromaEasing,
+
+    colors: Vec<Color>,
+    primary_color: Color,
+}
+
+impl Scale {
+    /// Generate Scale with your color, 10 steps and the OpenColor curves
+    pub fn new(primary: Color) -> Self {
+        Self::with_parameters(primary, Some(10), None, None)
+    }
+
+    /// Generate Scale with specified parameters
+    pub fn with_parameters(
+        primary: Color,
+        steps: Option<usize>,
+        curve: Option<LightnessCurve>,
+        easing: Option<ChromaEasing>,
+    ) -> Self {
+        let mut instance = Self {
+            colors: Vec::new(),
+            primary_color: primary,
+            steps: steps.unwrap_or(10),
+            curve: curve.unwrap_or_default(),
+            easing: easing.unwrap_or_default(),
+        };
+        instance.generate();
+
+        instance
+    }
+
+    fn generate(&mut self) {
+        let primary: OklchColor = self.primary_color.into();
+
+        self.colors = (0..self.steps)
+            .map(|idx| {
+                let t = if self.steps > 1 {
+                    idx as f32 / (self.steps - 1) as f32
+                } else {
+                    0.0
+                };
+
+                let lch = OklchColor::new(
+                    self.curve.lightness(t).clamp(0.0, 1.0),
+                    primary.chroma * self.easing.factor(t).max(0.0),
+                    primary.hue,
+                );
+
+                let mut color: Color = lch.clip_to_gamut().into();
+                color.red = color.red.clamp(0.0, 1.0);
+                color.green = color.green.clamp(0.0, 1.0);
+                color.blue = color.blue.clamp(0.0, 1.0);
+                color.alpha = self.primary_color.alpha;
+                color
+            })
+            .collect();
+    }
+
+    /// Measure how closely the scale reproduces the reference ramp, step by step
+    pub fn deviation(&self, reference: &[Color]) -> ScaleDeviation {
+        let distances: Vec<f32> = self
+            .colors
+            .iter()
+            .zip(reference.iter())
+            .map(|(actual, expected)| {
+                OklabColor::from(*actual).delta_e(&OklabColor::from(*expected))
+            })
+            .collect();
+
+        if distances.is_empty() {
+            return ScaleDeviation::default();
+        }
+
+        ScaleDeviation {
+            mean: distances.iter().sum::<f32>() / distances.len() as f32,
+            max: distances.iter().cloned().fold(0.0, f32::max),
+        }
+    }
+
+    /// Retrieve count of steps
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Set the count of steps
+    pub fn set_steps(&mut self, value: usize) {
+        self.steps = value;
+        self.generate();
+    }
+
+    /// Retrieve lightness curve of scale
+    pub fn curve(&self) -> &LightnessCurve {
+        &self.curve
+    }
+
+    /// Set the lightness curve of scale
+    pub fn set_curve(&mut self, value: LightnessCurve) {
+        self.curve = value;
+        self.generate();
+    }
+
+    /// Retrieve chroma easing of scale
+    pub fn easing(&self) -> ChromaEasing {
+        self.easing
+    }
+
+    /// Set the chroma easing of scale
+    pub fn set_easing(&mut self, value: ChromaEasing) {
+        self.easing = value;
+        self.generate();
+    }
+
+    /// Retrieve count colors of scheme
+    pub fn num_of_colors(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Set color by index
+    pub fn get_color(&self, index: usize) -> Option<Color> {
+        self.colors.get(index).copied()
+    }
+
+    /// Retrieve primary color of scheme
+    pub fn primary_color(&self) -> Color {
+        self.primary_color
+    }
+
+    /// Set the primary color of scheme
+    pub fn set_primary_color(&mut self, value: Color) {
+        self.primary_color = value;
+        self.generate();
+    }
+}
+
+impl ColorScheme for Scale {
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::Scale
+    }
+
+    fn primary_color(&self) -> Color {
+        Scale::primary_color(self)
+    }
+
+    fn set_primary_color(&mut self, value: Color) {
+        Scale::set_primary_color(self, value)
+    }
+
+    fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
+
+/// Linear interpolation over evenly spaced points at position `t` (0.0 - 1.0)
+fn resample(points: &[f32], t: f32) -> f32 {
+    match points.len() {
+        0 => 0.0,
+        1 => points[0],
+        len => {
+            let position = t.clamp(0.0, 1.0) * (len - 1) as f32;
+            let idx = (position.floor() as usize).min(len - 2);
+            let fraction = position - idx as f32;
+            points[idx] + (points[idx + 1] - points[idx]) * fraction
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::color;
+
+    #[test]
+    fn lightness_is_monotonic() {
+        let scale = Scale::new(color::BLUE_6);
+        assert_eq!(scale.num_of_colors(), 10);
+
+        let lightness: Vec<f32> = (0..scale.num_of_colors())
+            .map(|idx| OklabColor::from(scale.get_color(idx).unwrap()).l)
+            .collect();
+        for pair in lightness.windows(2) {
+            assert!(pair[0] > pair[1], "{:?}", lightness);
+        }
+    }
+
+    #[test]
+    fn linear_steps_are_even() {
+        let curve = LightnessCurve::Linear {
+            lightest: 0.9,
+            darkest: 0.3,
+        };
+        let scale = Scale::with_parameters(
+            color::GRAY_6,
+            Some(7),
+            Some(curve),
+            Some(ChromaEasing::Constant),
+        );
+        assert_eq!(scale.num_of_colors(), 7);
+        for idx in 0..7 {
+            let actual = OklabColor::from(scale.get_color(idx).unwrap()).l;
+            let expected = 0.9 - 0.1 * idx as f32;
+            assert!((actual - expected).abs() < 0.005, "{} != {}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn reproduces_open_color() {
+        for (name, ramp) in color::RAMPS.iter() {
+            let scale = Scale::new(ramp[6]);
+            assert_eq!(scale.num_of_colors(), ramp.len(), "{}", name);
+            // bounds are about 10 percent above what the generator meets for every ramp
+            let (mean, max) = match *name {
+                // gray ends much darker than the lightness curve of chromatic ramps
+                "GRAY" => (11.5, 29.0),
+                // yellow turns orange in darker shades, which keep constant hue here
+                "YELLOW" => (11.5, 17.5),
+                // lime, violet and indigo drift in hue towards their darker shades
+                "LIME" => (7.0, 10.0),
+                "VIOLET" => (8.5, 14.0),
+                "INDIGO" => (7.0, 11.0),
+                "ORANGE" => (5.5, 9.5),
+                "GRAPE" => (4.5, 7.0),
+                "PINK" => (3.5, 6.5),
+                "BLUE" => (4.5, 6.0),
+                "GREEN" => (3.5, 5.0),
+                "TEAL" => (2.5, 4.5),
+                "CYAN" => (2.0, 3.5),
+                "RED" => (1.5, 3.0),
+                _ => unreachable!("{} has no bounds", name),
+            };
+            let deviation = scale.deviation(ramp);
+            assert!(deviation.mean < mean, "{}: {:?}", name, deviation);
+            assert!(deviation.max < max, "{}: {:?}", name, deviation);
+        }
+    }
+
+    #[test]
+    fn resample_points() {
+        assert_eq!(resample(&[1.0, 0.0], 0.25), 0.75);
+        assert_eq!(resample(&[1.0, 0.5, 0.0], 1.0), 0.0);
+        assert_eq!(resample(&[0.4], 0.5), 0.4);
+    }
+}
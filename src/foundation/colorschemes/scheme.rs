@@ -0,0 +1,199 @@
+use std::{fmt, slice::Iter};
+
+use crate::foundation::colorspace::Color;
+
+use super::*;
+
+/// Defines the common functionality of color schemes
+pub trait ColorScheme {
+    /// Retrieve kind of scheme
+    fn kind(&self) -> SchemeKind;
+
+    /// Retrieve primary color of scheme
+    fn primary_color(&self) -> Color;
+
+    /// Set the primary color of scheme and regenerate its colors
+    fn set_primary_color(&mut self, value: Color);
+
+    /// Retrieve generated colors of scheme
+    fn colors(&self) -> &[Color];
+
+    /// Retrieve count colors of scheme
+    fn num_of_colors(&self) -> usize {
+        self.colors().len()
+    }
+
+    /// Retrieve color by index
+    fn get_color(&self, index: usize) -> Option<Color> {
+        self.colors().get(index).copied()
+    }
+
+    /// Iterate over generated colors of scheme
+    fn iter(&self) -> Iter<'_, Color> {
+        self.colors().iter()
+    }
+
+    /// Create the same scheme regenerated with another primary color
+    fn with_primary_color(&self, primary: Color) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        let mut scheme = self.clone();
+        scheme.set_primary_color(primary);
+        scheme
+    }
+}
+
+/// Represents the kind of color scheme, allows to switch schemes at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemeKind {
+    /// Analogous scheme
+    Analogous,
+    /// Complementary scheme
+    Complementary,
+    /// Compound scheme
+    Compound,
+    /// Flipped compound scheme
+    FlippedCompound,
+    /// Hexad (six-tone) scheme
+    Hexad,
+    /// Monochrome scheme
+    Monochrome,
+    /// Rectangle (double complementary) scheme
+    Rectangle,
+    /// Shade scale
+    Scale,
+    /// Shades mixed with black
+    Shades,
+    /// Split complementary scheme
+    SplitComplementary,
+    /// Square scheme
+    Square,
+    /// Tetrad scheme
+    Tetrad,
+    /// Tints mixed with white
+    Tints,
+    /// Tones mixed with gray
+    Tones,
+    /// Triad scheme
+    Triad,
+}
+
+impl SchemeKind {
+    /// All available kinds of scheme
+    pub const ALL: [SchemeKind; 15] = [
+        SchemeKind::Analogous,
+        SchemeKind::Complementary,
+        SchemeKind::Compound,
+        SchemeKind::FlippedCompound,
+        SchemeKind::Hexad,
+        SchemeKind::Monochrome,
+        SchemeKind::Rectangle,
+        SchemeKind::Scale,
+        SchemeKind::Shades,
+        SchemeKind::SplitComplementary,
+        SchemeKind::Square,
+        SchemeKind::Tetrad,
+        SchemeKind::Tints,
+        SchemeKind::Tones,
+        SchemeKind::Triad,
+    ];
+
+    /// Generate scheme of this kind with default parameters
+    pub fn generate(self, primary: Color) -> Box<dyn ColorScheme> {
+        match self {
+            Self::Analogous => Box::new(Analogous::new(primary)),
+            Self::Complementary => Box::new(Complementary::new(primary)),
+            Self::Compound => Box::new(Compound::new(primary)),
+            Self::FlippedCompound => Box::new(FlippedCompound::new(primary)),
+            Self::Hexad => Box::new(Hexad::new(primary)),
+            Self::Monochrome => Box::new(Monochrome::new(primary)),
+            Self::Rectangle => Box::new(Rectangle::new(primary)),
+            Self::Scale => Box::new(Scale::new(primary)),
+            Self::Shades => Box::new(Shades::new(primary)),
+            Self::SplitComplementary => Box::new(SplitComplementary::new(primary)),
+            Self::Square => Box::new(Square::new(primary)),
+            Self::Tetrad => Box::new(Tetrad::new(primary)),
+            Self::Tints => Box::new(Tints::new(primary)),
+            Self::Tones => Box::new(Tones::new(primary)),
+            Self::Triad => Box::new(Triad::new(primary)),
+        }
+    }
+}
+
+impl fmt::Display for SchemeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Analogous => "analogous",
+            Self::Complementary => "complementary",
+            Self::Compound => "compound",
+            Self::FlippedCompound => "flipped compound",
+            Self::Hexad => "hexad",
+            Self::Monochrome => "monochrome",
+            Self::Rectangle => "rectangle",
+            Self::Scale => "scale",
+            Self::Shades => "shades",
+            Self::SplitComplementary => "split complementary",
+            Self::Square => "square",
+            Self::Tetrad => "tetrad",
+            Self::Tints => "tints",
+            Self::Tones => "tones",
+            Self::Triad => "triad",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::color;
+
+    #[test]
+    fn generate_every_kind() {
+        for kind in SchemeKind::ALL.iter() {
+            let mut scheme = kind.generate(color::TEAL_6);
+            assert_eq!(scheme.kind(), *kind);
+            assert_eq!(scheme.primary_color(), color::TEAL_6);
+            assert!(scheme.num_of_colors() > 0, "{}", kind);
+            assert_eq!(scheme.iter().count(), scheme.num_of_colors());
+            assert_eq!(scheme.get_color(scheme.num_of_colors()), None);
+
+            scheme.set_primary_color(color::GRAPE_6);
+            assert_eq!(scheme.primary_color(), color::GRAPE_6);
+        }
+    }
+
+    #[test]
+    fn with_primary_color() {
+        let scheme = Triad::with_parameters(color::RED_6, Some(100.0));
+        let other = scheme.with_primary_color(color::BLUE_6);
+        assert_eq!(other.angle(), 100.0);
+        assert_eq!(other.colors(), Triad::with_parameters(color::BLUE_6, Some(100.0)).colors());
+    }
+
+    #[test]
+    fn harmony_hues() {
+        use crate::foundation::colorspace::HsvColor;
+
+        let hues = |colors: &[Color]| -> Vec<f32> {
+            colors
+                .iter()
+                .map(|color| HsvColor::from(*color).hue.round())
+                .collect()
+        };
+
+        let mut square = Square::new(Color::rgb(255, 0, 0));
+        square.set_wheel(HueWheel::Rgb);
+        assert_eq!(hues(square.colors()), vec![0.0, 90.0, 180.0, 270.0]);
+
+        let mut rectangle = Rectangle::with_parameters(Color::rgb(255, 0, 0), Some(30.0), None);
+        rectangle.set_wheel(HueWheel::Rgb);
+        assert_eq!(hues(rectangle.colors()), vec![0.0, 30.0, 180.0, 210.0]);
+
+        let mut hexad = Hexad::new(Color::rgb(255, 0, 0));
+        hexad.set_wheel(HueWheel::Rgb);
+        assert_eq!(hexad.num_of_colors(), 6);
+        assert_eq!(hexad.get_color(2).map(HsvColor::from).unwrap().hue.round(), 120.0);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::foundation::colorspace::Color;
+
+use super::{ColorScheme, SchemeKind};
+
+/// Primary color mixed towards the target by evenly growing amounts up to contrast percent
+#[derive(Debug, Clone)]
+struct Mixed {
+    target: Color,
+    steps: usize,
+    contrast: f32,
+
+    colors: Vec<Color>,
+    primary_color: Color,
+}
+
+impl Mixed {
+    fn new(primary: Color, target: Color, steps: Option<usize>, contrast: Option<f32>) -> Self {
+        let mut instance = Self {
+            colors: Vec::new(),
+            primary_color: primary,
+            target,
+            steps: steps.unwrap_or(5),
+            contrast: contrast.unwrap_or(80.0),
+        };
+        instance.generate();
+
+        instance
+    }
+
+    fn generate(&mut self) {
+        let (primary, target) = (self.primary_color, self.target);
+        let amount = self.contrast.clamp(0.0, 100.0) / 100.0;
+        let steps = self.steps;
+        self.colors = (0..steps)
+            .map(|idx| {
+                let t = if steps > 1 {
+                    amount * idx as f32 / (steps - 1) as f32
+                } else {
+                    0.0
+                };
+                Color {
+                    red: primary.red + (target.red - primary.red) * t,
+                    green: primary.green + (target.green - primary.green) * t,
+                    blue: primary.blue + (target.blue - primary.blue) * t,
+                    alpha: primary.alpha,
+                }
+            })
+            .collect();
+    }
+}
+
+/// Define public scheme mixing primary color with target color
+macro_rules! mixed_scheme {
+    ($(#[$meta:meta])* $name:ident, $target:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub struct $name(Mixed);
+
+        impl $name {
+            #[doc = concat!(
+                "Generate ",
+                stringify!($name),
+                " scheme with your color, 5 steps and 80 percent of contrast"
+            )]
+            pub fn new(primary: Color) -> Self {
+                Self::with_parameters(primary, Some(5), Some(80.0))
+            }
+
+            #[doc = concat!("Generate ", stringify!($name), " scheme with specified parameters")]
+            pub fn with_parameters(
+                primary: Color,
+                steps: Option<usize>,
+                contrast: Option<f32>,
+            ) -> Self {
+                Self(Mixed::new(primary, $target, steps, contrast))
+            }
+
+            /// Retrieve count of steps
+            pub fn steps(&self) -> usize {
+                self.0.steps
+            }
+
+            /// Set the count of steps
+            pub fn set_steps(&mut self, value: usize) {
+                self.0.steps = value;
+                self.0.generate();
+            }
+
+            /// Retrieve contrast of scheme
+            pub fn contrast(&self) -> f32 {
+                self.0.contrast
+            }
+
+            /// Set the contrast
+            pub fn set_contrast(&mut self, value: f32) {
+                self.0.contrast = value;
+                self.0.generate();
+            }
+
+            /// Retrieve count colors of scheme
+            pub fn num_of_colors(&self) -> usize {
+                self.0.colors.len()
+            }
+
+            /// Retrieve color by index
+            pub fn get_color(&self, index: usize) -> Option<Color> {
+                self.0.colors.get(index).copied()
+            }
+
+            /// Retrieve primary color of scheme
+            pub fn primary_color(&self) -> Color {
+                self.0.primary_color
+            }
+
+            /// Set the primary color of scheme
+            pub fn set_primary_color(&mut self, value: Color) {
+                self.0.primary_color = value;
+                self.0.generate();
+            }
+        }
+
+        impl ColorScheme for $name {
+            fn kind(&self) -> SchemeKind {
+                SchemeKind::$name
+            }
+
+            fn primary_color(&self) -> Color {
+                $name::primary_color(self)
+            }
+
+            fn set_primary_color(&mut self, value: Color) {
+                $name::set_primary_color(self, value)
+            }
+
+            fn colors(&self) -> &[Color] {
+                &self.0.colors
+            }
+        }
+    };
+}
+
+mixed_scheme!(
+    /// Shades of a hue are produced by mixing the color with black.
+    ///
+    /// The first color is the primary one, every next color is darker
+    /// and the last one is mixed with black by contrast percent.
+    Shades,
+    Color::rgb(0, 0, 0)
+);
+
+mixed_scheme!(
+    /// Tints of a hue are produced by mixing the color with white.
+    ///
+    /// The first color is the primary one, every next color is lighter
+    /// and the last one is mixed with white by contrast percent.
+    Tints,
+    Color::rgb(255, 255, 255)
+);
+
+mixed_scheme!(
+    /// Tones of a hue are produced by mixing the color with gray.
+    ///
+    /// The first color is the primary one, every next color is more muted
+    /// and the last one is mixed with middle gray by contrast percent.
+    Tones,
+    Color::new(0.5, 0.5, 0.5, 1.0)
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::colorspace::RgbColor;
+
+    #[test]
+    fn shades_reach_black() {
+        let scheme = Shades::with_parameters(Color::rgb(200, 100, 50), Some(3), Some(100.0));
+        assert_eq!(scheme.num_of_colors(), 3);
+        assert_eq!(scheme.get_color(0), Some(Color::rgb(200, 100, 50)));
+        assert_eq!(
+            RgbColor::from(scheme.get_color(1).unwrap()),
+            RgbColor::new(100, 50, 25)
+        );
+        assert_eq!(
+            RgbColor::from(scheme.get_color(2).unwrap()),
+            RgbColor::new(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn tints_and_tones() {
+        let tints = Tints::with_parameters(Color::rgb(0, 0, 0), Some(2), Some(50.0));
+        assert_eq!(
+            RgbColor::from(tints.get_color(1).unwrap()),
+            RgbColor::new(128, 128, 128)
+        );
+
+        let mut tones = Tones::with_parameters(Color::rgb(255, 0, 0), Some(2), Some(100.0));
+        assert_eq!(
+            RgbColor::from(tones.get_color(1).unwrap()),
+            RgbColor::new(128, 128, 128)
+        );
+        tones.set_steps(4);
+        assert_eq!(tones.steps(), 4);
+        assert_eq!(tones.num_of_colors(), 4);
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 use crate::foundation::colorspace::{Color, HsvColor};
 
-use super::ryb_rotate;
+use super::{HueWheel, ColorScheme, SchemeKind};
 
 /// Split complementary is a color and the analogous colors to its complement color. 
 ///
@@ -10,6 +10,7 @@ use super::ryb_rotate;
 /// It also results in greater harmony than the use of the direct complementary.
 #[derive(Debug, Clone)]
 pub struct SplitComplementary {
+    wheel: HueWheel,
     colors: Vec<Color>,
     primary_color: Color,
 }
@@ -20,6 +21,7 @@ impl SplitComplementary {
         let mut instance = Self {
             colors: Vec::new(),
             primary_color: primary,
+            wheel: HueWheel::default(),
         };
         instance.generate();
 
@@ -30,15 +32,26 @@ impl SplitComplementary {
         self.colors = vec![self.primary_color];
 
         // value is brightness
-        let mut c1: HsvColor = ryb_rotate(self.primary_color, 150.0);
+        let mut c1: HsvColor = self.wheel.rotate(self.primary_color, 150.0);
         c1.value += 10.0;
         self.colors.push(c1.into());
 
-        let mut c2: HsvColor = ryb_rotate(self.primary_color, 210.0);
+        let mut c2: HsvColor = self.wheel.rotate(self.primary_color, 210.0);
         c2.value += 10.0;
         self.colors.push(c2.into());
     }
 
+    /// Retrieve hue wheel of scheme
+    pub fn wheel(&self) -> &HueWheel {
+        &self.wheel
+    }
+
+    /// Set the hue wheel of scheme
+    pub fn set_wheel(&mut self, value: HueWheel) {
+        self.wheel = value;
+        self.generate();
+    }
+
     /// Retrieve count colors of scheme
     pub fn num_of_colors(&self) -> usize {
         self.colors.len()
@@ -60,3 +73,21 @@ impl SplitComplementary {
         self.generate();
     }
 }
+
+impl ColorScheme for SplitComplementary {
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::SplitComplementary
+    }
+
+    fn primary_color(&self) -> Color {
+        SplitComplementary::primary_color(self)
+    }
+
+    fn set_primary_color(&mut self, val: Color) {
+        SplitComplementary::set_primary_color(self, val)
+    }
+
+    fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
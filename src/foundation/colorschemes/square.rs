@@ -0,0 +1,13 @@
+use crate::foundation::colorspace::Color;
+
+use super::{ColorScheme, HueWheel, SchemeKind};
+
+rotated_scheme!(
+    /// A square color scheme uses four colors spaced evenly around the color wheel.
+    ///
+    /// It works best when one color is dominant and the others are used as accents.
+    /// The angle sets the spacing, so the default 90 degrees gives the even square.
+    Square,
+    90.0,
+    |angle| vec![angle, angle * 2.0, angle * 3.0]
+);
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 use crate::foundation::colorspace::{Color, HsvColor};
 
-use super::ryb_rotate;
+use super::{HueWheel, ColorScheme, SchemeKind};
 
 /// A tetradic color palette has four individual colors or hues if you will: a base color and three more colors.
 ///
@@ -11,6 +11,7 @@ pub struct Tetrad {
     angle: f32,
     alt: bool,
 
+    wheel: HueWheel,
     colors: Vec<Color>,
     primary_color: Color,
 }
@@ -26,6 +27,7 @@ impl Tetrad {
         let mut instance = Self {
             colors: Vec::new(),
             primary_color: primary,
+            wheel: HueWheel::default(),
             angle: angle.unwrap_or(90.0),
             alt: alt.unwrap_or_default(),
         };
@@ -40,7 +42,7 @@ impl Tetrad {
         let primary_hsb: HsvColor = self.primary_color.into();
 
         // value is brightness
-        let mut c1: HsvColor = ryb_rotate(self.primary_color, self.angle);
+        let mut c1: HsvColor = self.wheel.rotate(self.primary_color, self.angle);
         let mut multiplier: f32;
         if !self.alt {
             if primary_hsb.value < 50.0 {
@@ -55,7 +57,7 @@ impl Tetrad {
 
         self.colors.push(c1.into());
 
-        let mut c2: HsvColor = ryb_rotate(self.primary_color, self.angle * 2.0);
+        let mut c2: HsvColor = self.wheel.rotate(self.primary_color, self.angle * 2.0);
         if !self.alt {
             if primary_hsb.value > 50.0 {
                 c2.value += 10.0;
@@ -69,7 +71,7 @@ impl Tetrad {
 
         self.colors.push(c2.into());
 
-        let mut c3: HsvColor = ryb_rotate(self.primary_color, self.angle * 3.0);
+        let mut c3: HsvColor = self.wheel.rotate(self.primary_color, self.angle * 3.0);
         c3.value += 10.0;
         self.colors.push(c3.into());
     }
@@ -96,6 +98,17 @@ impl Tetrad {
         self.generate();
     }
 
+    /// Retrieve hue wheel of scheme
+    pub fn wheel(&self) -> &HueWheel {
+        &self.wheel
+    }
+
+    /// Set the hue wheel of scheme
+    pub fn set_wheel(&mut self, value: HueWheel) {
+        self.wheel = value;
+        self.generate();
+    }
+
     /// Retrieve count colors of scheme
     pub fn num_of_colors(&self) -> usize {
         self.colors.len()
@@ -117,3 +130,21 @@ impl Tetrad {
         self.generate();
     }
 }
+
+impl ColorScheme for Tetrad {
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::Tetrad
+    }
+
+    fn primary_color(&self) -> Color {
+        Tetrad::primary_color(self)
+    }
+
+    fn set_primary_color(&mut self, val: Color) {
+        Tetrad::set_primary_color(self, val)
+    }
+
+    fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 use crate::foundation::colorspace::{Color, HsvColor};
 
-use super::ryb_rotate;
+use super::{HueWheel, ColorScheme, SchemeKind};
 
 /// Triad colors are three hues equidistant on the color wheel. 
 ///
@@ -10,6 +10,7 @@ use super::ryb_rotate;
 pub struct Triad {
     angle: f32,
 
+    wheel: HueWheel,
     colors: Vec<Color>,
     primary_color: Color,
 }
@@ -25,6 +26,7 @@ impl Triad {
         let mut instance = Self {
             colors: Vec::new(),
             primary_color: primary,
+            wheel: HueWheel::default(),
             angle: angle.unwrap_or(120.0),
         };
         instance.generate();
@@ -47,17 +49,28 @@ impl Triad {
         self.colors = vec![self.primary_color];
 
         // value is brightness
-        let mut c1: HsvColor = ryb_rotate(self.primary_color, self.angle);
+        let mut c1: HsvColor = self.wheel.rotate(self.primary_color, self.angle);
 
         c1.value += 10.0;
         self.colors.push(c1.into());
 
-        let mut c2: HsvColor = ryb_rotate(self.primary_color, -self.angle);
+        let mut c2: HsvColor = self.wheel.rotate(self.primary_color, -self.angle);
 
         c2.value += 10.0;
         self.colors.push(c2.into());
     }
 
+    /// Retrieve hue wheel of scheme
+    pub fn wheel(&self) -> &HueWheel {
+        &self.wheel
+    }
+
+    /// Set the hue wheel of scheme
+    pub fn set_wheel(&mut self, value: HueWheel) {
+        self.wheel = value;
+        self.generate();
+    }
+
     /// Retrieve count colors of scheme
     pub fn num_of_colors(&self) -> usize {
         self.colors.len()
@@ -79,3 +92,21 @@ impl Triad {
         self.generate();
     }
 }
+
+impl ColorScheme for Triad {
+    fn kind(&self) -> SchemeKind {
+        SchemeKind::Triad
+    }
+
+    fn primary_color(&self) -> Color {
+        Triad::primary_color(self)
+    }
+
+    fn set_primary_color(&mut self, val: Color) {
+        Triad::set_primary_color(self, val)
+    }
+
+    fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
@@ -0,0 +1,211 @@
+use crate::foundation::colorspace::{Color, ColorError, HsvColor, OklchColor};
+
+/// Artistic red-yellow-blue wheel as pairs of wheel angle and HSV hue
+const RYB_WHEEL: [[f32; 2]; 25] = [
+    [0.0, 0.0],
+    [15.0, 8.0],
+    [30.0, 17.0],
+    [45.0, 26.0],
+    [60.0, 34.0],
+    [75.0, 41.0],
+    [90.0, 48.0],
+    [105.0, 54.0],
+    [120.0, 60.0],
+    [135.0, 81.0],
+    [150.0, 103.0],
+    [165.0, 123.0],
+    [180.0, 138.0],
+    [195.0, 155.0],
+    [210.0, 171.0],
+    [225.0, 187.0],
+    [240.0, 204.0],
+    [255.0, 219.0],
+    [270.0, 234.0],
+    [285.0, 251.0],
+    [300.0, 267.0],
+    [315.0, 282.0],
+    [330.0, 298.0],
+    [345.0, 329.0],
+    [360.0, 0.0],
+];
+
+/// The color wheel used by schemes to rotate hues.
+///
+/// Harmonies like complementary or triad depend on which colors are considered
+/// opposite, so the same angle gives different colors on different wheels.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum HueWheel {
+    /// Artistic red-yellow-blue wheel, used by painters and most design tools
+    #[default]
+    Ryb,
+    /// Additive red-green-blue wheel, the plain HSV hue
+    Rgb,
+    /// Perceptual wheel using the Oklch hue, keeps lightness and chroma
+    Oklch,
+    /// Custom mapping as pairs of wheel angle and HSV hue, see [HueWheel::custom]
+    Custom(CustomWheel),
+}
+
+/// Validated table of custom hue wheel
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomWheel {
+    table: Vec<[f32; 2]>,
+}
+
+impl CustomWheel {
+    /// Retrieve pairs of wheel angle and HSV hue
+    pub fn table(&self) -> &[[f32; 2]] {
+        &self.table
+    }
+}
+
+impl HueWheel {
+    /// Create custom wheel from pairs of wheel angle and HSV hue.
+    ///
+    /// Angles must grow from `0` to `360` and hues must go once around the HSV wheel,
+    /// wrapping at most once and closing at the first hue, as the built-in RYB wheel does.
+    pub fn custom(table: Vec<[f32; 2]>) -> Result<Self, ColorError> {
+        let valid_values = table
+            .iter()
+            .all(|[angle, hue]| (0.0..=360.0).contains(angle) && (0.0..=360.0).contains(hue));
+        let valid_ends = match (table.first(), table.last()) {
+            (Some(first), Some(last)) => first[0] == 0.0 && last[0] == 360.0,
+            _ => false,
+        };
+        let mut coverage = 0.0;
+        let mut growing = true;
+        for pair in table.windows(2) {
+            let ([x0, y0], [x1, y1]) = (pair[0], pair[1]);
+            let span = if y1 < y0 { y1 + 360.0 - y0 } else { y1 - y0 };
+            growing &= x1 > x0 && span > 0.0;
+            coverage += span;
+        }
+        if !(valid_values && valid_ends && growing && (coverage - 360.0).abs() < 1e-3) {
+            return Err(ColorError::InvalidHueWheel);
+        }
+        Ok(Self::Custom(CustomWheel { table }))
+    }
+
+    /// Rotate the hue of color by angle in degrees on this wheel
+    pub fn rotate(&self, color: Color, angle: f32) -> HsvColor {
+        match self {
+            Self::Ryb => table_rotate(&RYB_WHEEL, color, angle),
+            Self::Rgb => {
+                let mut hsb: HsvColor = color.into();
+                hsb.hue = (hsb.hue + angle).rem_euclid(360.0);
+                hsb
+            }
+            Self::Oklch => {
+                let mut lch: OklchColor = color.into();
+                lch.hue = (lch.hue + angle).rem_euclid(360.0);
+                let rotated: Color = lch.clip_to_gamut().into();
+                Color {
+                    red: rotated.red.clamp(0.0, 1.0),
+                    green: rotated.green.clamp(0.0, 1.0),
+                    blue: rotated.blue.clamp(0.0, 1.0),
+                    alpha: color.alpha,
+                }
+                .into()
+            }
+            Self::Custom(wheel) => table_rotate(&wheel.table, color, angle),
+        }
+    }
+}
+
+fn table_rotate(table: &[[f32; 2]], color: Color, angle: f32) -> HsvColor {
+    let mut hsb: HsvColor = color.into();
+
+    let mut a: f32 = 0.0;
+    for pair in table.windows(2) {
+        let [x0, y0] = pair[0];
+        let [x1, mut y1] = pair[1];
+
+        if y1 < y0 {
+            y1 += 360.0;
+        }
+
+        if y0 <= hsb.hue && hsb.hue <= y1 {
+            a = x0 + (x1 - x0) * (hsb.hue - y0) / (y1 - y0);
+            break;
+        }
+    }
+
+    a = (a + angle % 360.0).rem_euclid(360.0);
+
+    let mut new_hue: f32 = 0.0;
+    for pair in table.windows(2) {
+        let [xx0, yy0] = pair[0];
+        let [xx1, mut yy1] = pair[1];
+
+        if yy1 < yy0 {
+            yy1 += 360.0;
+        }
+
+        if xx0 <= a && a <= xx1 {
+            new_hue = yy0 + (yy1 - yy0) * (a - xx0) / (xx1 - xx0);
+            break;
+        }
+    }
+
+    hsb.hue = new_hue % 360.0;
+
+    hsb
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::colorspace::{OklabColor, RgbColor};
+
+    #[test]
+    fn ryb_complement() {
+        // red is opposite to green on the artistic wheel
+        let complement = HueWheel::Ryb.rotate(Color::rgb(255, 0, 0), 180.0);
+        assert_eq!(complement.hue.round(), 138.0);
+    }
+
+    #[test]
+    fn rgb_complement() {
+        let complement: RgbColor = HueWheel::Rgb.rotate(Color::rgb(255, 0, 0), 180.0).into();
+        assert_eq!(complement, RgbColor::new(0, 255, 255));
+    }
+
+    #[test]
+    fn oklch_keeps_lightness() {
+        let base = Color::rgb(120, 90, 160);
+        let rotated: Color = HueWheel::Oklch.rotate(base, 120.0).into();
+        let (base, rotated) = (OklabColor::from(base), OklabColor::from(rotated));
+        assert!((base.l - rotated.l).abs() < 0.01, "{} {}", base, rotated);
+    }
+
+    #[test]
+    fn custom_identity() {
+        let wheel = HueWheel::custom(vec![[0.0, 0.0], [180.0, 180.0], [360.0, 360.0]]).unwrap();
+        let rotated = wheel.rotate(Color::rgb(255, 0, 0), 90.0);
+        assert_eq!(rotated.hue.round(), 90.0);
+
+        let ryb = HueWheel::custom(RYB_WHEEL.to_vec()).unwrap();
+        let complement = ryb.rotate(Color::rgb(255, 0, 0), 180.0);
+        assert_eq!(complement.hue.round(), 138.0);
+    }
+
+    #[test]
+    fn custom_validation() {
+        let invalid = [
+            vec![],
+            vec![[0.0, 0.0]],
+            // unsorted angles
+            vec![[0.0, 0.0], [240.0, 240.0], [120.0, 120.0], [360.0, 0.0]],
+            // angles not reaching 360
+            vec![[0.0, 0.0], [180.0, 180.0], [300.0, 360.0]],
+            // hues covering half of the wheel
+            vec![[0.0, 0.0], [360.0, 180.0]],
+            // hues going around twice
+            vec![[0.0, 0.0], [120.0, 300.0], [240.0, 240.0], [360.0, 0.0]],
+            vec![[0.0, 0.0], [180.0, f32::NAN], [360.0, 360.0]],
+        ];
+        for table in invalid.iter() {
+            assert!(HueWheel::custom(table.clone()).is_err(), "{:?}", table);
+        }
+    }
+}
@@ -95,6 +95,278 @@ pub trait Invert: Sized {
 pub trait Adjust: Lighten + AdjustHue + Saturate + Grayscale {}
 impl<C: Lighten + AdjustHue + Saturate + Grayscale> Adjust for C {}
 
+/// Levels adjustment, maps input range to output range with gamma correction in between.
+///
+/// All values are fractions in `0..1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Levels {
+    /// Input value mapped to output black
+    pub input_black: Float,
+    /// Input value mapped to output white
+    pub input_white: Float,
+    /// Gamma of midtones, values above one brighten them
+    pub gamma: Float,
+    /// Darkest output value
+    pub output_black: Float,
+    /// Brightest output value
+    pub output_white: Float,
+}
+
+impl Default for Levels {
+    fn default() -> Self {
+        Self {
+            input_black: 0.,
+            input_white: 1.,
+            gamma: 1.,
+            output_black: 0.,
+            output_white: 1.,
+        }
+    }
+}
+
+impl Levels {
+    /// Create levels with parameters
+    pub fn new(input: (Float, Float), gamma: Float, output: (Float, Float)) -> Self {
+        Self {
+            input_black: input.0,
+            input_white: input.1,
+            gamma,
+            output_black: output.0,
+            output_white: output.1,
+        }
+    }
+
+    /// Map component value
+    pub fn apply(&self, value: Float) -> Float {
+        let range = self.input_white - self.input_black;
+        let value = if range.abs() > Float::EPSILON {
+            clamp((value - self.input_black) / range, 0., 1.)
+        } else if value < self.input_black {
+            0.
+        } else {
+            1.
+        };
+        let value = value.powf(1. / self.gamma.max(0.01));
+        self.output_black + (self.output_white - self.output_black) * value
+    }
+}
+
+/// Tone curve through control points, interpolated with monotone cubic spline
+/// so the curve never overshoots between points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curve {
+    points: Vec<(Float, Float)>,
+    tangents: Vec<Float>,
+}
+
+impl Curve {
+    /// Create curve from control points `(input, output)` with values in `0..1`.
+    ///
+    /// Points are sorted by input, without points the curve is identity.
+    pub fn new(points: &[(Float, Float)]) -> Self {
+        let mut points = if points.is_empty() {
+            vec![(0., 0.), (1., 1.)]
+        } else {
+            points.to_vec()
+        };
+        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        points.dedup_by(|a, b| (a.0 - b.0).abs() <= Float::EPSILON);
+
+        let secants: Vec<Float> = points
+            .windows(2)
+            .map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0))
+            .collect();
+
+        // Fritsch-Carlson tangents
+        let mut tangents = vec![0.; points.len()];
+        if !secants.is_empty() {
+            tangents[0] = secants[0];
+            tangents[points.len() - 1] = secants[secants.len() - 1];
+        }
+        for idx in 1..secants.len() {
+            let (before, after) = (secants[idx - 1], secants[idx]);
+            tangents[idx] = if before * after <= 0. {
+                0.
+            } else {
+                3. * (before + after) / ((2. * after + before) / before + (after + 2. * before) / after)
+            };
+        }
+
+        Self { points, tangents }
+    }
+
+    /// Retrieve control points sorted by input
+    pub fn points(&self) -> &[(Float, Float)] {
+        &self.points
+    }
+
+    /// Map component value, values outside of control points keep the nearest point output.
+    ///
+    /// Curve with one point is constant, NaN is kept for other curves.
+    pub fn apply(&self, value: Float) -> Float {
+        let points = &self.points;
+        if points.len() < 2 {
+            return points[0].1;
+        }
+        if value.is_nan() {
+            return value;
+        }
+        let last = points.len() - 1;
+        if value <= points[0].0 {
+            return points[0].1;
+        }
+        if value >= points[last].0 {
+            return points[last].1;
+        }
+
+        let idx = points.iter().rposition(|point| point.0 <= value).unwrap_or(0);
+        let ((x0, y0), (x1, y1)) = (points[idx], points[idx + 1]);
+        let h = x1 - x0;
+        let t = (value - x0) / h;
+        let (t2, t3) = (t * t, t * t * t);
+
+        (2. * t3 - 3. * t2 + 1.) * y0
+            + (t3 - 2. * t2 + t) * h * self.tangents[idx]
+            + (-2. * t3 + 3. * t2) * y1
+            + (t3 - t2) * h * self.tangents[idx + 1]
+    }
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+/// Channel mixer, every output channel is a weighted sum of input red, green and blue plus offset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelMixer {
+    /// Weights of red, green and blue for output red
+    pub red: [Float; 3],
+    /// Weights of red, green and blue for output green
+    pub green: [Float; 3],
+    /// Weights of red, green and blue for output blue
+    pub blue: [Float; 3],
+    /// Offsets added to red, green and blue outputs
+    pub offset: [Float; 3],
+}
+
+impl Default for ChannelMixer {
+    fn default() -> Self {
+        Self {
+            red: [1., 0., 0.],
+            green: [0., 1., 0.],
+            blue: [0., 0., 1.],
+            offset: [0., 0., 0.],
+        }
+    }
+}
+
+impl ChannelMixer {
+    /// Create mixer with weights of output red, green and blue
+    pub fn new(red: [Float; 3], green: [Float; 3], blue: [Float; 3]) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            offset: [0., 0., 0.],
+        }
+    }
+
+    /// Create mixer which outputs the same weighted sum to all channels
+    pub fn monochrome(weights: [Float; 3]) -> Self {
+        Self::new(weights, weights, weights)
+    }
+
+    /// Create the classic sepia tone mixer
+    pub fn sepia() -> Self {
+        Self::new(
+            [0.393, 0.769, 0.189],
+            [0.349, 0.686, 0.168],
+            [0.272, 0.534, 0.131],
+        )
+    }
+
+    /// Mix the red, green and blue components, the results are clamped to `0..1`
+    pub fn apply(&self, rgb: [Float; 3]) -> [Float; 3] {
+        let row = |weights: &[Float; 3], offset: Float| {
+            let value = weights[0] * rgb[0] + weights[1] * rgb[1] + weights[2] * rgb[2] + offset;
+            clamp(value, 0., 1.)
+        };
+        [
+            row(&self.red, self.offset[0]),
+            row(&self.green, self.offset[1]),
+            row(&self.blue, self.offset[2]),
+        ]
+    }
+}
+
+/// Defines the levels and gamma adjustment functionality
+pub trait AdjustLevels: Sized {
+    /// Apply levels to red, green and blue components
+    fn levels(self, levels: &Levels) -> Self;
+    /// Apply gamma correction, values above one brighten midtones
+    fn gamma(self, gamma: Float) -> Self {
+        self.levels(&Levels {
+            gamma,
+            ..Default::default()
+        })
+    }
+}
+
+/// Defines the tone curve adjustment functionality
+pub trait AdjustCurve: Sized {
+    /// Apply tone curve to red, green and blue components
+    fn curve(self, curve: &Curve) -> Self;
+}
+
+/// Defines the color brightness and contrast functionality
+pub trait BrightnessContrast: Sized {
+    /// Adjust brightness with delta in `-100..100`
+    fn brightness(self, delta: Float) -> Self;
+    /// Adjust contrast around middle gray with delta in `-100..100`
+    fn contrast(self, delta: Float) -> Self;
+}
+
+/// Defines the color vibrance functionality
+pub trait Vibrance: Sized {
+    /// Saturate the color with delta, muted colors are affected more than saturated ones
+    fn vibrance(self, delta: Float) -> Self;
+}
+
+/// Defines the color tinting functionality
+pub trait Tint: Sized {
+    /// Mix the color with tint color by amount in `0..100`
+    fn tint(self, color: Color, amount: Float) -> Self;
+    /// Replace hue and saturation keeping lightness, like a monochrome photo toned with color
+    fn colorize(self, hue: Float, saturation: Float) -> Self;
+    /// Apply sepia tone by amount in `0..100`
+    fn sepia(self, amount: Float) -> Self;
+}
+
+/// Defines the channel mixer functionality
+pub trait MixChannels: Sized {
+    /// Mix red, green and blue components
+    fn mix_channels(self, mixer: &ChannelMixer) -> Self;
+}
+
+/// Apply function to red, green and blue components of color in any color space
+fn map_rgb<C: ColorTransition, F: Fn([Float; 3]) -> [Float; 3]>(color: C, map: F) -> C {
+    let Color {
+        red,
+        green,
+        blue,
+        alpha,
+    } = color.into();
+    let [red, green, blue] = map([red, green, blue]);
+    C::from(Color {
+        red,
+        green,
+        blue,
+        alpha,
+    })
+}
+
 impl GetHue for HslColor {
     fn get_hue(self) -> Float {
         self.hue
@@ -118,8 +390,21 @@ impl SetHue for HsvColor {
     }
 }
 
+impl GetHue for OklchColor {
+    fn get_hue(self) -> Float {
+        self.hue
+    }
+}
+impl SetHue for OklchColor {
+    fn set_hue(&mut self, hue: Float) -> Self {
+        self.hue = hue_bound(hue);
+        *self
+    }
+}
+
 impl HasHue for HslColor {}
 impl HasHue for HsvColor {}
+impl HasHue for OklchColor {}
 
 impl HasSaturation for HslColor {
     fn get_saturation(self) -> Float {
@@ -236,6 +521,98 @@ impl<C: ColorTransition> Invert for C {
     }
 }
 
+impl<C: ColorTransition> AdjustLevels for C {
+    fn levels(self, levels: &Levels) -> Self {
+        map_rgb(self, |rgb| {
+            [
+                levels.apply(rgb[0]),
+                levels.apply(rgb[1]),
+                levels.apply(rgb[2]),
+            ]
+        })
+    }
+}
+
+impl<C: ColorTransition> AdjustCurve for C {
+    fn curve(self, curve: &Curve) -> Self {
+        map_rgb(self, |rgb| [curve.apply(rgb[0]), curve.apply(rgb[1]), curve.apply(rgb[2])])
+    }
+}
+
+impl<C: ColorTransition> BrightnessContrast for C {
+    fn brightness(self, delta: Float) -> Self {
+        let delta = clamp(delta, -100., 100.) / 100.;
+        map_rgb(self, |rgb| rgb.map(|value| clamp(value + delta, 0., 1.)))
+    }
+
+    fn contrast(self, delta: Float) -> Self {
+        // slope from zero for -100 to vertical for 100
+        let angle = (clamp(delta, -100., 99.) / 100. + 1.) * std::f64::consts::FRAC_PI_4 as Float;
+        let slope = angle.tan();
+        map_rgb(self, |rgb| rgb.map(|value| clamp((value - 0.5) * slope + 0.5, 0., 1.)))
+    }
+}
+
+impl<C: ColorTransition> Vibrance for C {
+    fn vibrance(self, delta: Float) -> Self {
+        let delta = clamp(delta, -100., 100.) / 100.;
+        let color: Color = self.into();
+        let mut hsl = HslColor::from(color);
+        hsl.saturation = if delta >= 0. {
+            hsl.saturation + (100. - hsl.saturation) * delta
+        } else {
+            hsl.saturation * (1. + delta)
+        };
+        let mut adjusted = Color::from(hsl);
+        adjusted.alpha = color.alpha;
+        C::from(adjusted)
+    }
+}
+
+impl<C: ColorTransition> Tint for C {
+    fn tint(self, color: Color, amount: Float) -> Self {
+        let amount = clamp(amount, 0., 100.) / 100.;
+        let tint = [color.red, color.green, color.blue];
+        map_rgb(self, |rgb| {
+            [
+                rgb[0] + (tint[0] - rgb[0]) * amount,
+                rgb[1] + (tint[1] - rgb[1]) * amount,
+                rgb[2] + (tint[2] - rgb[2]) * amount,
+            ]
+        })
+    }
+
+    fn colorize(self, hue: Float, saturation: Float) -> Self {
+        let color: Color = self.into();
+        let mut adjusted = Color::from(HslColor {
+            hue: hue_bound(hue),
+            saturation: clamp(saturation, 0., 100.),
+            lightness: HslColor::from(color).lightness,
+        });
+        adjusted.alpha = color.alpha;
+        C::from(adjusted)
+    }
+
+    fn sepia(self, amount: Float) -> Self {
+        let amount = clamp(amount, 0., 100.) / 100.;
+        let mixer = ChannelMixer::sepia();
+        map_rgb(self, |rgb| {
+            let toned = mixer.apply(rgb);
+            [
+                rgb[0] + (toned[0] - rgb[0]) * amount,
+                rgb[1] + (toned[1] - rgb[1]) * amount,
+                rgb[2] + (toned[2] - rgb[2]) * amount,
+            ]
+        })
+    }
+}
+
+impl<C: ColorTransition> MixChannels for C {
+    fn mix_channels(self, mixer: &ChannelMixer) -> Self {
+        map_rgb(self, |rgb| mixer.apply(rgb))
+    }
+}
+
 // Implementations for colors with alpha channel
 impl<C: Lighten + ColorSpace> Lighten for Alpha<C> {
     fn lighten(self, delta: Float) -> Self {
@@ -261,6 +638,54 @@ impl<C: Invert + ColorSpace> Invert for Alpha<C> {
         Alpha::new(color.invert(), alpha)
     }
 }
+impl<C: AdjustLevels + ColorSpace> AdjustLevels for Alpha<C> {
+    fn levels(self, levels: &Levels) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.levels(levels), alpha)
+    }
+}
+impl<C: AdjustCurve + ColorSpace> AdjustCurve for Alpha<C> {
+    fn curve(self, curve: &Curve) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.curve(curve), alpha)
+    }
+}
+impl<C: BrightnessContrast + ColorSpace> BrightnessContrast for Alpha<C> {
+    fn brightness(self, delta: Float) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.brightness(delta), alpha)
+    }
+    fn contrast(self, delta: Float) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.contrast(delta), alpha)
+    }
+}
+impl<C: Vibrance + ColorSpace> Vibrance for Alpha<C> {
+    fn vibrance(self, delta: Float) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.vibrance(delta), alpha)
+    }
+}
+impl<C: Tint + ColorSpace> Tint for Alpha<C> {
+    fn tint(self, tint: Color, amount: Float) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.tint(tint, amount), alpha)
+    }
+    fn colorize(self, hue: Float, saturation: Float) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.colorize(hue, saturation), alpha)
+    }
+    fn sepia(self, amount: Float) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.sepia(amount), alpha)
+    }
+}
+impl<C: MixChannels + ColorSpace> MixChannels for Alpha<C> {
+    fn mix_channels(self, mixer: &ChannelMixer) -> Self {
+        let (color, alpha) = self.split();
+        Alpha::new(color.mix_channels(mixer), alpha)
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -385,4 +810,104 @@ mod test {
         assert_eq!(inverted.green, 195);
         assert_eq!(inverted.blue, 0);
     }
+
+    #[test]
+    fn levels_and_gamma() {
+        let color = RgbColor::new(51, 128, 204);
+        let stretched = color.levels(&Levels::new((0.2, 0.8), 1., (0., 1.)));
+        assert_eq!(stretched.red, 0);
+        assert_eq!(stretched.blue, 255);
+
+        let compressed = color.levels(&Levels::new((0., 1.), 1., (0.2, 0.8)));
+        assert_eq!(compressed.red, 82);
+
+        let brighter = color.gamma(2.2);
+        assert!(brighter.green > 128);
+        assert_eq!(color.gamma(1.), color);
+    }
+
+    #[test]
+    fn curve() {
+        let identity = Curve::default();
+        assert!((identity.apply(0.3) - 0.3).abs() < 1e-6);
+
+        let curve = Curve::new(&[(1., 1.), (0., 0.), (0.5, 0.7)]);
+        assert_eq!(curve.points()[1], (0.5, 0.7));
+        assert!((curve.apply(0.5) - 0.7).abs() < 1e-6);
+        let samples: Vec<Float> = (0..=20).map(|idx| curve.apply(idx as Float / 20.)).collect();
+        assert!(samples.windows(2).all(|pair| pair[0] <= pair[1]), "{:?}", samples);
+
+        let lifted = RgbColor::new(128, 128, 128).curve(&curve);
+        assert_eq!(lifted.red, 179);
+    }
+
+    #[test]
+    fn curve_edge_cases() {
+        let single = Curve::new(&[(0.3, 0.6)]);
+        for value in [0., 0.3, 1., Float::NAN] {
+            assert_eq!(single.apply(value), 0.6);
+        }
+        assert!(Curve::new(&[(0., 1.), (1., 0.)]).apply(Float::NAN).is_nan());
+        assert!(Curve::default().apply(Float::NAN).is_nan());
+    }
+
+    #[test]
+    fn brightness_contrast() {
+        let color = RgbColor::new(100, 150, 200);
+        assert_eq!(color.brightness(10.).red, 126);
+        assert_eq!(color.brightness(-100.), RgbColor::new(0, 0, 0));
+        assert_eq!(color.contrast(0.), color);
+        let flat = color.contrast(-100.);
+        assert_eq!((flat.red, flat.blue), (128, 128));
+        let strong = color.contrast(50.);
+        assert!(strong.red < 100 && strong.blue > 200);
+    }
+
+    #[test]
+    fn vibrance() {
+        let muted = RgbColor::new(140, 120, 100);
+        let vivid = RgbColor::new(220, 40, 40);
+        let muted_gain = muted.vibrance(50.).get_hsl_saturation() - muted.get_hsl_saturation();
+        let vivid_gain = vivid.vibrance(50.).get_hsl_saturation() - vivid.get_hsl_saturation();
+        assert!(muted_gain > vivid_gain);
+        assert_eq!(vivid.vibrance(-100.).grayscale(), vivid.vibrance(-100.));
+    }
+
+    #[test]
+    fn tint_and_sepia() {
+        let color = RgbColor::new(0, 100, 200);
+        let tinted = color.tint(Color::rgb(255, 255, 255), 50.);
+        assert_eq!(tinted, RgbColor::new(128, 178, 228));
+
+        let colorized = color.colorize(30., 80.);
+        assert_eq!(colorized.get_hue().round(), 30.);
+        assert_eq!(
+            HslColor::from(colorized).lightness.round(),
+            HslColor::from(color).lightness.round()
+        );
+
+        let sepia = RgbColor::new(128, 128, 128).sepia(100.);
+        assert!(sepia.red > sepia.green && sepia.green > sepia.blue);
+        assert_eq!(color.sepia(0.), color);
+    }
+
+    #[test]
+    fn channel_mixer() {
+        let swap = ChannelMixer::new([0., 0., 1.], [0., 1., 0.], [1., 0., 0.]);
+        assert_eq!(
+            RgbColor::new(10, 20, 30).mix_channels(&swap),
+            RgbColor::new(30, 20, 10)
+        );
+        let gray = RgbColor::new(255, 0, 0).mix_channels(&ChannelMixer::monochrome([0.3, 0.6, 0.1]));
+        assert_eq!(gray, RgbColor::new(77, 77, 77));
+    }
+
+    #[test]
+    fn alpha_adjustments() {
+        let color = Alpha::new(RgbColor::new(100, 150, 200), 0.5);
+        let adjusted = color.brightness(10.).sepia(50.).gamma(1.2);
+        let (rgb, alpha) = adjusted.split();
+        assert_eq!(alpha, 0.5);
+        assert_ne!(rgb, RgbColor::new(100, 150, 200));
+    }
 }
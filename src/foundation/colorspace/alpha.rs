@@ -67,6 +67,7 @@ pub trait AdjustAlpha<C>: Clone + SetAlpha<C> {
 
 /// Represents the color with aplha component
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Alpha<C: ColorSpace> {
     color: C,
     alpha: Float,
@@ -1,5 +1,6 @@
 use std::{default, fmt};
 
+#[cfg(any(feature = "color_quantization", test))]
 use crate::prelude::color;
 
 use super::prelude::*;
@@ -7,22 +8,38 @@ use super::prelude::*;
 use super::*;
 
 /// Basic color representation
+///
+/// Channels are stored as `f32` by default, use `Color<f64>` for HDR pipelines
+/// or `Color<u8>`, `Color<u16>` for texture data, see [Component].
+///
+/// Only storage is generic: [Color::from_components], [Color::from_array],
+/// [Color::to_array], [Color::cast], `Default` and `Display`. Color space conversions,
+/// adjustments, mixing and contrast are implemented for `f32` channels only,
+/// `cast` other precisions to `Color` to use them.
 #[derive(Clone, Copy, PartialEq, Debug)] // Eq, Hash 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "super::serde_hex::ColorRepr<T>",
+        bound(deserialize = "T: Component + serde::Deserialize<'de>")
+    )
+)]
 #[repr(C)]
-pub struct Color {
+pub struct Color<T = Float> {
     /// Red component
-    pub red: Float,
+    pub red: T,
     /// Green component
-    pub green: Float,
+    pub green: T,
     /// Blue component
-    pub blue: Float,
+    pub blue: T,
     /// Alpha component
-    pub alpha: Float,
+    pub alpha: T,
 }
 
 impl Color {
     /// Create color with floating point components
-    pub fn new(red: Float, green: Float, blue: Float, alpha: Float) -> Self {
+    pub const fn new(red: Float, green: Float, blue: Float, alpha: Float) -> Self {
         Color {
             red,
             green,
@@ -32,7 +49,7 @@ impl Color {
     }
 
     /// Create solid color with byte components
-    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
         Self::new(
             red as Float / 255.,
             green as Float / 255.,
@@ -42,7 +59,7 @@ impl Color {
     }
 
     /// Create color with byte components
-    pub fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
         Self::new(
             red as Float / 255.,
             green as Float / 255.,
@@ -51,6 +68,39 @@ impl Color {
         )
     }
 
+    /// Parse hex color in `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` form, the `#` is optional.
+    ///
+    /// Can be evaluated at compile time, see [color!](crate::color!) macro.
+    pub const fn try_from_hex(hex: &str) -> Option<Self> {
+        let bytes = hex.as_bytes();
+        let start = if !bytes.is_empty() && bytes[0] == b'#' { 1 } else { 0 };
+        let len = bytes.len() - start;
+        if !matches!(len, 3 | 4 | 6 | 8) {
+            return None;
+        }
+
+        let mut channels = [255u8; 4];
+        let mut idx = 0;
+        while idx < len {
+            let digit = match bytes[start + idx] {
+                ch @ b'0'..=b'9' => ch - b'0',
+                ch @ b'a'..=b'f' => ch - b'a' + 10,
+                ch @ b'A'..=b'F' => ch - b'A' + 10,
+                _ => return None,
+            };
+            if len <= 4 {
+                channels[idx] = digit * 17;
+            } else if idx % 2 == 0 {
+                channels[idx / 2] = digit << 4;
+            } else {
+                channels[idx / 2] |= digit;
+            }
+            idx += 1;
+        }
+
+        Some(Self::rgba(channels[0], channels[1], channels[2], channels[3]))
+    }
+
     /// Create solid color with using hsl color space
     pub fn hsl(hue: Float, saturation: Float, lightness: Float) -> Self {
         Self::from_color(HslColor::new(hue, saturation, lightness))
@@ -99,6 +149,30 @@ impl Color {
         color
     }
 
+    /// Create solid color with using oklab color space
+    pub fn oklab(l: Float, a: Float, b: Float) -> Self {
+        Self::from_color(OklabColor::new(l, a, b))
+    }
+
+    /// Create solid color with using oklab color space and alpha component
+    pub fn oklaba(l: Float, a: Float, b: Float, alpha: Float) -> Self {
+        let mut color = Self::from_color(OklabColor::new(l, a, b));
+        color.alpha = alpha;
+        color
+    }
+
+    /// Create solid color with using oklch color space
+    pub fn oklch(l: Float, chroma: Float, hue: Float) -> Self {
+        Self::from_color(OklchColor::new(l, chroma, hue))
+    }
+
+    /// Create solid color with using oklch color space and alpha component
+    pub fn oklcha(l: Float, chroma: Float, hue: Float, alpha: Float) -> Self {
+        let mut color = Self::from_color(OklchColor::new(l, chroma, hue));
+        color.alpha = alpha;
+        color
+    }
+
     /// Create solid color with using xyz color space
     #[cfg(feature = "experimental")]
     pub fn xyz(x: Float, y: Float, z: Float) -> Self {
@@ -188,15 +262,70 @@ impl Color {
     }
 }
 
-impl fmt::Display for Color {
+impl<T: Component> Color<T> {
+    /// Create color with components of any precision
+    pub const fn from_components(red: T, green: T, blue: T, alpha: T) -> Self {
+        Color {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Create color from array of red, green, blue and alpha components
+    pub const fn from_array([red, green, blue, alpha]: [T; 4]) -> Self {
+        Self::from_components(red, green, blue, alpha)
+    }
+
+    /// Retrieve array of red, green, blue and alpha components
+    pub fn to_array(&self) -> [T; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+
+    /// Convert color to another precision.
+    ///
+    /// Widening conversions are also available with `From`, narrowing ones are rounded
+    /// and integer targets are clamped, e.g. `Color<f32>` to `Color<u8>`.
+    pub fn cast<U: Component>(&self) -> Color<U> {
+        let channel = |value: T| U::from_normalized(value.to_normalized());
+        Color {
+            red: channel(self.red),
+            green: channel(self.green),
+            blue: channel(self.blue),
+            alpha: channel(self.alpha),
+        }
+    }
+}
+
+macro_rules! promote_color {
+    ($($from:ty => $to:ty),*) => {$(
+        impl From<Color<$from>> for Color<$to> {
+            fn from(color: Color<$from>) -> Self {
+                color.cast()
+            }
+        }
+    )*};
+}
+
+promote_color!(
+    u8 => u16,
+    u8 => f32,
+    u8 => f64,
+    u16 => f32,
+    u16 => f64,
+    f32 => f64
+);
+
+impl<T: Component + fmt::Display> fmt::Display for Color<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "rgb({}, {}, {})", self.red, self.green, self.blue)
     }
 }
 
-impl default::Default for Color {
+impl<T: Component> default::Default for Color<T> {
     fn default() -> Self {
-        color::BLACK
+        Self::from_components(T::ZERO, T::ZERO, T::ZERO, T::MAX)
     }
 }
 
@@ -210,6 +339,16 @@ pub enum ColorError {
     DegreeOverflow,
     /// Unimplementer error
     Unimplemented,
+    /// Invalid hex string error
+    InvalidHex,
+    /// Malformed ICC profile error
+    InvalidProfile,
+    /// Unsupported kind of ICC profile error
+    UnsupportedProfile,
+    /// Unknown blend mode name error
+    UnknownBlendMode,
+    /// Custom hue wheel not covering the wheel error
+    InvalidHueWheel,
 }
 
 impl fmt::Display for ColorError {
@@ -224,6 +363,20 @@ impl fmt::Display for ColorError {
                 "Overflow of Hue in hsl(v) color space (can't be greater than 360 deg"
             ),
             Self::Unimplemented => write!(f, "Unimplemented color conversion"),
+            Self::InvalidHex => write!(
+                f,
+                "Invalid hex color (expected #rgb, #rgba, #rrggbb or #rrggbbaa)"
+            ),
+            Self::InvalidProfile => write!(f, "Malformed or truncated ICC profile"),
+            Self::UnsupportedProfile => write!(
+                f,
+                "Unsupported ICC profile (only matrix/TRC RGB and gray profiles are supported)"
+            ),
+            Self::UnknownBlendMode => write!(f, "Unknown blend mode name"),
+            Self::InvalidHueWheel => write!(
+                f,
+                "Invalid hue wheel (angles must grow from 0 to 360 and hues must go once around)"
+            ),
         }
     }
 }
@@ -348,4 +501,41 @@ pub mod test {
             }
         }
     }
+
+    #[test]
+    fn precision() {
+        let texel = Color::<u8>::from_array([18, 184, 134, 255]);
+
+        let deep: Color<u16> = texel.into();
+        assert_eq!(deep.to_array(), [4626, 47288, 34438, 65535]);
+
+        let color: Color = texel.into();
+        assert_eq!(color, Color::rgb(18, 184, 134));
+        assert_eq!(color.cast::<u8>(), texel);
+        assert_eq!(Color::<f64>::from(deep).cast::<u8>(), texel);
+
+        // narrowing is explicit and clamps out of range values
+        let hdr = Color::<f64>::from_components(2.0, 0.5, -1.0, 1.0);
+        assert_eq!(hdr.cast::<u8>().to_array(), [255, 128, 0, 255]);
+        assert_eq!(hdr.cast::<f32>().red, 2.0);
+
+        assert_eq!(Color::<u16>::default().to_array(), [0, 0, 0, 65535]);
+        assert_eq!(Color::default(), color::BLACK);
+    }
+
+    #[test]
+    fn const_constructors() {
+        const TEAL: Color = Color::rgb(18, 184, 134);
+        assert_eq!(Color::try_from_hex("#12b886"), Some(TEAL));
+        assert_eq!(Color::try_from_hex("12B886FF"), Some(TEAL));
+        assert_eq!(Color::try_from_hex("#fff"), Some(color::WHITE));
+        assert_eq!(Color::try_from_hex("#0000"), Some(Color::rgba(0, 0, 0, 0)));
+        assert_eq!(Color::try_from_hex("#12b88"), None);
+        assert_eq!(Color::try_from_hex("#12b88x"), None);
+        assert_eq!(Color::try_from_hex(""), None);
+
+        assert_eq!(crate::color!("#1c7ed6"), color::BLUE_7);
+        assert_eq!(crate::color!(rgb 28, 126, 214), color::BLUE_7);
+        assert_eq!(crate::color!(rgba 255, 255, 255, 0), color::TRANSPARENT);
+    }
 }
@@ -0,0 +1,547 @@
+//! Batch conversion of many colors at once.
+//!
+//! [convert_slice] works for any pair of color spaces and is a plain loop over `From`.
+//!
+//! For hot paths the colors can be split into planes (structure of arrays), one `Vec` per channel.
+//! Plane kernels have no early exits and their conditions only pick between computed values,
+//! so the compiler can lower them to selects and auto-vectorize the loops. With `simd` feature
+//! RGB to HSV/HSL kernels use SSE2 on `x86_64` explicitly.
+//!
+//! ```
+//! use primitives::foundation::colorspace::{batch::ColorPlanes, Color};
+//!
+//! let frame = vec![Color::rgb(255, 0, 128); 1024];
+//!
+//! let mut hsl = ColorPlanes::from_colors(&frame).to_hsl();
+//! hsl.lightness.iter_mut().for_each(|l| *l *= 0.5);
+//!
+//! let darker = hsl.to_rgb().to_colors();
+//! assert_eq!(darker.len(), frame.len());
+//! ```
+
+use super::{prelude::FromColor, Color, Float, HslColor, HsvColor};
+
+/// Convert slice of colors from one color space into another.
+///
+/// # Panics
+///
+/// Panics if slices have different length.
+pub fn convert_slice<Fr, To>(src: &[Fr], dst: &mut [To])
+where
+    Fr: Clone,
+    To: FromColor<Fr>,
+{
+    assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+    for (to, from) in dst.iter_mut().zip(src) {
+        *to = To::from_color(from.clone());
+    }
+}
+
+/// Convert slice of colors from one color space into new vector
+pub fn convert_vec<Fr, To>(src: &[Fr]) -> Vec<To>
+where
+    Fr: Clone,
+    To: FromColor<Fr>,
+{
+    src.iter().cloned().map(To::from_color).collect()
+}
+
+/// Rgb colors split into channel planes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColorPlanes {
+    /// Red components
+    pub red: Vec<Float>,
+    /// Green components
+    pub green: Vec<Float>,
+    /// Blue components
+    pub blue: Vec<Float>,
+    /// Alpha components
+    pub alpha: Vec<Float>,
+}
+
+impl ColorPlanes {
+    /// Split colors into planes
+    pub fn from_colors(colors: &[Color]) -> Self {
+        Self {
+            red: colors.iter().map(|c| c.red).collect(),
+            green: colors.iter().map(|c| c.green).collect(),
+            blue: colors.iter().map(|c| c.blue).collect(),
+            alpha: colors.iter().map(|c| c.alpha).collect(),
+        }
+    }
+
+    /// Retrieve count of colors
+    pub fn len(&self) -> usize {
+        self.red.len()
+    }
+
+    /// Check if there are no colors
+    pub fn is_empty(&self) -> bool {
+        self.red.is_empty()
+    }
+
+    /// Join planes into colors
+    pub fn to_colors(&self) -> Vec<Color> {
+        let mut colors = vec![Color::default(); self.len()];
+        self.write_colors(&mut colors);
+        colors
+    }
+
+    /// Join planes into existing slice of colors
+    ///
+    /// # Panics
+    ///
+    /// Panics if slice has different length.
+    pub fn write_colors(&self, dst: &mut [Color]) {
+        assert_eq!(self.len(), dst.len(), "Slices must have the same length");
+        for (idx, color) in dst.iter_mut().enumerate() {
+            *color = Color::new(self.red[idx], self.green[idx], self.blue[idx], self.alpha[idx]);
+        }
+    }
+
+    /// Convert all colors to Hsv color space, alpha is kept
+    pub fn to_hsv(&self) -> HsvPlanes {
+        let mut hsv = HsvPlanes {
+            hue: vec![0.0; self.len()],
+            saturation: vec![0.0; self.len()],
+            value: vec![0.0; self.len()],
+            alpha: self.alpha.clone(),
+        };
+        kernel::rgb_to_hsv(
+            [&self.red, &self.green, &self.blue],
+            [&mut hsv.hue, &mut hsv.saturation, &mut hsv.value],
+        );
+        hsv
+    }
+
+    /// Convert all colors to Hsl color space, alpha is kept
+    pub fn to_hsl(&self) -> HslPlanes {
+        let mut hsl = HslPlanes {
+            hue: vec![0.0; self.len()],
+            saturation: vec![0.0; self.len()],
+            lightness: vec![0.0; self.len()],
+            alpha: self.alpha.clone(),
+        };
+        kernel::rgb_to_hsl(
+            [&self.red, &self.green, &self.blue],
+            [&mut hsl.hue, &mut hsl.saturation, &mut hsl.lightness],
+        );
+        hsl
+    }
+}
+
+/// Hsv colors split into channel planes, the same units as [HsvColor]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HsvPlanes {
+    /// Hue components, degrees in `0..360`
+    pub hue: Vec<Float>,
+    /// Saturation components, percent
+    pub saturation: Vec<Float>,
+    /// Value components, percent
+    pub value: Vec<Float>,
+    /// Alpha components
+    pub alpha: Vec<Float>,
+}
+
+impl HsvPlanes {
+    /// Split colors into planes, colors are opaque
+    pub fn from_colors(colors: &[HsvColor]) -> Self {
+        Self {
+            hue: colors.iter().map(|c| c.hue).collect(),
+            saturation: colors.iter().map(|c| c.saturation).collect(),
+            value: colors.iter().map(|c| c.value).collect(),
+            alpha: vec![1.0; colors.len()],
+        }
+    }
+
+    /// Retrieve count of colors
+    pub fn len(&self) -> usize {
+        self.hue.len()
+    }
+
+    /// Check if there are no colors
+    pub fn is_empty(&self) -> bool {
+        self.hue.is_empty()
+    }
+
+    /// Join planes into colors, alpha is dropped
+    pub fn to_colors(&self) -> Vec<HsvColor> {
+        (0..self.len())
+            .map(|idx| HsvColor {
+                hue: self.hue[idx],
+                saturation: self.saturation[idx],
+                value: self.value[idx],
+            })
+            .collect()
+    }
+
+    /// Convert all colors to Rgb color space, alpha is kept
+    pub fn to_rgb(&self) -> ColorPlanes {
+        let mut rgb = ColorPlanes {
+            red: vec![0.0; self.len()],
+            green: vec![0.0; self.len()],
+            blue: vec![0.0; self.len()],
+            alpha: self.alpha.clone(),
+        };
+        kernel::hsv_to_rgb(
+            [&self.hue, &self.saturation, &self.value],
+            [&mut rgb.red, &mut rgb.green, &mut rgb.blue],
+        );
+        rgb
+    }
+}
+
+/// Hsl colors split into channel planes, the same units as [HslColor]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HslPlanes {
+    /// Hue components, degrees in `0..360`
+    pub hue: Vec<Float>,
+    /// Saturation components, percent
+    pub saturation: Vec<Float>,
+    /// Lightness components, percent
+    pub lightness: Vec<Float>,
+    /// Alpha components
+    pub alpha: Vec<Float>,
+}
+
+impl HslPlanes {
+    /// Split colors into planes, colors are opaque
+    pub fn from_colors(colors: &[HslColor]) -> Self {
+        Self {
+            hue: colors.iter().map(|c| c.hue).collect(),
+            saturation: colors.iter().map(|c| c.saturation).collect(),
+            lightness: colors.iter().map(|c| c.lightness).collect(),
+            alpha: vec![1.0; colors.len()],
+        }
+    }
+
+    /// Retrieve count of colors
+    pub fn len(&self) -> usize {
+        self.hue.len()
+    }
+
+    /// Check if there are no colors
+    pub fn is_empty(&self) -> bool {
+        self.hue.is_empty()
+    }
+
+    /// Join planes into colors, alpha is dropped
+    pub fn to_colors(&self) -> Vec<HslColor> {
+        (0..self.len())
+            .map(|idx| HslColor {
+                hue: self.hue[idx],
+                saturation: self.saturation[idx],
+                lightness: self.lightness[idx],
+            })
+            .collect()
+    }
+
+    /// Convert all colors to Rgb color space, alpha is kept
+    pub fn to_rgb(&self) -> ColorPlanes {
+        let mut rgb = ColorPlanes {
+            red: vec![0.0; self.len()],
+            green: vec![0.0; self.len()],
+            blue: vec![0.0; self.len()],
+            alpha: self.alpha.clone(),
+        };
+        kernel::hsl_to_rgb(
+            [&self.hue, &self.saturation, &self.lightness],
+            [&mut rgb.red, &mut rgb.green, &mut rgb.blue],
+        );
+        rgb
+    }
+}
+
+/// Per channel kernels with conditions only picking values, written to be auto-vectorized
+mod kernel {
+    use super::Float;
+
+    /// Wrap hue into `0..360`, assumes it is at most one turn away
+    #[inline(always)]
+    fn wrap_hue(hue: Float) -> Float {
+        if hue < 0.0 {
+            hue + 360.0
+        } else if hue >= 360.0 {
+            hue - 360.0
+        } else {
+            hue
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn hue(red: Float, green: Float, blue: Float, max: Float, delta: Float) -> Float {
+        let inv = if delta > 0.0 { 1.0 / delta } else { 0.0 };
+        let sector = if max == red {
+            (green - blue) * inv
+        } else if max == green {
+            (blue - red) * inv + 2.0
+        } else {
+            (red - green) * inv + 4.0
+        };
+        wrap_hue(sector * 60.0)
+    }
+
+    pub(super) fn rgb_to_hsv(rgb: [&[Float]; 3], hsv: [&mut [Float]; 3]) {
+        let [red, green, blue] = rgb;
+        let [hue, saturation, value] = hsv;
+
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        let start = super::sse::rgb_to_hsv([red, green, blue], [hue, saturation, value]);
+        #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+        let start = 0;
+
+        for idx in start..red.len() {
+            let (r, g, b) = (red[idx], green[idx], blue[idx]);
+            let max = r.max(g).max(b);
+            let delta = max - r.min(g).min(b);
+
+            hue[idx] = self::hue(r, g, b, max, delta);
+            saturation[idx] = if max > 0.0 { delta / max * 100.0 } else { 0.0 };
+            value[idx] = max * 100.0;
+        }
+    }
+
+    pub(super) fn rgb_to_hsl(rgb: [&[Float]; 3], hsl: [&mut [Float]; 3]) {
+        let [red, green, blue] = rgb;
+        let [hue, saturation, lightness] = hsl;
+
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        let start = super::sse::rgb_to_hsl([red, green, blue], [hue, saturation, lightness]);
+        #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+        let start = 0;
+
+        for idx in start..red.len() {
+            let (r, g, b) = (red[idx], green[idx], blue[idx]);
+            let max = r.max(g).max(b);
+            let min = r.min(g).min(b);
+            let delta = max - min;
+            let l = (max + min) / 2.0;
+
+            hue[idx] = self::hue(r, g, b, max, delta);
+            saturation[idx] = if delta > 0.0 {
+                delta / (1.0 - (2.0 * l - 1.0).abs()) * 100.0
+            } else {
+                0.0
+            };
+            lightness[idx] = l * 100.0;
+        }
+    }
+
+    pub(super) fn hsv_to_rgb(hsv: [&[Float]; 3], rgb: [&mut [Float]; 3]) {
+        let [hue, saturation, value] = hsv;
+        let [red, green, blue] = rgb;
+
+        for idx in 0..hue.len() {
+            let h = wrap_hue(hue[idx]) / 60.0;
+            let v = (value[idx] / 100.0).clamp(0.0, 1.0);
+            let chroma = v * (saturation[idx] / 100.0).clamp(0.0, 1.0);
+
+            // f(n) = v - c * max(0, min(k, 4 - k, 1)), k = (n + h) mod 6
+            let channel = |n: Float| {
+                let k = n + h;
+                let k = if k >= 6.0 { k - 6.0 } else { k };
+                v - chroma * k.min(4.0 - k).clamp(0.0, 1.0)
+            };
+            red[idx] = channel(5.0);
+            green[idx] = channel(3.0);
+            blue[idx] = channel(1.0);
+        }
+    }
+
+    pub(super) fn hsl_to_rgb(hsl: [&[Float]; 3], rgb: [&mut [Float]; 3]) {
+        let [hue, saturation, lightness] = hsl;
+        let [red, green, blue] = rgb;
+
+        for idx in 0..hue.len() {
+            let h = wrap_hue(hue[idx]) / 30.0;
+            let l = (lightness[idx] / 100.0).clamp(0.0, 1.0);
+            let a = (saturation[idx] / 100.0).clamp(0.0, 1.0) * l.min(1.0 - l);
+
+            // f(n) = l - a * max(-1, min(k - 3, 9 - k, 1)), k = (n + h) mod 12
+            let channel = |n: Float| {
+                let k = n + h;
+                let k = if k >= 12.0 { k - 12.0 } else { k };
+                l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+            };
+            red[idx] = channel(0.0);
+            green[idx] = channel(8.0);
+            blue[idx] = channel(4.0);
+        }
+    }
+}
+
+/// Explicit SSE2 kernels, SSE2 is always available on `x86_64`.
+///
+/// Kernels process groups of four colors and return count of processed ones,
+/// the rest is handled by the scalar code.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse {
+    use std::arch::x86_64::*;
+
+    use super::Float;
+
+    /// Compute hue in degrees, max and min for four colors
+    #[inline(always)]
+    unsafe fn hue(r: __m128, g: __m128, b: __m128) -> (__m128, __m128, __m128) {
+        let max = _mm_max_ps(_mm_max_ps(r, g), b);
+        let min = _mm_min_ps(_mm_min_ps(r, g), b);
+        let delta = _mm_sub_ps(max, min);
+        let chromatic = _mm_cmpgt_ps(delta, _mm_setzero_ps());
+        let inv = _mm_and_ps(_mm_div_ps(_mm_set1_ps(1.0), delta), chromatic);
+
+        let from_red = _mm_mul_ps(_mm_sub_ps(g, b), inv);
+        let from_green = _mm_add_ps(_mm_mul_ps(_mm_sub_ps(b, r), inv), _mm_set1_ps(2.0));
+        let from_blue = _mm_add_ps(_mm_mul_ps(_mm_sub_ps(r, g), inv), _mm_set1_ps(4.0));
+
+        let is_red = _mm_cmpeq_ps(max, r);
+        let is_green = _mm_andnot_ps(is_red, _mm_cmpeq_ps(max, g));
+        let is_blue = _mm_andnot_ps(_mm_or_ps(is_red, is_green), chromatic);
+
+        let sector = _mm_or_ps(
+            _mm_and_ps(from_red, is_red),
+            _mm_or_ps(_mm_and_ps(from_green, is_green), _mm_and_ps(from_blue, is_blue)),
+        );
+        let hue = _mm_and_ps(_mm_mul_ps(sector, _mm_set1_ps(60.0)), chromatic);
+        let negative = _mm_cmplt_ps(hue, _mm_setzero_ps());
+        let hue = _mm_add_ps(hue, _mm_and_ps(_mm_set1_ps(360.0), negative));
+
+        (hue, max, min)
+    }
+
+    pub(super) fn rgb_to_hsv(rgb: [&[Float]; 3], hsv: [&mut [Float]; 3]) -> usize {
+        let [red, green, blue] = rgb;
+        let [hue, saturation, value] = hsv;
+        let len = red.len() / 4 * 4;
+        assert!(green.len() >= len && blue.len() >= len);
+        assert!(hue.len() >= len && saturation.len() >= len && value.len() >= len);
+
+        for idx in (0..len).step_by(4) {
+            // SAFETY: bounds are checked above, loads and stores are unaligned
+            unsafe {
+                let r = _mm_loadu_ps(red.as_ptr().add(idx));
+                let g = _mm_loadu_ps(green.as_ptr().add(idx));
+                let b = _mm_loadu_ps(blue.as_ptr().add(idx));
+                let (h, max, min) = self::hue(r, g, b);
+
+                let positive = _mm_cmpgt_ps(max, _mm_setzero_ps());
+                let s = _mm_and_ps(_mm_div_ps(_mm_sub_ps(max, min), max), positive);
+                let percent = _mm_set1_ps(100.0);
+
+                _mm_storeu_ps(hue.as_mut_ptr().add(idx), h);
+                _mm_storeu_ps(saturation.as_mut_ptr().add(idx), _mm_mul_ps(s, percent));
+                _mm_storeu_ps(value.as_mut_ptr().add(idx), _mm_mul_ps(max, percent));
+            }
+        }
+        len
+    }
+
+    pub(super) fn rgb_to_hsl(rgb: [&[Float]; 3], hsl: [&mut [Float]; 3]) -> usize {
+        let [red, green, blue] = rgb;
+        let [hue, saturation, lightness] = hsl;
+        let len = red.len() / 4 * 4;
+        assert!(green.len() >= len && blue.len() >= len);
+        assert!(hue.len() >= len && saturation.len() >= len && lightness.len() >= len);
+
+        for idx in (0..len).step_by(4) {
+            // SAFETY: bounds are checked above, loads and stores are unaligned
+            unsafe {
+                let r = _mm_loadu_ps(red.as_ptr().add(idx));
+                let g = _mm_loadu_ps(green.as_ptr().add(idx));
+                let b = _mm_loadu_ps(blue.as_ptr().add(idx));
+                let (h, max, min) = self::hue(r, g, b);
+
+                let one = _mm_set1_ps(1.0);
+                let delta = _mm_sub_ps(max, min);
+                let l = _mm_mul_ps(_mm_add_ps(max, min), _mm_set1_ps(0.5));
+                // 1 - |2l - 1|, the absolute value clears the sign bit
+                let edge = _mm_andnot_ps(
+                    _mm_set1_ps(-0.0),
+                    _mm_sub_ps(_mm_add_ps(l, l), one),
+                );
+                let chromatic = _mm_cmpgt_ps(delta, _mm_setzero_ps());
+                let s = _mm_and_ps(_mm_div_ps(delta, _mm_sub_ps(one, edge)), chromatic);
+                let percent = _mm_set1_ps(100.0);
+
+                _mm_storeu_ps(hue.as_mut_ptr().add(idx), h);
+                _mm_storeu_ps(saturation.as_mut_ptr().add(idx), _mm_mul_ps(s, percent));
+                _mm_storeu_ps(lightness.as_mut_ptr().add(idx), _mm_mul_ps(l, percent));
+            }
+        }
+        len
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::color;
+
+    fn samples() -> Vec<Color> {
+        let mut colors: Vec<Color> = color::RAMPS
+            .iter()
+            .flat_map(|(_, ramp)| ramp.iter().copied())
+            .collect();
+        colors.extend_from_slice(&[
+            Color::rgb(255, 0, 128),
+            Color::rgb(0, 0, 0),
+            Color::rgb(255, 255, 255),
+            Color::rgb(90, 90, 90),
+        ]);
+        colors
+    }
+
+    fn assert_close(left: Float, right: Float, tolerance: Float) {
+        assert!((left - right).abs() <= tolerance, "{} != {}", left, right);
+    }
+
+    #[test]
+    fn generic_slices() {
+        let colors = samples();
+        let mut hsl = vec![HslColor::new(0.0, 0.0, 0.0); colors.len()];
+        convert_slice(&colors, &mut hsl);
+
+        for (color, hsl) in colors.iter().zip(&hsl) {
+            assert_eq!(*hsl, HslColor::from(*color));
+        }
+        assert_eq!(convert_vec::<HslColor, HsvColor>(&hsl).len(), colors.len());
+    }
+
+    #[test]
+    fn planes_match_scalar() {
+        let colors = samples();
+        let planes = ColorPlanes::from_colors(&colors);
+
+        let hsv = planes.to_hsv().to_colors();
+        let hsl = planes.to_hsl().to_colors();
+        for (idx, color) in colors.iter().enumerate() {
+            let scalar = HsvColor::from(*color);
+            assert_close(hsv[idx].hue, scalar.hue, 0.01);
+            assert_close(hsv[idx].saturation, scalar.saturation, 0.01);
+            assert_close(hsv[idx].value, scalar.value, 0.01);
+
+            let scalar = HslColor::from(*color);
+            assert_close(hsl[idx].hue, scalar.hue, 0.01);
+            assert_close(hsl[idx].saturation, scalar.saturation, 0.01);
+            assert_close(hsl[idx].lightness, scalar.lightness, 0.01);
+        }
+    }
+
+    #[test]
+    fn planes_roundtrip() {
+        let mut colors = samples();
+        colors[0].alpha = 0.25;
+        let planes = ColorPlanes::from_colors(&colors);
+
+        for restored in [planes.to_hsv().to_rgb(), planes.to_hsl().to_rgb()].iter() {
+            assert_eq!(restored.alpha, planes.alpha);
+            for (idx, color) in restored.to_colors().iter().enumerate() {
+                assert_close(color.red, colors[idx].red, 0.0001);
+                assert_close(color.green, colors[idx].green, 0.0001);
+                assert_close(color.blue, colors[idx].blue, 0.0001);
+            }
+        }
+
+        let scalar: Color = HslPlanes::from_colors(&[HslColor::new(200.0, 50.0, 40.0)]).to_rgb().to_colors()[0];
+        assert_eq!(scalar.cast::<u8>(), Color::from(HslColor::new(200.0, 50.0, 40.0)).cast::<u8>());
+    }
+}
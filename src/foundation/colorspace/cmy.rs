@@ -3,6 +3,7 @@ use std::fmt;
 
 /// Cmy color representation
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CmyColor {
     /// Cyan component
     pub cyan: Float,
@@ -3,6 +3,7 @@ use std::fmt;
 
 /// Cmyk color representation
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CmykColor {
     /// Cyan component
     pub cyan: Float,
@@ -58,7 +59,13 @@ impl From<Color> for CmykColor {
                 .cloned()
                 .fold(Float::NAN, Float::max);
 
-        let apply = |v: Float| (((1. - v - key) / (1. - key)) * 100.).round();
+        let apply = |v: Float| {
+            if key < 1. {
+                (((1. - v - key) / (1. - key)) * 100.).round()
+            } else {
+                0.
+            }
+        };
         CmykColor {
             cyan: apply(rgb.red),
             magenta: apply(rgb.green),
@@ -68,6 +75,127 @@ impl From<Color> for CmykColor {
     }
 }
 
+/// Which colors get black ink
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlackGeneration {
+    /// Gray component replacement, black replaces the gray component of every color
+    #[default]
+    Gcr,
+    /// Under color removal, black replaces the gray component of neutral colors only,
+    /// fading out as saturation grows
+    Ucr,
+}
+
+/// Parameters of RGB to CMYK separation for print.
+///
+/// Black is generated from the gray component `1 - max(RGB)` along a ramp shaped
+/// by its start and exponent, then cyan, magenta and yellow are computed so the color
+/// is reproduced exactly, finally the total ink is limited.
+///
+/// The default parameters give the simple `K = 1 - max(RGB)` conversion
+/// of `From<Color> for CmykColor`, without its rounding.
+///
+/// ```
+/// use primitives::foundation::colorspace::{BlackGeneration, Color, Separation};
+///
+/// let separation = Separation {
+///     mode: BlackGeneration::Ucr,
+///     black_start: 0.3,
+///     ink_limit: 300.0,
+///     ..Default::default()
+/// };
+/// let cmyk = separation.separate(Color::rgb(20, 10, 15));
+/// assert!(cmyk.total_ink() <= 300.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Separation {
+    /// Colors which get black ink
+    pub mode: BlackGeneration,
+    /// Strength of black generation in `0..1`, zero gives CMY only
+    pub black_strength: Float,
+    /// Gray component in `0..1` where black generation starts, black ramps up to full strength above it
+    pub black_start: Float,
+    /// Shape of black ramp above start, `1` is linear, larger values keep black out of midtones
+    pub black_exponent: Float,
+    /// Largest amount of black ink in `0..1`
+    pub max_black: Float,
+    /// Total area coverage limit in percent, `400` means no limit
+    pub ink_limit: Float,
+}
+
+impl Default for Separation {
+    fn default() -> Self {
+        Self {
+            mode: BlackGeneration::Gcr,
+            black_strength: 1.0,
+            black_start: 0.0,
+            black_exponent: 1.0,
+            max_black: 1.0,
+            ink_limit: 400.0,
+        }
+    }
+}
+
+impl Separation {
+    /// Separate color into Cmyk components in percent, alpha is ignored
+    pub fn separate(&self, color: Color) -> CmykColor {
+        let red = color.red.clamp(0.0, 1.0);
+        let green = color.green.clamp(0.0, 1.0);
+        let blue = color.blue.clamp(0.0, 1.0);
+
+        let max = red.max(green).max(blue);
+        let gray = 1.0 - max;
+
+        let weight = match self.mode {
+            BlackGeneration::Gcr => 1.0,
+            // one minus saturation of Hsv space
+            BlackGeneration::Ucr if max > 0.0 => red.min(green).min(blue) / max,
+            BlackGeneration::Ucr => 1.0,
+        };
+
+        let start = self.black_start.clamp(0.0, 1.0);
+        let ramp = if gray > start {
+            ((gray - start) / (1.0 - start)).powf(self.black_exponent.max(0.0))
+        } else {
+            0.0
+        };
+        let key = (self.black_strength.clamp(0.0, 1.0) * weight * ramp)
+            .min(self.max_black.clamp(0.0, 1.0))
+            .min(gray);
+
+        // inks multiply, so remaining color is `(1 - c) * (1 - k)`
+        let apply = |value: Float| {
+            if key < 1.0 {
+                (1.0 - value / (1.0 - key)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+        let (mut cyan, mut magenta, mut yellow) = (apply(red), apply(green), apply(blue));
+
+        let limit = self.ink_limit.clamp(0.0, 400.0) / 100.0;
+        let key = key.min(limit);
+        let colored = cyan + magenta + yellow;
+        if colored + key > limit && colored > 0.0 {
+            let scale = (limit - key) / colored;
+            cyan *= scale;
+            magenta *= scale;
+            yellow *= scale;
+        }
+
+        CmykColor::new(cyan * 100.0, magenta * 100.0, yellow * 100.0, key * 100.0)
+    }
+}
+
+impl CmykColor {
+    /// Retrieve total area coverage in percent, the sum of all components
+    pub fn total_ink(&self) -> Float {
+        self.cyan + self.magenta + self.yellow + self.key
+    }
+}
+
 #[cfg(test)]
 mod test {
     // use super::super::*;
@@ -120,4 +248,98 @@ mod test {
     //         );
     //     }
     // }
+
+    use super::*;
+
+    fn assert_close(actual: CmykColor, expected: CmykColor) {
+        let pairs = [
+            (actual.cyan, expected.cyan),
+            (actual.magenta, expected.magenta),
+            (actual.yellow, expected.yellow),
+            (actual.key, expected.key),
+        ];
+        for (a, e) in pairs.iter() {
+            assert!((a - e).abs() < 0.6, "{} != {}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn default_separation() {
+        for color in [
+            Color::rgb(56, 217, 169),
+            Color::rgb(18, 184, 134),
+            Color::rgb(40, 40, 40),
+            Color::rgb(0, 0, 0),
+        ]
+        .iter()
+        {
+            assert_close(Separation::default().separate(*color), CmykColor::from(*color));
+        }
+    }
+
+    #[test]
+    fn black_generation() {
+        let gray = Color::rgb(64, 64, 64);
+        let teal = Color::rgb(18, 120, 90);
+
+        let none = Separation {
+            black_strength: 0.0,
+            ..Default::default()
+        };
+        let cmy = none.separate(gray);
+        assert_eq!(cmy.key, 0.0);
+        assert!((cmy.cyan - 74.9).abs() < 0.1);
+
+        // reproduced color does not depend on amount of black
+        let half = Separation {
+            black_strength: 0.5,
+            ..Default::default()
+        };
+        for color in [gray, teal].iter() {
+            let back = Color::from(half.separate(*color));
+            assert!((back.red - color.red).abs() < 0.001);
+            assert!((back.green - color.green).abs() < 0.001);
+        }
+
+        let ucr = Separation {
+            mode: BlackGeneration::Ucr,
+            ..Default::default()
+        };
+        assert!((ucr.separate(gray).key - 74.9).abs() < 0.1);
+        assert!(ucr.separate(teal).key < Separation::default().separate(teal).key);
+        assert_eq!(ucr.separate(Color::rgb(200, 0, 0)).key, 0.0);
+
+        let start = Separation {
+            black_start: 0.5,
+            max_black: 0.4,
+            ..Default::default()
+        };
+        assert_eq!(start.separate(Color::rgb(200, 200, 200)).key, 0.0);
+        assert!((start.separate(Color::rgb(0, 0, 0)).key - 40.0).abs() < 0.001);
+
+        // skeleton black keeps midtones clean and still reaches full black
+        let skeleton = Separation {
+            black_exponent: 2.0,
+            ..Default::default()
+        };
+        assert!((skeleton.separate(gray).key - 56.1).abs() < 0.1);
+        assert!((skeleton.separate(Color::rgb(0, 0, 0)).key - 100.0).abs() < 0.001);
+        let back = Color::from(skeleton.separate(teal));
+        assert!((back.blue - teal.blue).abs() < 0.001);
+    }
+
+    #[test]
+    fn ink_limit() {
+        let separation = Separation {
+            black_strength: 0.2,
+            ink_limit: 280.0,
+            ..Default::default()
+        };
+        let cmyk = separation.separate(Color::rgb(10, 5, 0));
+        assert!((cmyk.total_ink() - 280.0).abs() < 0.01, "{}", cmyk);
+        assert!(cmyk.key > 15.0);
+
+        let light = separation.separate(Color::rgb(200, 180, 150));
+        assert!(light.total_ink() < 280.0);
+    }
 }
@@ -0,0 +1,55 @@
+use std::fmt::Debug;
+
+/// Defines the storage type of color channels.
+///
+/// Floating point channels are normalized to `0..1` and are not clamped, so they can
+/// hold HDR values. Integer channels use the whole range of the type, e.g. `0..255` for `u8`.
+pub trait Component: Copy + PartialEq + Debug + 'static {
+    /// Empty channel value
+    const ZERO: Self;
+    /// Full intensity channel value
+    const MAX: Self;
+
+    /// Convert channel to normalized `0..1` value
+    fn to_normalized(self) -> f64;
+
+    /// Convert normalized `0..1` value to channel, integers are rounded and clamped
+    fn from_normalized(value: f64) -> Self;
+}
+
+macro_rules! float_component {
+    ($($ty:ty),*) => {$(
+        impl Component for $ty {
+            const ZERO: Self = 0.0;
+            const MAX: Self = 1.0;
+
+            fn to_normalized(self) -> f64 {
+                self as f64
+            }
+
+            fn from_normalized(value: f64) -> Self {
+                value as $ty
+            }
+        }
+    )*};
+}
+
+macro_rules! integer_component {
+    ($($ty:ty),*) => {$(
+        impl Component for $ty {
+            const ZERO: Self = 0;
+            const MAX: Self = <$ty>::MAX;
+
+            fn to_normalized(self) -> f64 {
+                self as f64 / <$ty>::MAX as f64
+            }
+
+            fn from_normalized(value: f64) -> Self {
+                (value.clamp(0.0, 1.0) * <$ty>::MAX as f64).round() as $ty
+            }
+        }
+    )*};
+}
+
+float_component!(f32, f64);
+integer_component!(u8, u16);
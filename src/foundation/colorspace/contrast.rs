@@ -0,0 +1,32 @@
+use super::{to_linear, Color, Float};
+
+impl Color {
+    /// Retrieve relative luminance as defined by WCAG, from `0` for black to `1` for white
+    pub fn luminance(&self) -> Float {
+        0.2126 * to_linear(self.red.clamp(0.0, 1.0))
+            + 0.7152 * to_linear(self.green.clamp(0.0, 1.0))
+            + 0.0722 * to_linear(self.blue.clamp(0.0, 1.0))
+    }
+
+    /// Retrieve WCAG contrast ratio between colors, from `1` to `21`, alpha is ignored.
+    ///
+    /// Text usually needs at least `4.5` against its background, large text `3`.
+    pub fn contrast_ratio(&self, other: &Color) -> Float {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::color;
+
+    #[test]
+    fn contrast_ratio() {
+        assert!((color::BLACK.contrast_ratio(&color::WHITE) - 21.0).abs() < 0.001);
+        assert_eq!(color::WHITE.contrast_ratio(&color::WHITE), 1.0);
+        let ratio = Color::rgb(0x76, 0x76, 0x76).contrast_ratio(&color::WHITE);
+        assert!((ratio - 4.54).abs() < 0.01, "{}", ratio);
+    }
+}
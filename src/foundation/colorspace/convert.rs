@@ -62,6 +62,16 @@ impl From<RgbColor> for CmyColor {
         Self::from_color(c)
     }
 }
+impl From<RgbColor> for OklabColor {
+    fn from(c: RgbColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<RgbColor> for OklchColor {
+    fn from(c: RgbColor) -> Self {
+        Self::from_color(c)
+    }
+}
 #[cfg(feature = "experimental")]
 impl From<RgbColor> for LabColor {
     fn from(c: RgbColor) -> Self {
@@ -105,6 +115,16 @@ impl From<RgbaColor> for CmyColor {
         Self::from_color(c)
     }
 }
+impl From<RgbaColor> for OklabColor {
+    fn from(c: RgbaColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<RgbaColor> for OklchColor {
+    fn from(c: RgbaColor) -> Self {
+        Self::from_color(c)
+    }
+}
 #[cfg(feature = "experimental")]
 impl From<RgbaColor> for LabColor {
     fn from(c: RgbaColor) -> Self {
@@ -144,6 +164,16 @@ impl From<HslColor> for CmyColor {
         Self::from_color(c)
     }
 }
+impl From<HslColor> for OklabColor {
+    fn from(c: HslColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<HslColor> for OklchColor {
+    fn from(c: HslColor) -> Self {
+        Self::from_color(c)
+    }
+}
 #[cfg(feature = "experimental")]
 impl From<HslColor> for LabColor {
     fn from(c: HslColor) -> Self {
@@ -183,6 +213,16 @@ impl From<HsvColor> for CmyColor {
         Self::from_color(c)
     }
 }
+impl From<HsvColor> for OklabColor {
+    fn from(c: HsvColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<HsvColor> for OklchColor {
+    fn from(c: HsvColor) -> Self {
+        Self::from_color(c)
+    }
+}
 #[cfg(feature = "experimental")]
 impl From<HsvColor> for LabColor {
     fn from(c: HsvColor) -> Self {
@@ -222,6 +262,16 @@ impl From<CmykColor> for CmyColor {
         Self::from_color(c)
     }
 }
+impl From<CmykColor> for OklabColor {
+    fn from(c: CmykColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<CmykColor> for OklchColor {
+    fn from(c: CmykColor) -> Self {
+        Self::from_color(c)
+    }
+}
 #[cfg(feature = "experimental")]
 impl From<CmykColor> for LabColor {
     fn from(c: CmykColor) -> Self {
@@ -261,6 +311,16 @@ impl From<CmyColor> for CmykColor {
         Self::from_color(c)
     }
 }
+impl From<CmyColor> for OklabColor {
+    fn from(c: CmyColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<CmyColor> for OklchColor {
+    fn from(c: CmyColor) -> Self {
+        Self::from_color(c)
+    }
+}
 #[cfg(feature = "experimental")]
 impl From<CmyColor> for LabColor {
     fn from(c: CmyColor) -> Self {
@@ -274,6 +334,70 @@ impl From<CmyColor> for XyzColor {
     }
 }
 
+// OKLAB -> ALL
+impl From<OklabColor> for RgbColor {
+    fn from(c: OklabColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklabColor> for RgbaColor {
+    fn from(c: OklabColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklabColor> for HslColor {
+    fn from(c: OklabColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklabColor> for HsvColor {
+    fn from(c: OklabColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklabColor> for CmykColor {
+    fn from(c: OklabColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklabColor> for CmyColor {
+    fn from(c: OklabColor) -> Self {
+        Self::from_color(c)
+    }
+}
+
+// OKLCH -> ALL
+impl From<OklchColor> for RgbColor {
+    fn from(c: OklchColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklchColor> for RgbaColor {
+    fn from(c: OklchColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklchColor> for HslColor {
+    fn from(c: OklchColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklchColor> for HsvColor {
+    fn from(c: OklchColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklchColor> for CmykColor {
+    fn from(c: OklchColor) -> Self {
+        Self::from_color(c)
+    }
+}
+impl From<OklchColor> for CmyColor {
+    fn from(c: OklchColor) -> Self {
+        Self::from_color(c)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::prelude::*;
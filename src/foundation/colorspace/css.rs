@@ -1,176 +1,35 @@
 #![cfg(any(feature = "color_from_css", test))]
 
-use super::Color;
+use super::{Color, Float, OklabColor, RgbColor};
+use crate::prelude::color;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+pub use super::named::NAMED_COLORS;
+
 lazy_static! {
     pub static ref COLORS: HashMap<&'static str, Color> = {
-        let colors: Vec<(&'static str, u32)> = vec![
-            // css 1
-            ("black",   0x000000),
-            ("silver",  0xc0c0c0),
-            ("gray",    0x808080),
-            ("white",   0xffffff),
-            ("maroon",  0x800000),
-            ("red",     0xff0000),
-            ("purple",  0x800080),
-            ("fuchsia", 0xff00ff),
-            ("green",   0x008000),
-            ("lime",    0x00ff00),
-            ("olive",   0x808000),
-            ("yellow",  0xffff00),
-            ("navy",    0x000080),
-            ("blue",    0x0000ff),
-            ("teal",    0x008080),
-            ("aqua",    0x00ffff),
-
-            // css2 rev 1
-            ("orange",          0xffa500),
-            ("aliceblue",       0xf0f8ff),
-            ("antiquewhite",    0xfaebd7),
-            ("aquamarine",      0x7fffd4),
-            ("azure",           0xf0ffff),
-            ("beige",           0xf5f5dc),
-            ("bisque",          0xffe4c4),
-            ("blanchedalmond",  0xffebcd),
-            ("blueviolet",      0x8a2be2),
-            ("brown",           0xa52a2a),
-            ("burlywood",       0xdeb887),
-            ("cadetblue",       0x5f9ea0),
-            ("chartreuse",      0x7fff00),
-            ("chocolate",       0xd2691e),
-            ("coral",           0xff7f50),
-            ("cornflowerblue",  0x6495ed),
-            ("cornsilk",        0xfff8dc),
-            ("crimson",         0xdc143c),
-            ("cyan",            0x00ffff),
-            ("darkblue",        0x00008b),
-            ("darkcyan",        0x008b8b),
-            ("darkgoldenrod",   0xb8860b),
-            ("darkgray",        0xa9a9a9),
-            ("darkgreen",       0x006400),
-            ("darkgrey",        0xa9a9a9),
-            ("darkkhaki",       0xbdb76b),
-            ("darkmagenta",     0x8b008b),
-            ("darkolivegreen",  0x556b2f),
-            ("darkorange",      0xff8c00),
-            ("darkorchid",      0x9932cc),
-            ("darkred",         0x8b0000),
-            ("darksalmon",      0xe9967a),
-            ("darkseagreen",    0x8fbc8f),
-            ("darkslateblue",   0x483d8b),
-            ("darkslategray",   0x2f4f4f),
-            ("darkslategrey",   0x2f4f4f),
-            ("darkturquoise",   0x00ced1),
-            ("darkviolet",      0x9400d3),
-            ("deeppink",        0xff1493),
-            ("deepskyblue",     0x00bfff),
-            ("dimgray",         0x696969),
-            ("dimgrey",         0x696969),
-            ("dodgerblue",      0x1e90ff),
-            ("firebrick",       0xb22222),
-            ("floralwhite",     0xfffaf0),
-            ("forestgreen",     0x228b22),
-            ("gainsboro",       0xdcdcdc),
-            ("ghostwhite",      0xf8f8ff),
-            ("gold",            0xffd700),
-            ("goldenrod",       0xdaa520),
-            ("greenyellow",     0xadff2f),
-            ("grey",            0x808080),
-            ("honeydew",        0xf0fff0),
-            ("hotpink",         0xff69b4),
-            ("indianred",       0xcd5c5c),
-            ("indigo",          0x4b0082),
-            ("ivory",           0xfffff0),
-            ("khaki",           0xf0e68c),
-            ("lavender",        0xe6e6fa),
-            ("lavenderblush",   0xfff0f5),
-            ("lawngreen",       0x7cfc00),
-            ("lemonchiffon",    0xfffacd),
-            ("lightblue",       0xadd8e6),
-            ("lightcoral",      0xf08080),
-            ("lightcyan",       0xe0ffff),
-            ("lightgoldenrodyellow", 0xfafad2),
-
-            // css3
-            ("lightgray",           0xd3d3d3),
-            ("lightgreen",          0x90ee90),
-            ("lightgrey",           0xd3d3d3),
-            ("lightpink",           0xffb6c1),
-            ("lightsalmon",         0xffa07a),
-            ("lightseagreen",       0x20b2aa),
-            ("lightskyblue",        0x87cefa),
-            ("lightslategray",      0x778899),
-            ("lightslategrey",      0x778899),
-            ("lightsteelblue",      0xb0c4de),
-            ("lightyellow",         0xffffe0),
-            ("limegreen",           0x32cd32),
-            ("linen",               0xfaf0e6),
-            ("magenta",             0xff00ff),
-            ("mediumaquamarine",    0x66cdaa),
-            ("mediumblue",          0x0000cd),
-            ("mediumorchid",        0xba55d3),
-            ("mediumpurple",        0x9370db),
-            ("mediumseagreen",      0x3cb371),
-            ("mediumslateblue",     0x7b68ee),
-            ("mediumspringgreen",   0x00fa9a),
-            ("mediumturquoise",     0x48d1cc),
-            ("mediumvioletred",     0xc71585),
-            ("midnightblue",        0x191970),
-            ("mintcream",           0xf5fffa),
-            ("mistyrose",           0xffe4e1),
-            ("moccasin",            0xffe4b5),
-            ("navajowhite",         0xffdead),
-            ("oldlace",             0xfdf5e6),
-            ("olivedrab",           0x6b8e23),
-            ("orangered",           0xff4500),
-            ("orchid",              0xda70d6),
-            ("palegoldenrod",       0xeee8aa),
-            ("palegreen",           0x98fb98),
-            ("paleturquoise",       0xafeeee),
-            ("palevioletred",       0xdb7093),
-            ("papayawhip",          0xffefd5),
-            ("peachpuff",           0xffdab9),
-            ("peru",                0xcd853f),
-            ("pink",                0xffc0cb),
-            ("plum",                0xdda0dd),
-            ("powderblue",          0xb0e0e6),
-            ("rosybrown",           0xbc8f8f),
-            ("royalblue",           0x4169e1),
-            ("saddlebrown",         0x8b4513),
-            ("salmon",              0xfa8072),
-            ("sandybrown",          0xf4a460),
-            ("seagreen",            0x2e8b57),
-            ("seashell",            0xfff5ee),
-            ("sienna",              0xa0522d),
-            ("skyblue",             0x87ceeb),
-            ("slateblue",           0x6a5acd),
-            ("slategray",           0x708090),
-            ("slategrey",           0x708090),
-            ("snow",                0xfffafa),
-            ("springgreen",         0x00ff7f),
-            ("steelblue",           0x4682b4),
-            ("tan",                 0xd2b48c),
-            ("thistle",             0xd8bfd8),
-            ("tomato",              0xff6347),
-            ("turquoise",           0x40e0d0),
-            ("violet",              0xee82ee),
-            ("wheat",               0xf5deb3),
-            ("whitesmoke",          0xf5f5f5),
-            ("yellowgreen",         0x9acd32),
-
-            // css4
-            ("rebeccapurple",       0x663399),
-        ];
         let mut map = HashMap::new();
-        for (color_name, color_u32) in colors.iter() {
+        for (color_name, color_u32) in NAMED_COLORS.iter() {
             map.insert(*color_name, (*color_u32).into());
         }
         map
     };
 }
 
+/// Closest named colors with their perceptual distance
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearestName {
+    /// Closest css (X11) color name
+    pub css: &'static str,
+    /// Distance to the css color in ΔE OK units
+    pub css_distance: Float,
+    /// Closest OpenColor constant name, like `TEAL_4`
+    pub open_color: String,
+    /// Distance to the OpenColor constant in ΔE OK units
+    pub open_color_distance: Float,
+}
+
 impl From<u32> for Color {
     fn from(color_u32: u32) -> Self {
         Self::from_rgb_u32(color_u32)
@@ -188,6 +47,52 @@ impl From<&str> for Color {
 }
 
 impl Color {
+    /// Retrieve css name of the color when it exactly matches one, ignoring alpha
+    pub fn css_name(&self) -> Option<&'static str> {
+        let rgb = RgbColor::from(*self);
+        NAMED_COLORS
+            .iter()
+            .find(|(_, color_u32)| RgbColor::from(Color::from_rgb_u32(*color_u32)) == rgb)
+            .map(|(name, _)| *name)
+    }
+
+    /// Find the closest css and OpenColor names of the color, ignoring alpha
+    pub fn nearest_name(&self) -> NearestName {
+        let lab = OklabColor::from(*self);
+        let distance = |color: Color| lab.delta_e(&OklabColor::from(color));
+
+        let mut css = ("", Float::MAX);
+        for (name, color_u32) in NAMED_COLORS.iter() {
+            let delta = distance(Color::from_rgb_u32(*color_u32));
+            if delta < css.1 {
+                css = (*name, delta);
+            }
+        }
+
+        let mut open_color = (String::new(), Float::MAX);
+        for (name, ramp) in color::RAMPS.iter() {
+            for (idx, shade) in ramp.iter().enumerate() {
+                let delta = distance(*shade);
+                if delta < open_color.1 {
+                    open_color = (format!("{}_{}", name, idx), delta);
+                }
+            }
+        }
+        for (name, shade) in [("BLACK", color::BLACK), ("WHITE", color::WHITE)].iter() {
+            let delta = distance(*shade);
+            if delta < open_color.1 {
+                open_color = (name.to_string(), delta);
+            }
+        }
+
+        NearestName {
+            css: css.0,
+            css_distance: css.1,
+            open_color: open_color.0,
+            open_color_distance: open_color.1,
+        }
+    }
+
     pub fn from_css_name(name: &str) -> Self {
         *COLORS.get(name).expect("Unknown css name of color")
     }
@@ -213,7 +118,7 @@ impl Color {
         }
     }
 
-    pub fn from_short_rgb_u16(c: u16) -> Color {
+    pub const fn from_short_rgb_u16(c: u16) -> Color {
         let (red, green, blue, _) = (
             ((c >> 8) + ((c >> 8) << 4)) as u8,
             ((c & 0x0f0) + ((c & 0x0f0) >> 4)) as u8,
@@ -223,7 +128,7 @@ impl Color {
         Color::rgb(red, green, blue)
     }
 
-    pub fn from_rgb_u32(c: u32) -> Color {
+    pub const fn from_rgb_u32(c: u32) -> Color {
         let (red, green, blue, _) = (
             (c >> 16) as u8,
             ((c & 0x00ff00) >> 8) as u8,
@@ -233,7 +138,7 @@ impl Color {
         Color::rgb(red, green, blue)
     }
 
-    pub fn from_short_rgba_u16(c: u16) -> Color {
+    pub const fn from_short_rgba_u16(c: u16) -> Color {
         let (red, green, blue, alpha) = (
             ((c >> 12) + ((c >> 12) << 4)) as u8,
             (((c & 0x0f00) >> 4) + ((c & 0x0f00) >> 8)) as u8,
@@ -243,7 +148,7 @@ impl Color {
         Color::rgba(red, green, blue, alpha)
     }
 
-    pub fn from_rgba_u32(c: u32) -> Color {
+    pub const fn from_rgba_u32(c: u32) -> Color {
         let (red, green, blue, alpha) = (
             (c >> 24) as u8,
             ((c & 0x00ff0000) >> 16) as u8,
@@ -330,4 +235,26 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn css_name() {
+        assert_eq!(Color::rgb(0xff, 0x63, 0x47).css_name(), Some("tomato"));
+        assert_eq!(Color::rgb(0x80, 0x80, 0x80).css_name(), Some("gray"));
+        assert_eq!(Color::rgb(0xff, 0x63, 0x48).css_name(), None);
+    }
+
+    #[test]
+    fn nearest_name() {
+        let nearest = Color::rgb(0xff, 0x64, 0x48).nearest_name();
+        assert_eq!(nearest.css, "tomato");
+        assert!(nearest.css_distance > 0. && nearest.css_distance < 1.);
+
+        let nearest = Color::rgb(0x38, 0xd9, 0xa9).nearest_name();
+        assert_eq!(nearest.open_color, "TEAL_4");
+        assert!(nearest.open_color_distance < 0.01);
+
+        let nearest = Color::rgb(0x01, 0x00, 0x00).nearest_name();
+        assert_eq!(nearest.css, "black");
+        assert_eq!(nearest.open_color, "BLACK");
+    }
 }
@@ -3,6 +3,7 @@ use std::fmt;
 
 /// Hsl color representation
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HslColor {
     /// Hue component
     pub hue: Float,
@@ -88,7 +89,7 @@ impl From<Color> for HslColor {
             0.
         } else {
             match c_max {
-                x if (x - red).abs() < Float::MIN_POSITIVE => 60. * (((green - blue) / delta) % 6.),
+                x if (x - red).abs() < Float::MIN_POSITIVE => 60. * ((green - blue) / delta).rem_euclid(6.),
                 x if (x - green).abs() < Float::MIN_POSITIVE => 60. * (((blue - red) / delta) + 2.),
                 x if (x - blue).abs() < Float::MIN_POSITIVE => 60. * (((red - green) / delta) + 4.),
                 _ => unreachable!("Invalid hue calculation!"),
@@ -161,4 +162,15 @@ mod test {
             );
         })
     }
+
+    #[test]
+    fn edge_cases() {
+        // red is the largest and green is below blue, hue wraps to 330, it was negative -30
+        let pink = HslColor::from(Color::new(1., 0., 0.5, 1.));
+        assert!((pink.hue - 330.).abs() < 1e-3, "{}", pink.hue);
+        let gray = HslColor::from(Color::rgb(128, 128, 128));
+        assert_eq!((gray.hue, gray.saturation), (0., 0.));
+        let black = HslColor::from(Color::rgb(0, 0, 0));
+        assert_eq!((black.hue, black.saturation, black.lightness), (0., 0., 0.));
+    }
 }
@@ -4,6 +4,7 @@ use utils::{hue_bound, percentage_to_fraction};
 
 /// Hsv color representation
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HsvColor {
     /// Hue component
     pub hue: Float,
@@ -83,12 +84,14 @@ impl From<Color> for HsvColor {
             alpha: _,
         } = rgb;
         let (min, max) = utils::min_max_tuple([red, green, blue].iter());
-        let hue = if (max - red).abs() < Float::MIN_POSITIVE {
+        let hue = if (max - min).abs() < Float::EPSILON {
+            0.
+        } else if (max - red).abs() < Float::MIN_POSITIVE {
             //normalize_hue(60. * (green - blue) / delta - 30.)
             if green >= blue {
                 60. * (green as Float - blue as Float) / (max - min) as Float
             } else {
-                360. - (green as Float - blue as Float) / (max - min) as Float * 60.
+                360. + (green as Float - blue as Float) / (max - min) as Float * 60.
             }
         } else if (max - green).abs() < Float::MIN_POSITIVE {
             60. * (blue as Float - red as Float) / (max - min) as Float + 120.
@@ -99,7 +102,7 @@ impl From<Color> for HsvColor {
         };
         let saturation = 1.
             - (if (max - 0.).abs() < Float::EPSILON {
-                0 as Float
+                1 as Float
             } else {
                 min as Float / max as Float
             });
@@ -156,4 +159,17 @@ mod test {
             );
         })
     }
+
+    #[test]
+    fn edge_cases() {
+        // grays have zero hue and saturation, hue was NaN
+        let gray = HsvColor::from(Color::rgb(128, 128, 128));
+        assert_eq!((gray.hue, gray.saturation), (0., 0.));
+        // black has zero saturation, it was 100
+        let black = HsvColor::from(Color::rgb(0, 0, 0));
+        assert_eq!((black.hue, black.saturation, black.value), (0., 0., 0.));
+        // red is the largest and green is below blue, hue wraps to 330, it was mirrored to 30
+        let pink = HsvColor::from(Color::new(1., 0., 0.5, 1.));
+        assert!((pink.hue - 330.).abs() < 1e-3, "{}", pink.hue);
+    }
 }
@@ -0,0 +1,318 @@
+//! Minimal reader of ICC color profiles.
+//!
+//! Supports matrix/TRC (matrix-shaper) RGB profiles and gray profiles of ICC v2 and v4,
+//! which cover the profiles embedded by cameras, browsers and most image editors.
+//! Profiles based on lookup tables are reported as [ColorError::UnsupportedProfile].
+
+use super::{rgb_space::D50, ColorError, Float, RgbSpace, ToneCurve};
+
+const HEADER_SIZE: usize = 128;
+
+fn slice(data: &[u8], offset: usize, len: usize) -> Result<&[u8], ColorError> {
+    offset
+        .checked_add(len)
+        .and_then(|end| data.get(offset..end))
+        .ok_or(ColorError::InvalidProfile)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, ColorError> {
+    let bytes = slice(data, offset, 2)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, ColorError> {
+    let bytes = slice(data, offset, 4)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_s15_fixed16(data: &[u8], offset: usize) -> Result<Float, ColorError> {
+    Ok(read_u32(data, offset)? as i32 as Float / 65536.0)
+}
+
+fn signature(data: &[u8], offset: usize) -> Result<[u8; 4], ColorError> {
+    let bytes = slice(data, offset, 4)?;
+    Ok([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+/// Tag table of profile
+struct Tags<'a> {
+    data: &'a [u8],
+    entries: Vec<([u8; 4], usize, usize)>,
+}
+
+impl<'a> Tags<'a> {
+    fn parse(data: &'a [u8]) -> Result<Self, ColorError> {
+        let count = read_u32(data, HEADER_SIZE)? as usize;
+        // every entry takes 12 bytes, reject counts which can't fit
+        if count > data.len() / 12 {
+            return Err(ColorError::InvalidProfile);
+        }
+
+        let mut entries = Vec::with_capacity(count);
+        for idx in 0..count {
+            let offset = HEADER_SIZE + 4 + idx * 12;
+            entries.push((
+                signature(data, offset)?,
+                read_u32(data, offset + 4)? as usize,
+                read_u32(data, offset + 8)? as usize,
+            ));
+        }
+        Ok(Self { data, entries })
+    }
+
+    fn get(&self, tag: &[u8; 4]) -> Result<Option<&'a [u8]>, ColorError> {
+        match self.entries.iter().find(|(sig, _, _)| sig == tag) {
+            Some((_, offset, size)) => slice(self.data, *offset, *size).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn contains(&self, tag: &[u8; 4]) -> bool {
+        self.entries.iter().any(|(sig, _, _)| sig == tag)
+    }
+
+    fn xyz(&self, tag: &[u8; 4]) -> Result<[Float; 3], ColorError> {
+        let data = self.get(tag)?.ok_or(ColorError::InvalidProfile)?;
+        if &signature(data, 0)? != b"XYZ " {
+            return Err(ColorError::InvalidProfile);
+        }
+        Ok([
+            read_s15_fixed16(data, 8)?,
+            read_s15_fixed16(data, 12)?,
+            read_s15_fixed16(data, 16)?,
+        ])
+    }
+
+    fn curve(&self, tag: &[u8; 4]) -> Result<ToneCurve, ColorError> {
+        let data = self.get(tag)?.ok_or(ColorError::InvalidProfile)?;
+        match &signature(data, 0)? {
+            b"curv" => {
+                let count = read_u32(data, 8)? as usize;
+                match count {
+                    0 => Ok(ToneCurve::Identity),
+                    1 => Ok(ToneCurve::Gamma(read_u16(data, 12)? as Float / 256.0)),
+                    _ => {
+                        let table = slice(data, 12, count.checked_mul(2).ok_or(ColorError::InvalidProfile)?)?;
+                        Ok(ToneCurve::Table(
+                            table
+                                .chunks_exact(2)
+                                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]) as Float / 65535.0)
+                                .collect(),
+                        ))
+                    }
+                }
+            }
+            b"para" => {
+                let function = read_u16(data, 8)?;
+                let count = match function {
+                    0 => 1,
+                    1 => 3,
+                    2 => 4,
+                    3 => 5,
+                    4 => 7,
+                    _ => return Err(ColorError::UnsupportedProfile),
+                };
+                let mut params = [0.0; 7];
+                for (idx, param) in params.iter_mut().enumerate().take(count) {
+                    *param = read_s15_fixed16(data, 12 + idx * 4)?;
+                }
+                let [g, a, b, c, d, e, f] = params;
+                Ok(match function {
+                    0 => ToneCurve::Gamma(g),
+                    // zero below -b/a
+                    1 => ToneCurve::Parametric { g, a, b, c: 0.0, d: -b / a, e: 0.0, f: 0.0 },
+                    // c below -b/a, c is added to both segments
+                    2 => ToneCurve::Parametric { g, a, b, c: 0.0, d: -b / a, e: c, f: c },
+                    3 => ToneCurve::Parametric { g, a, b, c, d, e: 0.0, f: 0.0 },
+                    _ => ToneCurve::Parametric { g, a, b, c, d, e, f },
+                })
+            }
+            _ => Err(ColorError::UnsupportedProfile),
+        }
+    }
+}
+
+impl RgbSpace {
+    /// Read Rgb space from ICC profile data.
+    ///
+    /// Gray profiles produce space with the same curve for every channel,
+    /// which maps equal components to the neutral axis.
+    pub fn from_icc(data: &[u8]) -> Result<Self, ColorError> {
+        if data.len() < HEADER_SIZE + 4 || &signature(data, 36)? != b"acsp" {
+            return Err(ColorError::InvalidProfile);
+        }
+        let version = data[8];
+        if !(2..=4).contains(&version) {
+            return Err(ColorError::UnsupportedProfile);
+        }
+        if &signature(data, 20)? != b"XYZ " {
+            // matrix-shaper profiles always connect through XYZ
+            return Err(ColorError::UnsupportedProfile);
+        }
+
+        let tags = Tags::parse(data)?;
+        let white_point = if tags.contains(b"wtpt") {
+            tags.xyz(b"wtpt")?
+        } else {
+            D50
+        };
+
+        match &signature(data, 16)? {
+            b"RGB " => {
+                let shaper = [b"rXYZ", b"gXYZ", b"bXYZ", b"rTRC", b"gTRC", b"bTRC"];
+                if !shaper.iter().all(|tag| tags.contains(tag)) {
+                    return Err(ColorError::UnsupportedProfile);
+                }
+
+                let [red, green, blue] = [tags.xyz(b"rXYZ")?, tags.xyz(b"gXYZ")?, tags.xyz(b"bXYZ")?];
+                Ok(RgbSpace {
+                    to_xyz: [
+                        [red[0], green[0], blue[0]],
+                        [red[1], green[1], blue[1]],
+                        [red[2], green[2], blue[2]],
+                    ],
+                    curves: [tags.curve(b"rTRC")?, tags.curve(b"gTRC")?, tags.curve(b"bTRC")?],
+                    white_point,
+                })
+            }
+            b"GRAY" => {
+                if !tags.contains(b"kTRC") {
+                    return Err(ColorError::UnsupportedProfile);
+                }
+                let curve = tags.curve(b"kTRC")?;
+                // gray connects to the D50 white scaled by the curve
+                let column = |idx: usize| [D50[idx] / 3.0; 3];
+                Ok(RgbSpace {
+                    to_xyz: [column(0), column(1), column(2)],
+                    curves: [curve.clone(), curve.clone(), curve],
+                    white_point,
+                })
+            }
+            _ => Err(ColorError::UnsupportedProfile),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::colorspace::Color;
+
+    /// Build profile with header and tags, tag data is aligned to 4 bytes
+    fn profile(space: &[u8; 4], tags: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0u8; HEADER_SIZE];
+        data[8] = 4;
+        data[12..16].copy_from_slice(b"mntr");
+        data[16..20].copy_from_slice(space);
+        data[20..24].copy_from_slice(b"XYZ ");
+        data[36..40].copy_from_slice(b"acsp");
+
+        data.extend_from_slice(&(tags.len() as u32).to_be_bytes());
+        let mut offset = HEADER_SIZE + 4 + tags.len() * 12;
+        let mut body = Vec::new();
+        for (sig, tag) in tags {
+            data.extend_from_slice(*sig);
+            data.extend_from_slice(&(offset as u32).to_be_bytes());
+            data.extend_from_slice(&(tag.len() as u32).to_be_bytes());
+            body.extend_from_slice(tag);
+            while body.len() % 4 != 0 {
+                body.push(0);
+            }
+            offset = HEADER_SIZE + 4 + tags.len() * 12 + body.len();
+        }
+        data.extend_from_slice(&body);
+        let size = data.len() as u32;
+        data[0..4].copy_from_slice(&size.to_be_bytes());
+        data
+    }
+
+    fn fixed(value: Float) -> [u8; 4] {
+        ((value * 65536.0).round() as i32).to_be_bytes()
+    }
+
+    fn xyz(value: [Float; 3]) -> Vec<u8> {
+        let mut tag = b"XYZ \0\0\0\0".to_vec();
+        value.iter().for_each(|v| tag.extend_from_slice(&fixed(*v)));
+        tag
+    }
+
+    fn para(function: u16, params: &[Float]) -> Vec<u8> {
+        let mut tag = b"para\0\0\0\0".to_vec();
+        tag.extend_from_slice(&function.to_be_bytes());
+        tag.extend_from_slice(&[0, 0]);
+        params.iter().for_each(|v| tag.extend_from_slice(&fixed(*v)));
+        tag
+    }
+
+    fn curv(values: &[u16]) -> Vec<u8> {
+        let mut tag = b"curv\0\0\0\0".to_vec();
+        tag.extend_from_slice(&(values.len() as u32).to_be_bytes());
+        values.iter().for_each(|v| tag.extend_from_slice(&v.to_be_bytes()));
+        tag
+    }
+
+    fn srgb_profile() -> Vec<u8> {
+        let space = RgbSpace::srgb();
+        let column = |idx: usize| [space.to_xyz[0][idx], space.to_xyz[1][idx], space.to_xyz[2][idx]];
+        let trc = para(3, &[2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045]);
+        profile(
+            b"RGB ",
+            &[
+                (b"wtpt", xyz(D50)),
+                (b"rXYZ", xyz(column(0))),
+                (b"gXYZ", xyz(column(1))),
+                (b"bXYZ", xyz(column(2))),
+                (b"rTRC", trc.clone()),
+                (b"gTRC", trc.clone()),
+                (b"bTRC", trc),
+            ],
+        )
+    }
+
+    #[test]
+    fn srgb_matrix_shaper() {
+        let space = RgbSpace::from_icc(&srgb_profile()).unwrap();
+        assert!((space.white_point[2] - D50[2]).abs() < 0.0001);
+
+        for color in [Color::rgb(18, 184, 134), Color::rgb(255, 255, 255), Color::rgb(250, 82, 82)].iter() {
+            assert_eq!(space.to_srgb(*color).cast::<u8>(), color.cast::<u8>());
+        }
+    }
+
+    #[test]
+    fn gray_and_tables() {
+        // linear gray is brighter when encoded to sRGB
+        let gray = profile(b"GRAY", &[(b"kTRC", curv(&[256]))]);
+        let space = RgbSpace::from_icc(&gray).unwrap();
+        assert_eq!(space.curves[0], ToneCurve::Gamma(1.0));
+        let mid = space.to_srgb(Color::new(0.5, 0.5, 0.5, 1.0)).cast::<u8>();
+        for channel in mid.to_array()[..3].iter() {
+            assert!((187..=189).contains(channel), "{:?}", mid);
+        }
+
+        let table = profile(b"GRAY", &[(b"kTRC", curv(&[0, 16384, 65535]))]);
+        let space = RgbSpace::from_icc(&table).unwrap();
+        assert!((space.curves[0].eval(0.5) - 0.25).abs() < 0.001);
+        assert!((space.curves[0].eval(0.75) - 0.625).abs() < 0.001);
+    }
+
+    #[test]
+    fn errors() {
+        let lut = profile(b"RGB ", &[(b"A2B0", b"mft2\0\0\0\0".to_vec())]);
+        assert!(matches!(RgbSpace::from_icc(&lut), Err(ColorError::UnsupportedProfile)));
+
+        let cmyk = profile(b"CMYK", &[]);
+        assert!(matches!(RgbSpace::from_icc(&cmyk), Err(ColorError::UnsupportedProfile)));
+
+        let valid = srgb_profile();
+        assert!(matches!(RgbSpace::from_icc(&valid[..100]), Err(ColorError::InvalidProfile)));
+        for len in (HEADER_SIZE..valid.len()).step_by(7) {
+            // truncated data must never panic
+            let _ = RgbSpace::from_icc(&valid[..len]);
+        }
+
+        let mut broken = valid;
+        broken[36] = b'x';
+        assert!(matches!(RgbSpace::from_icc(&broken), Err(ColorError::InvalidProfile)));
+    }
+}
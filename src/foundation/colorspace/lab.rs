@@ -5,6 +5,7 @@ use std::fmt;
 
 /// Lab color representation
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LabColor {
     /// L component
     pub l: f64,
@@ -0,0 +1,41 @@
+/// Create [Color](crate::foundation::colorspace::Color) evaluated at compile time.
+///
+/// Accepts hex string or byte components, invalid hex strings are compile errors.
+///
+/// ```
+/// use primitives::{color, foundation::colorspace::Color};
+///
+/// const PRIMARY: Color = color!("#1c7ed6");
+/// const SECONDARY: Color = color!(rgb 28, 126, 214);
+/// const OVERLAY: Color = color!(rgba 0, 0, 0, 128);
+///
+/// assert_eq!(PRIMARY, SECONDARY);
+/// assert_eq!(color!("#00000080"), OVERLAY);
+/// ```
+///
+/// ```compile_fail
+/// use primitives::{color, foundation::colorspace::Color};
+///
+/// const BROKEN: Color = color!("#1c7ed");
+/// ```
+#[macro_export]
+macro_rules! color {
+    (rgb $red:expr, $green:expr, $blue:expr $(,)?) => {{
+        const COLOR: $crate::foundation::colorspace::Color =
+            $crate::foundation::colorspace::Color::rgb($red, $green, $blue);
+        COLOR
+    }};
+    (rgba $red:expr, $green:expr, $blue:expr, $alpha:expr $(,)?) => {{
+        const COLOR: $crate::foundation::colorspace::Color =
+            $crate::foundation::colorspace::Color::rgba($red, $green, $blue, $alpha);
+        COLOR
+    }};
+    ($hex:literal) => {{
+        const COLOR: $crate::foundation::colorspace::Color =
+            match $crate::foundation::colorspace::Color::try_from_hex($hex) {
+                Some(color) => color,
+                None => panic!(concat!("Invalid hex color ", stringify!($hex))),
+            };
+        COLOR
+    }};
+}
@@ -22,6 +22,8 @@ impl ColorTransition for HslColor {}
 impl ColorTransition for HsvColor {}
 impl ColorTransition for CmykColor {}
 impl ColorTransition for CmyColor {}
+impl ColorTransition for OklabColor {}
+impl ColorTransition for OklchColor {}
 #[cfg(feature = "experimental")]
 impl ColorTransition for LabColor {}
 #[cfg(feature = "experimental")]
@@ -33,6 +35,8 @@ impl ColorSpace for HslColor {}
 impl ColorSpace for HsvColor {}
 impl ColorSpace for CmykColor {}
 impl ColorSpace for CmyColor {}
+impl ColorSpace for OklabColor {}
+impl ColorSpace for OklchColor {}
 #[cfg(feature = "experimental")]
 impl ColorSpace for LabColor {}
 #[cfg(feature = "experimental")]
@@ -42,6 +46,8 @@ impl NonRgbSpace for HslColor {}
 impl NonRgbSpace for HsvColor {}
 impl NonRgbSpace for CmykColor {}
 impl NonRgbSpace for CmyColor {}
+impl NonRgbSpace for OklabColor {}
+impl NonRgbSpace for OklchColor {}
 #[cfg(feature = "experimental")]
 impl NonRgbSpace for LabColor {}
 #[cfg(feature = "experimental")]
@@ -52,6 +58,7 @@ impl NonRadialSpace for RgbColor {}
 impl NonRadialSpace for RgbaColor {}
 impl NonRadialSpace for CmykColor {}
 impl NonRadialSpace for CmyColor {}
+impl NonRadialSpace for OklabColor {}
 #[cfg(feature = "experimental")]
 impl NonRadialSpace for LabColor {}
 #[cfg(feature = "experimental")]
@@ -62,6 +69,8 @@ impl NonSaturationSpace for RgbColor {}
 impl NonSaturationSpace for RgbaColor {}
 impl NonSaturationSpace for CmykColor {}
 impl NonSaturationSpace for CmyColor {}
+impl NonSaturationSpace for OklabColor {}
+impl NonSaturationSpace for OklchColor {}
 #[cfg(feature = "experimental")]
 impl NonSaturationSpace for LabColor {}
 #[cfg(feature = "experimental")]
@@ -1 +1,235 @@
+use std::{fmt, str::FromStr};
 
+use super::{Color, ColorError, Float};
+
+/// Blend modes of [Compositing and Blending](https://www.w3.org/TR/compositing-1/) specification,
+/// named like `globalCompositeOperation` values of canvas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendMode {
+    /// Source over backdrop, `source-over`
+    #[default]
+    Normal,
+    /// Source replaces backdrop, `copy`
+    Copy,
+    /// Backdrop over source, `destination-over`
+    DestinationOver,
+    /// Sum of colors, `lighter`
+    Add,
+    /// Product of colors, always darker
+    Multiply,
+    /// Inverted product of inverted colors, always lighter
+    Screen,
+    /// Multiply or screen depending on backdrop
+    Overlay,
+    /// Darker of colors
+    Darken,
+    /// Lighter of colors
+    Lighten,
+    /// Brighten backdrop to reflect source
+    ColorDodge,
+    /// Darken backdrop to reflect source
+    ColorBurn,
+    /// Multiply or screen depending on source
+    HardLight,
+    /// Darken or lighten depending on source, like diffused spotlight
+    SoftLight,
+    /// Absolute difference of colors
+    Difference,
+    /// Like difference with lower contrast
+    Exclusion,
+}
+
+impl BlendMode {
+    /// All blend modes
+    pub const ALL: [BlendMode; 15] = [
+        Self::Normal,
+        Self::Copy,
+        Self::DestinationOver,
+        Self::Add,
+        Self::Multiply,
+        Self::Screen,
+        Self::Overlay,
+        Self::Darken,
+        Self::Lighten,
+        Self::ColorDodge,
+        Self::ColorBurn,
+        Self::HardLight,
+        Self::SoftLight,
+        Self::Difference,
+        Self::Exclusion,
+    ];
+
+    /// Retrieve name used by canvas `globalCompositeOperation`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Normal => "source-over",
+            Self::Copy => "copy",
+            Self::DestinationOver => "destination-over",
+            Self::Add => "lighter",
+            Self::Multiply => "multiply",
+            Self::Screen => "screen",
+            Self::Overlay => "overlay",
+            Self::Darken => "darken",
+            Self::Lighten => "lighten",
+            Self::ColorDodge => "color-dodge",
+            Self::ColorBurn => "color-burn",
+            Self::HardLight => "hard-light",
+            Self::SoftLight => "soft-light",
+            Self::Difference => "difference",
+            Self::Exclusion => "exclusion",
+        }
+    }
+
+    /// Blend component of source with component of backdrop
+    fn blend(&self, source: Float, backdrop: Float) -> Float {
+        match self {
+            Self::Normal | Self::Copy | Self::DestinationOver | Self::Add => source,
+            Self::Multiply => source * backdrop,
+            Self::Screen => source + backdrop - source * backdrop,
+            Self::Overlay => Self::HardLight.blend(backdrop, source),
+            Self::Darken => source.min(backdrop),
+            Self::Lighten => source.max(backdrop),
+            Self::ColorDodge => {
+                if backdrop <= 0.0 {
+                    0.0
+                } else if source >= 1.0 {
+                    1.0
+                } else {
+                    (backdrop / (1.0 - source)).min(1.0)
+                }
+            }
+            Self::ColorBurn => {
+                if backdrop >= 1.0 {
+                    1.0
+                } else if source <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - backdrop) / source).min(1.0)
+                }
+            }
+            Self::HardLight => {
+                if source <= 0.5 {
+                    Self::Multiply.blend(2.0 * source, backdrop)
+                } else {
+                    Self::Screen.blend(2.0 * source - 1.0, backdrop)
+                }
+            }
+            Self::SoftLight => {
+                if source <= 0.5 {
+                    backdrop - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop)
+                } else {
+                    let d = if backdrop <= 0.25 {
+                        ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+                    } else {
+                        backdrop.sqrt()
+                    };
+                    backdrop + (2.0 * source - 1.0) * (d - backdrop)
+                }
+            }
+            Self::Difference => (source - backdrop).abs(),
+            Self::Exclusion => source + backdrop - 2.0 * source * backdrop,
+        }
+    }
+}
+
+impl fmt::Display for BlendMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BlendMode {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|mode| mode.as_str() == s)
+            .copied()
+            .ok_or(ColorError::UnknownBlendMode)
+    }
+}
+
+impl Color {
+    /// Composite the color as source over backdrop color with blend mode.
+    ///
+    /// Both colors have straight alpha, the result too.
+    pub fn blend(&self, backdrop: Color, mode: BlendMode) -> Color {
+        let source_alpha = self.alpha.clamp(0.0, 1.0);
+        let backdrop_alpha = backdrop.alpha.clamp(0.0, 1.0);
+        let source = [self.red, self.green, self.blue];
+        let back = [backdrop.red, backdrop.green, backdrop.blue];
+
+        // premultiplied result
+        let (color, alpha) = match mode {
+            BlendMode::Copy => return *self,
+            BlendMode::DestinationOver => return backdrop.blend(*self, BlendMode::Normal),
+            BlendMode::Add => {
+                let color =
+                    [0, 1, 2].map(|idx| source[idx] * source_alpha + back[idx] * backdrop_alpha);
+                (color, (source_alpha + backdrop_alpha).min(1.0))
+            }
+            _ => {
+                let color = [0, 1, 2].map(|idx| {
+                    let mixed = (1.0 - backdrop_alpha) * source[idx]
+                        + backdrop_alpha * mode.blend(source[idx], back[idx]);
+                    source_alpha * mixed + backdrop_alpha * (1.0 - source_alpha) * back[idx]
+                });
+                (color, source_alpha + backdrop_alpha * (1.0 - source_alpha))
+            }
+        };
+
+        if alpha <= 0.0 {
+            return Color::new(0.0, 0.0, 0.0, 0.0);
+        }
+        Color::new(
+            (color[0] / alpha).clamp(0.0, 1.0),
+            (color[1] / alpha).clamp(0.0, 1.0),
+            (color[2] / alpha).clamp(0.0, 1.0),
+            alpha,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bytes(color: Color) -> [u8; 4] {
+        color.cast::<u8>().to_array()
+    }
+
+    #[test]
+    fn blend_modes() {
+        let red = Color::rgb(255, 0, 0);
+        let gray = Color::rgb(128, 128, 128);
+        let blend = |mode| bytes(red.blend(gray, mode));
+        assert_eq!(blend(BlendMode::Normal), [255, 0, 0, 255]);
+        assert_eq!(blend(BlendMode::Multiply), [128, 0, 0, 255]);
+        assert_eq!(blend(BlendMode::Screen), [255, 128, 128, 255]);
+        assert_eq!(blend(BlendMode::Difference), [127, 128, 128, 255]);
+        assert_eq!(blend(BlendMode::Add), [255, 128, 128, 255]);
+        assert_eq!(blend(BlendMode::Darken), [128, 0, 0, 255]);
+
+        // half transparent source mixes with backdrop
+        let half = Color::new(1.0, 1.0, 1.0, 0.5);
+        let mixed = half.blend(Color::rgb(0, 0, 0), BlendMode::Normal);
+        assert_eq!(bytes(mixed), [128, 128, 128, 255]);
+
+        // transparent backdrop keeps source
+        let clear = Color::new(0.0, 0.0, 0.0, 0.0);
+        for mode in BlendMode::ALL.iter() {
+            assert_eq!(bytes(half.blend(clear, *mode)), bytes(half), "{}", mode);
+        }
+        assert_eq!(clear.blend(red, BlendMode::DestinationOver), red);
+    }
+
+    #[test]
+    fn names() {
+        for mode in BlendMode::ALL.iter() {
+            assert_eq!(mode.as_str().parse::<BlendMode>().unwrap(), *mode);
+        }
+        assert!("unknown".parse::<BlendMode>().is_err());
+    }
+}
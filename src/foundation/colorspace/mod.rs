@@ -5,6 +5,8 @@
 //! * `CMYK` 4-channel [CMYK](http://en.wikipedia.org/wiki/CMYK_color_model) color space.
 //! * `HSV` (AKA HSB) 3-channel [HSB](http://en.wikipedia.org/wiki/HSL_and_HSV) color space.
 //! * `HSL` 3-channel [HSL](http://en.wikipedia.org/wiki/HSL_and_HSV) color space.
+//! * `Oklab` 3-channel [Oklab](https://bottosson.github.io/posts/oklab/) perceptual color space.
+//! * `Oklch` 3-channel cylindrical form of `Oklab` color space.
 //! * `Lab` 3-channel [Lab](http://en.wikipedia.org/wiki/Lab_color_space) color space. (experimantal)
 //! * `RGB` Normal 3-channel [RGB](http://en.wikipedia.org/wiki/RGB_color_space) color space.
 //! * `RGBA` 4-channel RGBA color space. It is a sub-struct of `RGB` with an additional `alpha` value.
@@ -27,14 +29,21 @@
 //
 
 mod base;
+#[macro_use]
+mod macros;
+mod component;
 mod cmy;
 mod cmyk;
+mod contrast;
 mod hsl;
 mod hsv;
+pub mod icc;
+mod oklab;
 #[cfg(feature = "experimental")]
 mod lab;
 mod rgb;
 mod rgba;
+mod rgb_space;
 #[cfg(feature = "experimental")]
 mod xyz;
 
@@ -46,11 +55,19 @@ mod convert;
 mod round;
 mod to_hex_string;
 
+pub mod batch;
+
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+
 pub(crate) type Float = f32;
 
 #[cfg(any(feature = "color_from_css", test))]
 pub mod css;
 
+mod named;
+pub(crate) use named::NAMED_COLORS;
+
 mod utils;
 pub(crate) use utils::*;
 
@@ -58,14 +75,18 @@ pub(crate) use utils::*;
 mod test_utils;
 
 pub use base::{Color, ColorError};
+pub use component::Component;
 pub use cmy::CmyColor;
-pub use cmyk::CmykColor;
+pub use cmyk::{BlackGeneration, CmykColor, Separation};
 pub use hsl::HslColor;
 pub use hsv::HsvColor;
+pub use mix::BlendMode;
+pub use oklab::{OklabColor, OklchColor};
 #[cfg(feature = "experimental")]
 pub use lab::LabColor;
 pub use rgb::RgbColor;
 pub use rgba::RgbaColor;
+pub use rgb_space::{RgbSpace, ToneCurve};
 #[cfg(feature = "experimental")]
 pub use xyz::XyzColor;
 
@@ -0,0 +1,232 @@
+use super::{from_linear, to_linear, Color, Float};
+use std::fmt;
+
+/// Oklab color representation
+///
+/// Perceptual color space by [Björn Ottosson](https://bottosson.github.io/posts/oklab/),
+/// where euclidean distance approximates the perceived difference between colors.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OklabColor {
+    /// Perceived lightness component (0.0 - 1.0)
+    pub l: Float,
+    /// Green/red axis component
+    pub a: Float,
+    /// Blue/yellow axis component
+    pub b: Float,
+}
+
+impl OklabColor {
+    /// Create new Oklab color with parameters
+    pub fn new(l: Float, a: Float, b: Float) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Perceptual difference between two colors (ΔE OK, scaled by 100)
+    pub fn delta_e(&self, other: &Self) -> Float {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        (dl * dl + da * da + db * db).sqrt() * 100.
+    }
+}
+
+impl fmt::Display for OklabColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "oklab({}, {}, {})", self.l, self.a, self.b)
+    }
+}
+
+/// Oklch color representation
+///
+/// Cylindrical form of [`OklabColor`] with chroma and hue in degrees.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OklchColor {
+    /// Perceived lightness component (0.0 - 1.0)
+    pub l: Float,
+    /// Chroma component
+    pub chroma: Float,
+    /// Hue component
+    pub hue: Float,
+}
+
+impl OklchColor {
+    /// Create new Oklch color with parameters
+    pub fn new(l: Float, chroma: Float, hue: Float) -> Self {
+        Self {
+            l,
+            chroma: chroma.max(0.),
+            hue: hue.rem_euclid(360.),
+        }
+    }
+
+    /// Check the color can be displayed in sRGB without clipping
+    pub fn is_in_gamut(&self) -> bool {
+        in_gamut(Color::from(*self))
+    }
+
+    /// Reduce chroma until the color fits into sRGB, keeping lightness and hue
+    pub fn clip_to_gamut(self) -> Self {
+        if self.is_in_gamut() {
+            return self;
+        }
+
+        let mut low: Float = 0.;
+        let mut high = self.chroma;
+        for _ in 0..24 {
+            let middle = (low + high) / 2.;
+            let probe = OklchColor {
+                chroma: middle,
+                ..self
+            };
+            if probe.is_in_gamut() {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        OklchColor { chroma: low, ..self }
+    }
+}
+
+impl fmt::Display for OklchColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "oklch({}, {}, {}°)", self.l, self.chroma, self.hue)
+    }
+}
+
+#[inline]
+fn in_gamut(color: Color) -> bool {
+    const EPSILON: Float = 1e-4;
+    [color.red, color.green, color.blue]
+        .iter()
+        .all(|cmp| (-EPSILON..=1. + EPSILON).contains(cmp))
+}
+
+// OKLAB -> RGB
+impl From<OklabColor> for Color {
+    fn from(lab: OklabColor) -> Self {
+        let OklabColor { l, a, b } = lab;
+
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+        Color {
+            red: from_linear(4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s),
+            green: from_linear(-1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s),
+            blue: from_linear(-0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s),
+            alpha: 1.,
+        }
+    }
+}
+
+// RGB -> OKLAB
+impl From<Color> for OklabColor {
+    fn from(rgb: Color) -> Self {
+        let (red, green, blue) = (
+            to_linear(rgb.red),
+            to_linear(rgb.green),
+            to_linear(rgb.blue),
+        );
+
+        let l = 0.412_221_46 * red + 0.536_332_55 * green + 0.051_445_995 * blue;
+        let m = 0.211_903_5 * red + 0.680_699_5 * green + 0.107_396_96 * blue;
+        let s = 0.088_302_46 * red + 0.281_718_85 * green + 0.629_978_7 * blue;
+
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+        OklabColor {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+}
+
+// OKLAB -> OKLCH
+impl From<OklabColor> for OklchColor {
+    fn from(lab: OklabColor) -> Self {
+        let chroma = (lab.a * lab.a + lab.b * lab.b).sqrt();
+        let hue = if chroma < 1e-6 {
+            0.
+        } else {
+            lab.b.atan2(lab.a).to_degrees().rem_euclid(360.)
+        };
+        OklchColor {
+            l: lab.l,
+            chroma,
+            hue,
+        }
+    }
+}
+
+// OKLCH -> OKLAB
+impl From<OklchColor> for OklabColor {
+    fn from(lch: OklchColor) -> Self {
+        let (sin, cos) = lch.hue.to_radians().sin_cos();
+        OklabColor {
+            l: lch.l,
+            a: lch.chroma * cos,
+            b: lch.chroma * sin,
+        }
+    }
+}
+
+// OKLCH -> RGB
+impl From<OklchColor> for Color {
+    fn from(lch: OklchColor) -> Self {
+        OklabColor::from(lch).into()
+    }
+}
+
+// RGB -> OKLCH
+impl From<Color> for OklchColor {
+    fn from(rgb: Color) -> Self {
+        OklabColor::from(rgb).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils;
+    use super::super::*;
+
+    #[test]
+    fn from_rgb() {
+        let white = OklabColor::from(Color::rgb(255, 255, 255));
+        assert!(test_utils::diff_less_than_f64(white.l, 1., 1e-3));
+        assert!(test_utils::diff_less_than_f64(white.a, 0., 1e-3));
+        assert!(test_utils::diff_less_than_f64(white.b, 0., 1e-3));
+
+        let red = OklabColor::from(Color::rgb(255, 0, 0));
+        assert!(test_utils::diff_less_than_f64(red.l, 0.628, 1e-3), "{}", red);
+        assert!(test_utils::diff_less_than_f64(red.a, 0.225, 1e-3), "{}", red);
+        assert!(test_utils::diff_less_than_f64(red.b, 0.126, 1e-3), "{}", red);
+    }
+
+    #[test]
+    fn to_rgb() {
+        for (color, _) in test_utils::RGB_HSL.iter() {
+            let lch: OklchColor = (*color).into();
+            let expected = RgbColor::from(*color);
+            let actual = RgbColor::from(Color::from(lch));
+            assert_eq!(actual, expected, "{}", lch);
+        }
+    }
+
+    #[test]
+    fn clip_to_gamut() {
+        let vivid = OklchColor::new(0.7, 0.4, 150.);
+        assert!(!vivid.is_in_gamut());
+        let clipped = vivid.clip_to_gamut();
+        assert!(clipped.is_in_gamut());
+        assert!(clipped.chroma < vivid.chroma);
+        assert_eq!(clipped.l, vivid.l);
+        assert_eq!(clipped.hue, vivid.hue);
+    }
+}
@@ -0,0 +1,142 @@
+use super::{from_linear, Color, Float};
+
+/// Transfer function of one channel, converts encoded value to linear light
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToneCurve {
+    /// Linear channel
+    Identity,
+    /// Pure power function `x ^ gamma`
+    Gamma(Float),
+    /// Sampled curve with values in `0..1` for evenly spaced inputs, interpolated linearly
+    Table(Vec<Float>),
+    /// ICC parametric curve `(a * x + b) ^ g + e` if `x >= d` else `c * x + f`.
+    ///
+    /// Covers all five ICC function types, missing parameters have neutral values.
+    Parametric {
+        /// Exponent
+        g: Float,
+        /// Scale of power segment
+        a: Float,
+        /// Offset of power segment
+        b: Float,
+        /// Scale of linear segment
+        c: Float,
+        /// Boundary between segments
+        d: Float,
+        /// Offset added to power segment
+        e: Float,
+        /// Offset added to linear segment
+        f: Float,
+    },
+}
+
+impl ToneCurve {
+    /// The sRGB transfer function
+    pub const SRGB: ToneCurve = ToneCurve::Parametric {
+        g: 2.4,
+        a: 1.0 / 1.055,
+        b: 0.055 / 1.055,
+        c: 1.0 / 12.92,
+        d: 0.04045,
+        e: 0.0,
+        f: 0.0,
+    };
+
+    /// Convert encoded value to linear light
+    pub fn eval(&self, x: Float) -> Float {
+        match self {
+            Self::Identity => x,
+            Self::Gamma(gamma) => x.max(0.0).powf(*gamma),
+            Self::Table(table) => match table.len() {
+                0 => x,
+                1 => table[0],
+                len => {
+                    let position = x.clamp(0.0, 1.0) * (len - 1) as Float;
+                    let idx = (position as usize).min(len - 2);
+                    let t = position - idx as Float;
+                    table[idx] + (table[idx + 1] - table[idx]) * t
+                }
+            },
+            Self::Parametric { g, a, b, c, d, e, f } => {
+                if x >= *d {
+                    (a * x + b).max(0.0).powf(*g) + e
+                } else {
+                    c * x + f
+                }
+            }
+        }
+    }
+}
+
+/// Rgb color space described by tone curves and primaries.
+///
+/// Components are decoded by the per channel curves and converted to CIE XYZ
+/// relative to D50 white, the profile connection space of ICC profiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RgbSpace {
+    /// Columns are XYZ of red, green and blue primaries, relative to D50
+    pub to_xyz: [[Float; 3]; 3],
+    /// Tone curves of red, green and blue channels
+    pub curves: [ToneCurve; 3],
+    /// Media white point in XYZ
+    pub white_point: [Float; 3],
+}
+
+/// D50 white point of the profile connection space
+pub(crate) const D50: [Float; 3] = [0.9642, 1.0, 0.8249];
+
+/// XYZ relative to D50 to linear sRGB, Bradford adapted
+const XYZ_D50_TO_SRGB: [[Float; 3]; 3] = [
+    [3.133_856, -1.616_867, -0.490_615],
+    [-0.978_768, 1.916_142, 0.033_454],
+    [0.071_945, -0.228_991, 1.405_243],
+];
+
+impl RgbSpace {
+    /// The sRGB space
+    pub fn srgb() -> Self {
+        Self {
+            to_xyz: [
+                [0.436_075, 0.385_065, 0.143_080],
+                [0.222_504, 0.716_879, 0.060_617],
+                [0.013_932, 0.097_105, 0.714_173],
+            ],
+            curves: [ToneCurve::SRGB, ToneCurve::SRGB, ToneCurve::SRGB],
+            white_point: D50,
+        }
+    }
+
+    /// Convert encoded components of this space to XYZ relative to D50
+    pub fn to_xyz(&self, rgb: [Float; 3]) -> [Float; 3] {
+        let linear = [
+            self.curves[0].eval(rgb[0]),
+            self.curves[1].eval(rgb[1]),
+            self.curves[2].eval(rgb[2]),
+        ];
+        multiply(&self.to_xyz, linear)
+    }
+
+    /// Convert color with components encoded in this space to sRGB, alpha is kept.
+    ///
+    /// Out of gamut colors are clamped.
+    pub fn to_srgb(&self, color: Color) -> Color {
+        let xyz = self.to_xyz([color.red, color.green, color.blue]);
+        let [red, green, blue] = multiply(&XYZ_D50_TO_SRGB, xyz);
+
+        let encode = |value: Float| from_linear(value.clamp(0.0, 1.0));
+        Color::new(encode(red), encode(green), encode(blue), color.alpha)
+    }
+}
+
+impl Default for RgbSpace {
+    fn default() -> Self {
+        Self::srgb()
+    }
+}
+
+fn multiply(matrix: &[[Float; 3]; 3], vector: [Float; 3]) -> [Float; 3] {
+    let row = |idx: usize| {
+        matrix[idx][0] * vector[0] + matrix[idx][1] * vector[1] + matrix[idx][2] * vector[2]
+    };
+    [row(0), row(1), row(2)]
+}
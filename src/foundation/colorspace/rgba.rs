@@ -3,6 +3,7 @@ use std::fmt;
 
 /// Rgba color representation with u8 components
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RgbaColor {
     /// Red component
     pub red: u8,
@@ -60,6 +60,26 @@ impl Round for CmyColor {
     }
 }
 
+impl Round for OklabColor {
+    fn round(self) -> Self {
+        OklabColor {
+            l: self.l.round(),
+            a: self.a.round(),
+            b: self.b.round(),
+        }
+    }
+}
+
+impl Round for OklchColor {
+    fn round(self) -> Self {
+        OklchColor {
+            l: self.l.round(),
+            chroma: self.chroma.round(),
+            hue: self.hue.round(),
+        }
+    }
+}
+
 impl<C: Round + ColorSpace> Round for Alpha<C> {
     fn round(self) -> Self {
         let (color, alpha) = self.split();
@@ -0,0 +1,151 @@
+//! Serialize [Color] as hex string.
+//!
+//! By default [Color] is serialized as struct with `red`, `green`, `blue` and `alpha`
+//! components. Use this module with `#[serde(with = "...")]` to store it as compact
+//! `#rrggbb` string instead, the alpha is appended as `#rrggbbaa` when color is not opaque.
+//!
+//! Deserialization of [Color] accepts both forms, so data written either way can be read back.
+//!
+//! ```
+//! use primitives::foundation::colorspace::Color;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Theme {
+//!     #[serde(with = "primitives::foundation::colorspace::serde_hex")]
+//!     accent: Color,
+//! }
+//!
+//! let json = serde_json::to_string(&Theme { accent: Color::rgb(18, 184, 134) }).unwrap();
+//! assert_eq!(json, r##"{"accent":"#12b886"}"##);
+//! ```
+
+use std::convert::TryFrom;
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+use super::{Color, ColorError, Component};
+
+/// Serialize color as hex string
+pub fn serialize<T: Component, S: Serializer>(
+    color: &Color<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&to_hex(color.cast()))
+}
+
+/// Deserialize color from hex string
+pub fn deserialize<'de, T: Component, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Color<T>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    from_hex(&value)
+        .map(|color| color.cast())
+        .map_err(de::Error::custom)
+}
+
+/// Any accepted representation of serialized color
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ColorRepr<T> {
+    Hex(String),
+    Components {
+        red: T,
+        green: T,
+        blue: T,
+        alpha: Option<T>,
+    },
+}
+
+impl<T: Component> TryFrom<ColorRepr<T>> for Color<T> {
+    type Error = ColorError;
+
+    fn try_from(repr: ColorRepr<T>) -> Result<Self, Self::Error> {
+        match repr {
+            ColorRepr::Hex(value) => from_hex(&value).map(|color| color.cast()),
+            ColorRepr::Components {
+                red,
+                green,
+                blue,
+                alpha,
+            } => Ok(Color::from_components(
+                red,
+                green,
+                blue,
+                alpha.unwrap_or(T::MAX),
+            )),
+        }
+    }
+}
+
+fn to_hex(color: Color<u8>) -> String {
+    let mut hex = format!("#{:02x}{:02x}{:02x}", color.red, color.green, color.blue);
+    if color.alpha != u8::MAX {
+        hex.push_str(&format!("{:02x}", color.alpha));
+    }
+    hex
+}
+
+fn from_hex(value: &str) -> Result<Color<u8>, ColorError> {
+    Color::try_from_hex(value)
+        .map(|color| color.cast())
+        .ok_or(ColorError::InvalidHex)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Swatch {
+        #[serde(with = "super")]
+        hex: Color,
+        plain: Color,
+    }
+
+    #[test]
+    fn struct_roundtrip() {
+        let color = Color::new(0.25, 0.5, 0.75, 0.5);
+        let json = serde_json::to_string(&color).unwrap();
+        assert_eq!(json, r#"{"red":0.25,"green":0.5,"blue":0.75,"alpha":0.5}"#);
+        assert_eq!(serde_json::from_str::<Color>(&json).unwrap(), color);
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        let swatch = Swatch {
+            hex: Color::rgb(18, 184, 134),
+            plain: Color::rgba(0, 0, 0, 0),
+        };
+        let json = serde_json::to_string(&swatch).unwrap();
+        assert!(json.starts_with(r##"{"hex":"#12b886","plain":{"##));
+        assert_eq!(serde_json::from_str::<Swatch>(&json).unwrap(), swatch);
+
+        let translucent = Color::rgba(255, 0, 0, 128);
+        let json = serde_json::to_string(&Swatch { hex: translucent, plain: translucent }).unwrap();
+        assert!(json.starts_with(r##"{"hex":"#ff000080""##));
+    }
+
+    #[test]
+    fn either_form() {
+        assert_eq!(serde_json::from_str::<Color>(r##""#fff""##).unwrap(), Color::rgb(255, 255, 255));
+        assert_eq!(serde_json::from_str::<Color>(r#""12b886""#).unwrap(), Color::rgb(18, 184, 134));
+        assert_eq!(
+            serde_json::from_str::<Color>(r#"{"red":1.0,"green":0.0,"blue":0.0}"#).unwrap(),
+            Color::new(1.0, 0.0, 0.0, 1.0)
+        );
+        assert!(serde_json::from_str::<Color>(r##""#12b88""##).is_err());
+        assert!(serde_json::from_str::<Color>(r##""#gggggg""##).is_err());
+    }
+
+    #[test]
+    fn precision() {
+        let texel: Color<u8> = serde_json::from_str(r##""#12b886""##).unwrap();
+        assert_eq!(texel.to_array(), [18, 184, 134, 255]);
+        assert_eq!(serde_json::to_string(&texel).unwrap(), r#"{"red":18,"green":184,"blue":134,"alpha":255}"#);
+
+        let hdr: Color<f64> = serde_json::from_str(r#"{"red":2.5,"green":1.0,"blue":0.0}"#).unwrap();
+        assert_eq!(hdr.to_array(), [2.5, 1.0, 0.0, 1.0]);
+    }
+}
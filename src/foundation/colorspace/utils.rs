@@ -50,6 +50,26 @@ pub(crate) fn percentage_to_fraction(value: Float) -> Float {
     clamp(value, 0., 100.) / 100.
 }
 
+/// Decode sRGB component into linear light
+#[inline(always)]
+pub(crate) fn to_linear(value: Float) -> Float {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode linear light into sRGB component
+#[inline(always)]
+pub(crate) fn from_linear(value: Float) -> Float {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1. / 2.4) - 0.055
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
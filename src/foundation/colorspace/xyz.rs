@@ -4,6 +4,7 @@ use super::{Color, ColorError, Float};
 
 /// Xyz color representation
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XyzColor {
     pub x: Float,
     pub y: Float,
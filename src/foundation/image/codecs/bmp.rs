@@ -0,0 +1,361 @@
+//! Windows bitmap with core, info and V2 to V5 headers.
+//!
+//! Uncompressed and bit field images of 1, 4, 8, 16, 24 and 32 bits are supported.
+//! Images with alpha mask decode to `ARgb32`, 16 bit images with `565` masks to `Rgb16_565`
+//! and others to `Rgb24`.
+
+use super::{check_format, check_length, create_image, to_byte, ImageError, Reader};
+use crate::foundation::{
+    colorspace::Color,
+    image::{ImageData, PixelFormat},
+};
+
+const FILE_HEADER_SIZE: usize = 14;
+const INFO_HEADER_SIZE: u32 = 40;
+const V4_HEADER_SIZE: u32 = 108;
+
+const BI_RGB: u32 = 0;
+const BI_BITFIELDS: u32 = 3;
+const BI_ALPHABITFIELDS: u32 = 6;
+
+/// Channel stored under bit mask
+#[derive(Debug, Clone, Copy)]
+struct Mask {
+    mask: u32,
+    shift: u32,
+    max: f32,
+}
+
+impl Mask {
+    fn new(mask: u32) -> Self {
+        let shift = if mask == 0 { 0 } else { mask.trailing_zeros() };
+        let bits = mask.count_ones();
+        Self {
+            mask,
+            shift,
+            max: ((1u64 << bits) - 1) as f32,
+        }
+    }
+
+    fn get(&self, value: u32) -> f32 {
+        if self.mask == 0 {
+            return 0.0;
+        }
+        ((value & self.mask) >> self.shift) as f32 / self.max
+    }
+}
+
+/// Decode bitmap file
+pub fn decode(data: &[u8]) -> Result<ImageData, ImageError> {
+    let mut reader = Reader::new(data);
+    if reader.bytes(2)? != b"BM" {
+        return Err(ImageError::UnknownFormat);
+    }
+    reader.bytes(8)?;
+    let offset = reader.u32_le()? as usize;
+
+    let header_size = reader.u32_le()?;
+    let (width, height, bpp, compression, colors_used) = match header_size {
+        12 => {
+            let width = reader.u16_le()? as i32;
+            let height = reader.u16_le()? as i32;
+            reader.u16_le()?;
+            (width, height, reader.u16_le()?, BI_RGB, 0)
+        }
+        40 | 52 | 56 | 64 | 108 | 124 => {
+            let width = reader.u32_le()? as i32;
+            let height = reader.u32_le()? as i32;
+            reader.u16_le()?;
+            let bpp = reader.u16_le()?;
+            let compression = reader.u32_le()?;
+            reader.bytes(12)?;
+            let colors_used = reader.u32_le()?;
+            reader.u32_le()?;
+            (width, height, bpp, compression, colors_used)
+        }
+        _ => return Err(ImageError::Unsupported("BMP header version")),
+    };
+
+    match compression {
+        BI_RGB | BI_BITFIELDS | BI_ALPHABITFIELDS => {}
+        1 | 2 => return Err(ImageError::Unsupported("BMP RLE compression")),
+        4 | 5 => return Err(ImageError::Unsupported("BMP with embedded JPEG or PNG")),
+        _ => return Err(ImageError::Unsupported("BMP compression method")),
+    }
+    if width <= 0 || height == 0 || height == i32::MIN {
+        return Err(ImageError::Invalid("BMP dimensions are not positive"));
+    }
+    let top_down = height < 0;
+    let (width, height) = (width as u32, height.unsigned_abs());
+
+    // masks are part of V2+ headers or follow info header
+    let mut masks = match bpp {
+        16 => [0x7c00, 0x03e0, 0x001f, 0],
+        _ => [0x00ff_0000, 0x0000_ff00, 0x0000_00ff, 0],
+    };
+    if compression != BI_RGB {
+        let count = match (header_size, compression) {
+            (12, _) => 0,
+            (INFO_HEADER_SIZE, BI_BITFIELDS) | (52, _) => 3,
+            _ => 4,
+        };
+        for mask in masks.iter_mut().take(count) {
+            *mask = reader.u32_le()?;
+        }
+    } else if header_size >= 56 && bpp == 32 {
+        // uncompressed images may still declare alpha mask in V3+ headers
+        reader.bytes(12)?;
+        masks[3] = reader.u32_le()?;
+    }
+    if !matches!(bpp, 16 | 32) && compression != BI_RGB {
+        return Err(ImageError::Invalid(
+            "BMP bit fields need 16 or 32 bits per pixel",
+        ));
+    }
+
+    let palette = match bpp {
+        1 | 4 | 8 => {
+            reader.seek(
+                FILE_HEADER_SIZE + header_size as usize + extra_masks(header_size, compression),
+            )?;
+            let count = match colors_used {
+                0 => 1usize << bpp,
+                count => (count as usize).min(256),
+            };
+            let entry = if header_size == 12 { 3 } else { 4 };
+            let mut palette = Vec::with_capacity(count);
+            for _ in 0..count {
+                let bytes = reader.bytes(entry)?;
+                palette.push(Color::rgb(bytes[2], bytes[1], bytes[0]));
+            }
+            palette
+        }
+        16 | 24 | 32 => Vec::new(),
+        _ => return Err(ImageError::Unsupported("BMP bits per pixel")),
+    };
+
+    let channels = [
+        Mask::new(masks[0]),
+        Mask::new(masks[1]),
+        Mask::new(masks[2]),
+        Mask::new(masks[3]),
+    ];
+    let format = if masks[3] != 0 {
+        PixelFormat::ARgb32
+    } else if bpp == 16 && masks[..3] == [0xf800, 0x07e0, 0x001f] {
+        PixelFormat::Rgb16_565
+    } else {
+        PixelFormat::Rgb24
+    };
+    let stride = (bpp as usize * width as usize).div_ceil(32) * 4;
+    check_length(data, offset, (stride as u64).saturating_mul(height as u64))?;
+    let mut image = create_image(format, width, height)?;
+
+    for row in 0..height {
+        let y = if top_down { row } else { height - 1 - row };
+        let start = offset + row as usize * stride;
+        let bytes = data
+            .get(start..start + stride)
+            .ok_or(ImageError::Truncated)?;
+        for x in 0..width {
+            let idx = x as usize;
+            let color = match bpp {
+                1 | 4 | 8 => {
+                    let bit = idx * bpp as usize;
+                    let shift = 8 - bpp as usize - bit % 8;
+                    let index = (bytes[bit / 8] >> shift) & ((1u16 << bpp) - 1) as u8;
+                    *palette
+                        .get(index as usize)
+                        .ok_or(ImageError::Invalid("BMP color index is out of palette"))?
+                }
+                24 => Color::rgb(bytes[idx * 3 + 2], bytes[idx * 3 + 1], bytes[idx * 3]),
+                _ => {
+                    let size = bpp as usize / 8;
+                    let value = bytes[idx * size..idx * size + size]
+                        .iter()
+                        .rev()
+                        .fold(0u32, |acc, byte| acc << 8 | *byte as u32);
+                    let alpha = if masks[3] != 0 {
+                        channels[3].get(value)
+                    } else {
+                        1.0
+                    };
+                    Color::new(
+                        channels[0].get(value),
+                        channels[1].get(value),
+                        channels[2].get(value),
+                        alpha,
+                    )
+                }
+            };
+            image.set_pixel(x, y, color);
+        }
+    }
+
+    Ok(image.freeze())
+}
+
+/// Size of masks stored after info header
+fn extra_masks(header_size: u32, compression: u32) -> usize {
+    match (header_size, compression) {
+        (INFO_HEADER_SIZE, BI_BITFIELDS) => 12,
+        (INFO_HEADER_SIZE, BI_ALPHABITFIELDS) => 16,
+        _ => 0,
+    }
+}
+
+/// Encode bitmap file.
+///
+/// Images with alpha are written with 32 bits and V4 header with alpha mask,
+/// other images with 24 bits and info header.
+pub fn encode(image: &ImageData) -> Result<Vec<u8>, ImageError> {
+    check_format(image)?;
+    let (width, height) = (image.width, image.height);
+    let alpha = image.format.has_alpha();
+    let (header_size, bpp) = if alpha {
+        (V4_HEADER_SIZE, 32)
+    } else {
+        (INFO_HEADER_SIZE, 24)
+    };
+    let stride = (bpp * width as usize).div_ceil(32) * 4;
+    let offset = FILE_HEADER_SIZE + header_size as usize;
+    let size = offset + stride * height as usize;
+    if size > u32::MAX as usize || width > i32::MAX as u32 || height > i32::MAX as u32 {
+        return Err(ImageError::TooLarge);
+    }
+
+    let mut data = Vec::with_capacity(size);
+    data.extend_from_slice(b"BM");
+    data.extend_from_slice(&(size as u32).to_le_bytes());
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&(offset as u32).to_le_bytes());
+
+    data.extend_from_slice(&header_size.to_le_bytes());
+    data.extend_from_slice(&width.to_le_bytes());
+    data.extend_from_slice(&height.to_le_bytes());
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&(bpp as u16).to_le_bytes());
+    let compression = if alpha { BI_BITFIELDS } else { BI_RGB };
+    data.extend_from_slice(&compression.to_le_bytes());
+    data.extend_from_slice(&((stride * height as usize) as u32).to_le_bytes());
+    // 72 DPI
+    data.extend_from_slice(&2835u32.to_le_bytes());
+    data.extend_from_slice(&2835u32.to_le_bytes());
+    data.extend_from_slice(&[0; 8]);
+    if alpha {
+        for mask in [0x00ff_0000u32, 0x0000_ff00, 0x0000_00ff, 0xff00_0000].iter() {
+            data.extend_from_slice(&mask.to_le_bytes());
+        }
+        // sRGB color space, unused endpoints and gamma
+        data.extend_from_slice(b"BGRs");
+        data.extend_from_slice(&[0; 48]);
+    }
+
+    for y in (0..height).rev() {
+        let start = data.len();
+        for x in 0..width {
+            let color = image
+                .try_get_pixel(x, y)
+                .unwrap_or_else(|| Color::new(0.0, 0.0, 0.0, 0.0));
+            data.extend_from_slice(&[
+                to_byte(color.blue),
+                to_byte(color.green),
+                to_byte(color.red),
+            ]);
+            if alpha {
+                data.push(to_byte(color.alpha));
+            }
+        }
+        data.resize(start + stride, 0);
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Bitmap with info header, optional masks or palette, and pixel rows
+    fn bitmap(bpp: u16, height: i32, compression: u32, extra: &[u8], rows: &[u8]) -> Vec<u8> {
+        let offset = (14 + 40 + extra.len()) as u32;
+        let mut data = b"BM".to_vec();
+        data.extend_from_slice(&(offset + rows.len() as u32).to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&offset.to_le_bytes());
+        data.extend_from_slice(&40u32.to_le_bytes());
+        data.extend_from_slice(&2i32.to_le_bytes());
+        data.extend_from_slice(&height.to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&bpp.to_le_bytes());
+        data.extend_from_slice(&compression.to_le_bytes());
+        data.extend_from_slice(&[0; 20]);
+        data.extend_from_slice(extra);
+        data.extend_from_slice(rows);
+        data
+    }
+
+    fn bytes(image: &ImageData) -> Vec<[u8; 4]> {
+        image
+            .pixels()
+            .map(|color| color.cast::<u8>().to_array())
+            .collect()
+    }
+
+    #[test]
+    fn variants() {
+        // bottom-up 2x2 palette image, rows padded to 4 bytes
+        let palette = [0, 0, 255, 0, 255, 0, 0, 0];
+        let image = decode(&bitmap(
+            1,
+            2,
+            BI_RGB,
+            &palette,
+            &[0x40, 0, 0, 0, 0x80, 0, 0, 0],
+        ))
+        .unwrap();
+        assert_eq!(image.format, PixelFormat::Rgb24);
+        let (red, blue) = ([255, 0, 0, 255], [0, 0, 255, 255]);
+        assert_eq!(bytes(&image), vec![blue, red, red, blue]);
+
+        // top-down 565 bit fields
+        let masks = [0x00, 0xf8, 0, 0, 0xe0, 0x07, 0, 0, 0x1f, 0, 0, 0];
+        let rows = [0x00, 0xf8, 0x1f, 0x00, 0xe0, 0x07, 0xff, 0xff];
+        let image = decode(&bitmap(16, -2, BI_BITFIELDS, &masks, &rows)).unwrap();
+        assert_eq!(image.format, PixelFormat::Rgb16_565);
+        assert_eq!(image.get_pixel(0, 0), Color::rgb(255, 0, 0));
+        assert_eq!(image.get_pixel(1, 1), Color::rgb(255, 255, 255));
+
+        // 32 bits with alpha bit field
+        let masks = [0, 0, 0xff, 0, 0, 0xff, 0, 0, 0xff, 0, 0, 0, 0, 0, 0, 0xff];
+        let rows = [0, 0, 0xff, 0xff, 0, 0, 0xff, 0x80];
+        let image = decode(&bitmap(32, 1, BI_ALPHABITFIELDS, &masks, &rows)).unwrap();
+        assert_eq!(image.format, PixelFormat::ARgb32);
+        assert_eq!(bytes(&image), vec![red, [255, 0, 0, 128]]);
+
+        let data = encode(&image).unwrap();
+        assert_eq!(&data[..2], b"BM");
+        assert_eq!(data.len(), 14 + 108 + 8);
+        assert_eq!(bytes(&decode(&data).unwrap()), bytes(&image));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            decode(&bitmap(8, 1, 1, &[], &[0; 4])).err(),
+            Some(ImageError::Unsupported("BMP RLE compression"))
+        );
+        assert_eq!(
+            decode(&bitmap(32, 1, 5, &[], &[0; 8])).err(),
+            Some(ImageError::Unsupported("BMP with embedded JPEG or PNG"))
+        );
+        assert_eq!(
+            decode(&bitmap(24, 2, BI_RGB, &[], &[0; 8])).err(),
+            Some(ImageError::Truncated)
+        );
+        assert!(matches!(
+            decode(&bitmap(24, 0, BI_RGB, &[], &[])).err(),
+            Some(ImageError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,381 @@
+//! Readers and writers of simple image file formats.
+//!
+//! Decoded images get the closest [PixelFormat], encoders pick the smallest variant
+//! of file format keeping all channels of image.
+
+use std::fmt;
+
+use super::{ImageData, ImageDataMut, PixelFormat};
+
+pub mod bmp;
+pub mod png;
+pub mod pnm;
+pub mod qoi;
+pub mod tga;
+mod zlib;
+
+/// Maximal count of pixels of decoded image
+const MAX_PIXELS: u64 = 1 << 28;
+
+/// Represents image decoding and encoding error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// Data does not start with signature of known format
+    UnknownFormat,
+    /// Data ends before image is complete
+    Truncated,
+    /// Header or data is malformed
+    Invalid(&'static str),
+    /// Valid file uses variant of format which is not supported
+    Unsupported(&'static str),
+    /// Image has too many pixels
+    TooLarge,
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFormat => write!(f, "Unknown image format"),
+            Self::Truncated => write!(f, "Truncated image data"),
+            Self::Invalid(reason) => write!(f, "Malformed image: {}", reason),
+            Self::Unsupported(variant) => write!(f, "Unsupported image variant: {}", variant),
+            Self::TooLarge => write!(f, "Image is too large (more than {} pixels)", MAX_PIXELS),
+        }
+    }
+}
+
+/// Supported image file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageFormat {
+    /// Netpbm formats PBM, PGM, PPM and PAM
+    Pnm,
+    /// Windows bitmap
+    Bmp,
+    /// Truevision TGA
+    Tga,
+    /// Quite OK Image format
+    Qoi,
+    /// Portable Network Graphics
+    Png,
+}
+
+impl ImageFormat {
+    /// All image formats
+    pub const ALL: [ImageFormat; 5] = [Self::Pnm, Self::Bmp, Self::Tga, Self::Qoi, Self::Png];
+
+    /// Detect format by signature at start of data, TGA has no signature and is never detected
+    pub fn detect(data: &[u8]) -> Option<ImageFormat> {
+        match data {
+            [b'B', b'M', ..] => Some(Self::Bmp),
+            [b'q', b'o', b'i', b'f', ..] => Some(Self::Qoi),
+            [0x89, b'P', b'N', b'G', ..] => Some(Self::Png),
+            [b'P', b'1'..=b'7', ..] => Some(Self::Pnm),
+            _ => None,
+        }
+    }
+
+    /// Retrieve format by file extension, case insensitive
+    pub fn from_extension(extension: &str) -> Option<ImageFormat> {
+        match extension.to_ascii_lowercase().as_str() {
+            "pbm" | "pgm" | "ppm" | "pnm" | "pam" => Some(Self::Pnm),
+            "bmp" | "dib" => Some(Self::Bmp),
+            "tga" | "icb" | "vda" | "vst" => Some(Self::Tga),
+            "qoi" => Some(Self::Qoi),
+            "png" => Some(Self::Png),
+            _ => None,
+        }
+    }
+
+    /// Retrieve common file extension
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Pnm => "pnm",
+            Self::Bmp => "bmp",
+            Self::Tga => "tga",
+            Self::Qoi => "qoi",
+            Self::Png => "png",
+        }
+    }
+
+    /// Decode image of this format
+    pub fn decode(&self, data: &[u8]) -> Result<ImageData, ImageError> {
+        match self {
+            Self::Pnm => pnm::decode(data),
+            Self::Bmp => bmp::decode(data),
+            Self::Tga => tga::decode(data),
+            Self::Qoi => qoi::decode(data),
+            Self::Png => png::decode(data),
+        }
+    }
+
+    /// Encode image into this format, TGA images are compressed with RLE
+    pub fn encode(&self, image: &ImageData) -> Result<Vec<u8>, ImageError> {
+        match self {
+            Self::Pnm => pnm::encode(image),
+            Self::Bmp => bmp::encode(image),
+            Self::Tga => tga::encode(image, true),
+            Self::Qoi => qoi::encode(image),
+            Self::Png => png::encode(image),
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Pnm => "PNM",
+            Self::Bmp => "BMP",
+            Self::Tga => "TGA",
+            Self::Qoi => "QOI",
+            Self::Png => "PNG",
+        })
+    }
+}
+
+impl ImageData {
+    /// Decode image detecting its format by signature.
+    ///
+    /// Data without known signature is tried as TGA, use [ImageFormat::decode]
+    /// to get detailed errors of TGA files.
+    pub fn decode(data: &[u8]) -> Result<ImageData, ImageError> {
+        match ImageFormat::detect(data) {
+            Some(format) => format.decode(data),
+            None => tga::decode(data).map_err(|_| ImageError::UnknownFormat),
+        }
+    }
+
+    /// Encode image into file format
+    pub fn encode(&self, format: ImageFormat) -> Result<Vec<u8>, ImageError> {
+        format.encode(self)
+    }
+}
+
+/// Fail when image of size has more than maximal count of pixels
+fn check_size(width: u32, height: u32) -> Result<(), ImageError> {
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(ImageError::TooLarge);
+    }
+    Ok(())
+}
+
+/// Create zeroed image checking its size
+fn create_image(format: PixelFormat, width: u32, height: u32) -> Result<ImageDataMut, ImageError> {
+    check_size(width, height)?;
+    Ok(ImageDataMut::new(format, width, height))
+}
+
+/// Fail when data after start is shorter than required bytes, checked before image is allocated
+fn check_length(data: &[u8], start: usize, required: u64) -> Result<(), ImageError> {
+    if (data.len().saturating_sub(start) as u64) < required {
+        return Err(ImageError::Truncated);
+    }
+    Ok(())
+}
+
+/// Fail when image has no pixel format to encode
+fn check_format(image: &ImageData) -> Result<(), ImageError> {
+    match image.format {
+        PixelFormat::Invalid => Err(ImageError::Unsupported("invalid pixel format")),
+        _ => Ok(()),
+    }
+}
+
+/// Convert color component to byte
+#[inline]
+fn to_byte(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Cursor over bytes of file
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], ImageError> {
+        let end = self
+            .position
+            .checked_add(count)
+            .ok_or(ImageError::Truncated)?;
+        let bytes = self
+            .data
+            .get(self.position..end)
+            .ok_or(ImageError::Truncated)?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn seek(&mut self, position: usize) -> Result<(), ImageError> {
+        if position > self.data.len() {
+            return Err(ImageError::Truncated);
+        }
+        self.position = position;
+        Ok(())
+    }
+
+    fn u8(&mut self) -> Result<u8, ImageError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16_le(&mut self) -> Result<u16, ImageError> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32_le(&mut self) -> Result<u32, ImageError> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn u32_be(&mut self) -> Result<u32, ImageError> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::foundation::colorspace::Color;
+
+    /// Image with every pixel different, translucent pixels for formats with alpha
+    pub(crate) fn gradient(format: PixelFormat, width: u32, height: u32) -> ImageData {
+        let mut image = ImageDataMut::new(format, width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let alpha = if (x + y) % 3 == 0 {
+                    255
+                } else {
+                    255 - (x * 40) as u8
+                };
+                let color = Color::rgba((x * 50) as u8, (y * 60) as u8, 200, alpha);
+                image.set_pixel(x, y, color);
+            }
+        }
+        image.freeze()
+    }
+
+    pub(crate) fn assert_same_pixels(left: &ImageData, right: &ImageData) {
+        assert_eq!((left.width, left.height), (right.width, right.height));
+        for (idx, (left, right)) in left.pixels().zip(right.pixels()).enumerate() {
+            assert_eq!(
+                left.cast::<u8>().to_array(),
+                right.cast::<u8>().to_array(),
+                "pixel {}",
+                idx
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let formats = [
+            PixelFormat::ARgb32,
+            PixelFormat::Rgb24,
+            PixelFormat::A8,
+            PixelFormat::A1,
+            PixelFormat::Rgb16_565,
+        ];
+        for file_format in ImageFormat::ALL.iter() {
+            for format in formats.iter() {
+                let image = gradient(*format, 5, 4);
+                let data = image.encode(*file_format).unwrap();
+                let decoded = file_format.decode(&data).unwrap();
+                assert_same_pixels(&image, &decoded);
+                if *file_format != ImageFormat::Tga {
+                    assert_eq!(ImageFormat::detect(&data), Some(*file_format));
+                }
+                assert_same_pixels(&image, &ImageData::decode(&data).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_input() {
+        // decoders must fail without panics on truncated and randomly corrupted files
+        let mut seed = 0x2545_f491u32;
+        let mut random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed
+        };
+        for file_format in ImageFormat::ALL.iter() {
+            for format in [PixelFormat::ARgb32, PixelFormat::Rgb24, PixelFormat::A1].iter() {
+                let data = gradient(*format, 7, 5).encode(*file_format).unwrap();
+                for length in 0..data.len() {
+                    let _ = file_format.decode(&data[..length]);
+                }
+                for _ in 0..500 {
+                    let mut corrupted = data.clone();
+                    for _ in 0..1 + random() % 4 {
+                        let idx = random() as usize % corrupted.len();
+                        corrupted[idx] = random() as u8;
+                    }
+                    let _ = file_format.decode(&corrupted);
+                    let _ = ImageData::decode(&corrupted);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn oversized_header() {
+        // headers of huge images without pixel data fail before the image is allocated
+        let side = 1u32 << 14;
+        let image = gradient(PixelFormat::Rgb24, 1, 1);
+
+        let mut bmp = image.encode(ImageFormat::Bmp).unwrap();
+        bmp[18..22].copy_from_slice(&side.to_le_bytes());
+        bmp[22..26].copy_from_slice(&side.to_le_bytes());
+        let mut tga = image.encode(ImageFormat::Tga).unwrap();
+        tga[12..14].copy_from_slice(&(side as u16).to_le_bytes());
+        tga[14..16].copy_from_slice(&(side as u16).to_le_bytes());
+        let mut qoi = image.encode(ImageFormat::Qoi).unwrap();
+        qoi[4..8].copy_from_slice(&side.to_be_bytes());
+        qoi[8..12].copy_from_slice(&side.to_be_bytes());
+        let pnm = format!("P6\n{} {}\n255\n", side, side).into_bytes();
+        let mut png = image.encode(ImageFormat::Png).unwrap();
+        png[16..20].copy_from_slice(&side.to_be_bytes());
+        png[20..24].copy_from_slice(&side.to_be_bytes());
+        let crc = png::crc32(&[&png[12..29]]);
+        png[29..33].copy_from_slice(&crc.to_be_bytes());
+
+        let files = [
+            (ImageFormat::Png, png),
+            (ImageFormat::Bmp, bmp),
+            (ImageFormat::Tga, tga),
+            (ImageFormat::Qoi, qoi),
+            (ImageFormat::Pnm, pnm),
+        ];
+        for (format, data) in files.iter() {
+            let result = format.decode(data);
+            assert!(matches!(result, Err(ImageError::Truncated)), "{}", format);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            ImageData::decode(b"GIF89a").err(),
+            Some(ImageError::UnknownFormat)
+        );
+        let image = ImageData::new(PixelFormat::Invalid, 1, 1, Default::default());
+        for format in ImageFormat::ALL.iter() {
+            assert!(image.encode(*format).is_err());
+            assert!(format.decode(&[]).is_err());
+        }
+        assert_eq!(ImageFormat::from_extension("PPM"), Some(ImageFormat::Pnm));
+        assert_eq!(ImageFormat::from_extension("gif"), None);
+        assert_eq!(
+            ImageError::Unsupported("BMP RLE compression").to_string(),
+            "Unsupported image variant: BMP RLE compression"
+        );
+    }
+}
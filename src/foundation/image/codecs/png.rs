@@ -0,0 +1,653 @@
+//! Portable Network Graphics, see [specification](https://www.w3.org/TR/png/).
+//!
+//! All color types, bit depths, filters and Adam7 interlacing are decoded. Images with alpha
+//! channel or transparency chunk decode to `ARgb32`, 16 bit images without alpha to `Rgb30`
+//! and others to `Rgb24`. Ancillary chunks besides `tRNS` are skipped.
+
+use super::{check_format, check_size, create_image, zlib, ImageError, Reader};
+use crate::foundation::{
+    colorspace::Color,
+    image::{ImageData, PixelFormat},
+};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+const GRAY: u8 = 0;
+const RGB: u8 = 2;
+const PALETTE: u8 = 3;
+const GRAY_ALPHA: u8 = 4;
+const RGB_ALPHA: u8 = 6;
+
+/// Origin and step of Adam7 passes
+const ADAM7: [(u32, u32, u32, u32); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut idx = 0;
+    while idx < 256 {
+        let mut value = idx as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 != 0 {
+                0xedb8_8320 ^ (value >> 1)
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[idx] = value;
+        idx += 1;
+    }
+    table
+}
+
+pub(super) fn crc32(parts: &[&[u8]]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for byte in parts.iter().flat_map(|part| part.iter()) {
+        crc = CRC_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// Image header
+#[derive(Debug, Clone, Copy)]
+struct Header {
+    width: u32,
+    height: u32,
+    depth: u8,
+    color_type: u8,
+    interlaced: bool,
+}
+
+impl Header {
+    fn channels(&self) -> usize {
+        match self.color_type {
+            GRAY | PALETTE => 1,
+            GRAY_ALPHA => 2,
+            RGB => 3,
+            _ => 4,
+        }
+    }
+
+    /// Bytes of pixel used by filters, at least one
+    fn filter_step(&self) -> usize {
+        (self.channels() * self.depth as usize).div_ceil(8)
+    }
+
+    /// Bytes of row with width, without filter type
+    fn row_bytes(&self, width: u32) -> usize {
+        (width as usize * self.channels() * self.depth as usize).div_ceil(8)
+    }
+
+    /// Width and height of interlace pass, or of whole image when not interlaced
+    fn passes(&self) -> Vec<(u32, u32, u32, u32, u32, u32)> {
+        if !self.interlaced {
+            return vec![(0, 0, 1, 1, self.width, self.height)];
+        }
+        ADAM7
+            .iter()
+            .map(|(x, y, dx, dy)| {
+                let width = (self.width + dx - 1 - x) / dx;
+                let height = (self.height + dy - 1 - y) / dy;
+                (*x, *y, *dx, *dy, width, height)
+            })
+            .collect()
+    }
+}
+
+/// Decode PNG file
+pub fn decode(data: &[u8]) -> Result<ImageData, ImageError> {
+    let mut reader = Reader::new(data);
+    if reader.bytes(8)? != SIGNATURE {
+        return Err(ImageError::UnknownFormat);
+    }
+
+    let mut header = None;
+    let mut palette: Vec<Color> = Vec::new();
+    let mut transparency: Option<Vec<u8>> = None;
+    let mut compressed = Vec::new();
+    loop {
+        let length = reader.u32_be()? as usize;
+        if length > i32::MAX as usize {
+            return Err(ImageError::Invalid("PNG chunk is too long"));
+        }
+        let kind = reader.bytes(4)?;
+        let content = reader.bytes(length)?;
+        if reader.u32_be()? != crc32(&[kind, content]) {
+            return Err(ImageError::Invalid("PNG chunk CRC mismatch"));
+        }
+        if header.is_none() && kind != b"IHDR" {
+            return Err(ImageError::Invalid("PNG does not start with IHDR chunk"));
+        }
+
+        match kind {
+            b"IHDR" => {
+                if header.is_some() || content.len() != 13 {
+                    return Err(ImageError::Invalid("malformed PNG IHDR chunk"));
+                }
+                header = Some(read_header(content)?);
+            }
+            b"PLTE" => {
+                if content.len() % 3 != 0 || content.len() > 3 * 256 {
+                    return Err(ImageError::Invalid("malformed PNG PLTE chunk"));
+                }
+                palette = content
+                    .chunks(3)
+                    .map(|rgb| Color::rgb(rgb[0], rgb[1], rgb[2]))
+                    .collect();
+            }
+            b"tRNS" => transparency = Some(content.to_vec()),
+            b"IDAT" => compressed.extend_from_slice(content),
+            b"IEND" => break,
+            _ if kind[0].is_ascii_uppercase() => {
+                return Err(ImageError::Unsupported("PNG critical chunk"));
+            }
+            _ => {}
+        }
+    }
+    let header = header.ok_or(ImageError::Invalid("PNG without IHDR chunk"))?;
+    if header.color_type == PALETTE && palette.is_empty() {
+        return Err(ImageError::Invalid("PNG palette image without PLTE chunk"));
+    }
+
+    // alpha of palette entries, or sample values of transparent color
+    let maximum = ((1u32 << header.depth) - 1) as f32;
+    let mut key = None;
+    if let Some(transparency) = transparency.as_ref() {
+        match header.color_type {
+            PALETTE => {
+                if transparency.len() > palette.len() {
+                    return Err(ImageError::Invalid("PNG tRNS chunk is longer than palette"));
+                }
+                for (color, alpha) in palette.iter_mut().zip(transparency.iter()) {
+                    color.alpha = *alpha as f32 / 255.0;
+                }
+            }
+            GRAY | RGB => {
+                if transparency.len() != 2 * header.channels() {
+                    return Err(ImageError::Invalid("malformed PNG tRNS chunk"));
+                }
+                let samples: Vec<u32> = transparency
+                    .chunks(2)
+                    .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]) as u32)
+                    .collect();
+                key = Some(samples);
+            }
+            _ => return Err(ImageError::Invalid("PNG tRNS chunk with alpha channel")),
+        }
+    }
+
+    let alpha = matches!(header.color_type, GRAY_ALPHA | RGB_ALPHA) || transparency.is_some();
+    let format = if alpha {
+        PixelFormat::ARgb32
+    } else if header.depth == 16 {
+        PixelFormat::Rgb30
+    } else {
+        PixelFormat::Rgb24
+    };
+    // pixel data is inflated before the image is allocated, huge images fail before both
+    check_size(header.width, header.height)?;
+
+    let passes = header.passes();
+    let size = passes
+        .iter()
+        .filter(|pass| pass.4 > 0)
+        .map(|pass| pass.5 as usize * (1 + header.row_bytes(pass.4)))
+        .sum();
+    let mut raw = zlib::decompress(&compressed, size)?;
+    if raw.len() != size {
+        return Err(ImageError::Truncated);
+    }
+    let mut image = create_image(format, header.width, header.height)?;
+
+    let mut offset = 0;
+    let mut samples = [0u32; 4];
+    for (x0, y0, dx, dy, width, height) in passes {
+        if width == 0 || height == 0 {
+            continue;
+        }
+        let row_bytes = header.row_bytes(width);
+        let pass = &mut raw[offset..offset + height as usize * (row_bytes + 1)];
+        offset += pass.len();
+        unfilter(pass, row_bytes, header.filter_step())?;
+
+        for (row, line) in pass.chunks(row_bytes + 1).enumerate() {
+            let line = &line[1..];
+            for column in 0..width as usize {
+                for (channel, sample) in samples.iter_mut().take(header.channels()).enumerate() {
+                    *sample = read_sample(line, column * header.channels() + channel, header.depth);
+                }
+                let value = |sample: u32| sample as f32 / maximum;
+                let opaque = match &key {
+                    Some(key) => key[..] != samples[..header.channels()],
+                    None => true,
+                };
+                let transparent = if opaque { 1.0 } else { 0.0 };
+                let color = match header.color_type {
+                    GRAY => {
+                        let gray = value(samples[0]);
+                        Color::new(gray, gray, gray, transparent)
+                    }
+                    RGB => Color::new(
+                        value(samples[0]),
+                        value(samples[1]),
+                        value(samples[2]),
+                        transparent,
+                    ),
+                    PALETTE => *palette
+                        .get(samples[0] as usize)
+                        .ok_or(ImageError::Invalid("PNG color index is out of palette"))?,
+                    GRAY_ALPHA => {
+                        let gray = value(samples[0]);
+                        Color::new(gray, gray, gray, value(samples[1]))
+                    }
+                    _ => Color::new(
+                        value(samples[0]),
+                        value(samples[1]),
+                        value(samples[2]),
+                        value(samples[3]),
+                    ),
+                };
+                let x = x0 + column as u32 * dx;
+                let y = y0 + row as u32 * dy;
+                image.set_pixel(x, y, color);
+            }
+        }
+    }
+
+    Ok(image.freeze())
+}
+
+fn read_header(content: &[u8]) -> Result<Header, ImageError> {
+    let mut reader = Reader::new(content);
+    let width = reader.u32_be()?;
+    let height = reader.u32_be()?;
+    let depth = reader.u8()?;
+    let color_type = reader.u8()?;
+    let (compression, filter, interlace) = (reader.u8()?, reader.u8()?, reader.u8()?);
+
+    if width == 0 || height == 0 || width > i32::MAX as u32 || height > i32::MAX as u32 {
+        return Err(ImageError::Invalid("PNG dimensions are out of range"));
+    }
+    let valid = match color_type {
+        GRAY => matches!(depth, 1 | 2 | 4 | 8 | 16),
+        PALETTE => matches!(depth, 1 | 2 | 4 | 8),
+        RGB | GRAY_ALPHA | RGB_ALPHA => matches!(depth, 8 | 16),
+        _ => false,
+    };
+    if !valid {
+        return Err(ImageError::Invalid(
+            "PNG color type and bit depth do not match",
+        ));
+    }
+    if compression != 0 || filter != 0 {
+        return Err(ImageError::Unsupported("PNG compression or filter method"));
+    }
+    if interlace > 1 {
+        return Err(ImageError::Unsupported("PNG interlace method"));
+    }
+
+    Ok(Header {
+        width,
+        height,
+        depth,
+        color_type,
+        interlaced: interlace == 1,
+    })
+}
+
+/// Read sample packed starting from the most significant bit, 16 bit samples are big endian
+#[inline]
+fn read_sample(line: &[u8], idx: usize, depth: u8) -> u32 {
+    match depth {
+        8 => line[idx] as u32,
+        16 => u16::from_be_bytes([line[idx * 2], line[idx * 2 + 1]]) as u32,
+        _ => {
+            let bit = idx * depth as usize;
+            let shift = 8 - depth as usize - bit % 8;
+            (line[bit / 8] >> shift) as u32 & ((1 << depth) - 1)
+        }
+    }
+}
+
+fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+    let estimate = left as i16 + up as i16 - up_left as i16;
+    let (to_left, to_up, to_up_left) = (
+        (estimate - left as i16).abs(),
+        (estimate - up as i16).abs(),
+        (estimate - up_left as i16).abs(),
+    );
+    if to_left <= to_up && to_left <= to_up_left {
+        left
+    } else if to_up <= to_up_left {
+        up
+    } else {
+        up_left
+    }
+}
+
+/// Reverse filters of rows prefixed with filter type in place
+fn unfilter(data: &mut [u8], row_bytes: usize, step: usize) -> Result<(), ImageError> {
+    let mut previous = vec![0u8; row_bytes];
+    for line in data.chunks_mut(row_bytes + 1) {
+        let (filter, row) = line.split_at_mut(1);
+        match filter[0] {
+            0 => {}
+            1 => {
+                for idx in step..row_bytes {
+                    row[idx] = row[idx].wrapping_add(row[idx - step]);
+                }
+            }
+            2 => {
+                for (value, up) in row.iter_mut().zip(previous.iter()) {
+                    *value = value.wrapping_add(*up);
+                }
+            }
+            3 => {
+                for idx in 0..row_bytes {
+                    let left = if idx >= step { row[idx - step] } else { 0 };
+                    let average = ((left as u16 + previous[idx] as u16) / 2) as u8;
+                    row[idx] = row[idx].wrapping_add(average);
+                }
+            }
+            4 => {
+                for idx in 0..row_bytes {
+                    let (left, up_left) = if idx >= step {
+                        (row[idx - step], previous[idx - step])
+                    } else {
+                        (0, 0)
+                    };
+                    row[idx] = row[idx].wrapping_add(paeth(left, previous[idx], up_left));
+                }
+            }
+            _ => return Err(ImageError::Invalid("unknown PNG filter type")),
+        }
+        previous.copy_from_slice(row);
+    }
+    Ok(())
+}
+
+/// Filter row choosing the type with the minimal sum of absolute differences
+fn filter_row(row: &[u8], previous: &[u8], step: usize, output: &mut Vec<u8>) {
+    let left = |idx: usize| if idx >= step { row[idx - step] } else { 0 };
+    let up_left = |idx: usize| if idx >= step { previous[idx - step] } else { 0 };
+    let predict = |filter: u8, idx: usize| match filter {
+        0 => 0,
+        1 => left(idx),
+        2 => previous[idx],
+        3 => ((left(idx) as u16 + previous[idx] as u16) / 2) as u8,
+        _ => paeth(left(idx), previous[idx], up_left(idx)),
+    };
+    let cost = |filter: u8| -> u64 {
+        (0..row.len())
+            .map(|idx| (row[idx].wrapping_sub(predict(filter, idx)) as i8).unsigned_abs() as u64)
+            .sum()
+    };
+
+    let filter = (0..5).min_by_key(|filter| cost(*filter)).unwrap_or(0);
+    output.push(filter);
+    output.extend((0..row.len()).map(|idx| row[idx].wrapping_sub(predict(filter, idx))));
+}
+
+fn write_chunk(output: &mut Vec<u8>, kind: &[u8; 4], content: &[u8]) {
+    output.extend_from_slice(&(content.len() as u32).to_be_bytes());
+    output.extend_from_slice(kind);
+    output.extend_from_slice(content);
+    output.extend_from_slice(&crc32(&[kind, content]).to_be_bytes());
+}
+
+/// Encode PNG file without interlacing.
+///
+/// `ARgb32` images are written as 8 bit RGB with alpha, `A8` and `A1` as 8 bit gray with alpha,
+/// `Rgb30` as 16 bit RGB and other formats as 8 bit RGB.
+pub fn encode(image: &ImageData) -> Result<Vec<u8>, ImageError> {
+    check_format(image)?;
+    if image.width == 0 || image.height == 0 {
+        return Err(ImageError::Unsupported("PNG without pixels"));
+    }
+    if image.width > i32::MAX as u32 || image.height > i32::MAX as u32 {
+        return Err(ImageError::TooLarge);
+    }
+    let (color_type, depth) = match image.format {
+        PixelFormat::ARgb32 => (RGB_ALPHA, 8),
+        PixelFormat::A8 | PixelFormat::A1 => (GRAY_ALPHA, 8),
+        PixelFormat::Rgb30 => (RGB, 16),
+        _ => (RGB, 8),
+    };
+    let header = Header {
+        width: image.width,
+        height: image.height,
+        depth,
+        color_type,
+        interlaced: false,
+    };
+
+    let row_bytes = header.row_bytes(image.width);
+    let mut filtered = Vec::with_capacity((row_bytes + 1) * image.height as usize);
+    let mut previous = vec![0u8; row_bytes];
+    let mut row = Vec::with_capacity(row_bytes);
+    for y in 0..image.height {
+        row.clear();
+        for x in 0..image.width {
+            let color = image
+                .try_get_pixel(x, y)
+                .unwrap_or_else(|| Color::new(0.0, 0.0, 0.0, 0.0));
+            let channels = match color_type {
+                RGB_ALPHA => vec![color.red, color.green, color.blue, color.alpha],
+                GRAY_ALPHA => vec![0.0, color.alpha],
+                _ => vec![color.red, color.green, color.blue],
+            };
+            for channel in channels {
+                let channel = channel.clamp(0.0, 1.0);
+                if depth == 16 {
+                    row.extend_from_slice(&((channel * 65535.0).round() as u16).to_be_bytes());
+                } else {
+                    row.push((channel * 255.0).round() as u8);
+                }
+            }
+        }
+        filter_row(&row, &previous, header.filter_step(), &mut filtered);
+        std::mem::swap(&mut previous, &mut row);
+    }
+
+    let mut output = SIGNATURE.to_vec();
+    let mut content = Vec::with_capacity(13);
+    content.extend_from_slice(&image.width.to_be_bytes());
+    content.extend_from_slice(&image.height.to_be_bytes());
+    content.extend_from_slice(&[depth, color_type, 0, 0, 0]);
+    write_chunk(&mut output, b"IHDR", &content);
+    write_chunk(&mut output, b"IDAT", &zlib::compress(&filtered));
+    write_chunk(&mut output, b"IEND", &[]);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::image::codecs::test::{assert_same_pixels, gradient};
+
+    /// Build PNG from raw filtered scanlines
+    fn png(
+        header: [u8; 5],
+        width: u32,
+        height: u32,
+        chunks: &[(&[u8; 4], &[u8])],
+        raw: &[u8],
+    ) -> Vec<u8> {
+        let mut output = SIGNATURE.to_vec();
+        let mut content = Vec::new();
+        content.extend_from_slice(&width.to_be_bytes());
+        content.extend_from_slice(&height.to_be_bytes());
+        content.extend_from_slice(&header);
+        write_chunk(&mut output, b"IHDR", &content);
+        for (kind, content) in chunks {
+            write_chunk(&mut output, kind, content);
+        }
+        write_chunk(&mut output, b"IDAT", &zlib::compress(raw));
+        write_chunk(&mut output, b"IEND", &[]);
+        output
+    }
+
+    fn bytes(image: &ImageData) -> Vec<[u8; 4]> {
+        image
+            .pixels()
+            .map(|color| color.cast::<u8>().to_array())
+            .collect()
+    }
+
+    #[test]
+    fn color_types() {
+        let image = decode(&png([2, GRAY, 0, 0, 0], 3, 1, &[], &[0, 0b00_01_11_00])).unwrap();
+        assert_eq!(image.format, PixelFormat::Rgb24);
+        let grays: Vec<u8> = bytes(&image).iter().map(|pixel| pixel[0]).collect();
+        assert_eq!(grays, vec![0, 85, 255]);
+
+        let chunks: [(&[u8; 4], &[u8]); 2] =
+            [(b"PLTE", &[255, 0, 0, 0, 0, 255]), (b"tRNS", &[255, 0])];
+        let image = decode(&png(
+            [1, PALETTE, 0, 0, 0],
+            4,
+            1,
+            &chunks,
+            &[0, 0b0100_0000],
+        ))
+        .unwrap();
+        assert_eq!(image.format, PixelFormat::ARgb32);
+        let (red, clear) = ([255, 0, 0, 255], [0, 0, 0, 0]);
+        assert_eq!(bytes(&image), vec![red, clear, red, red]);
+
+        let chunks: [(&[u8; 4], &[u8]); 1] = [(b"tRNS", &[0, 0, 0, 0, 0, 0])];
+        let raw = [0, 0xff, 0xff, 0x80, 0x00, 0, 0, 0, 0, 0, 0, 0, 0];
+        let image = decode(&png([16, RGB, 0, 0, 0], 2, 1, &chunks, &raw)).unwrap();
+        assert_eq!(bytes(&image), vec![[255, 128, 0, 255], clear]);
+
+        let image = decode(&png([16, GRAY, 0, 0, 0], 1, 1, &[], &[0, 0xff, 0xff])).unwrap();
+        assert_eq!(image.format, PixelFormat::Rgb30);
+        assert_eq!(bytes(&image), vec![[255, 255, 255, 255]]);
+
+        let image = decode(&png([8, GRAY_ALPHA, 0, 0, 0], 1, 1, &[], &[0, 255, 51])).unwrap();
+        assert_eq!(bytes(&image), vec![[255, 255, 255, 51]]);
+    }
+
+    #[test]
+    fn filters() {
+        // sub and up
+        let raw = [
+            1, 10, 20, 30, 5, 5, 5, 5, 5, 5, //
+            2, 1, 2, 3, 1, 2, 3, 1, 2, 3,
+        ];
+        let image = decode(&png([8, RGB, 0, 0, 0], 3, 2, &[], &raw)).unwrap();
+        assert_eq!(image.get_pixel(2, 0), Color::rgb(20, 30, 40));
+        assert_eq!(image.get_pixel(2, 1), Color::rgb(21, 32, 43));
+
+        // average and paeth
+        let raw = [
+            3, 10, 20, 30, 25, 30, 35, //
+            4, 2, 2, 2, 3, 4, 5,
+        ];
+        let image = decode(&png([8, RGB, 0, 0, 0], 2, 2, &[], &raw)).unwrap();
+        assert_eq!(image.get_pixel(1, 0), Color::rgb(30, 40, 50));
+        assert_eq!(image.get_pixel(0, 1), Color::rgb(12, 22, 32));
+        assert_eq!(image.get_pixel(1, 1), Color::rgb(33, 44, 55));
+    }
+
+    #[test]
+    fn interlacing() {
+        let color = |x: u32, y: u32| [(x * 40) as u8, (y * 40) as u8, 7];
+        let mut raw = Vec::new();
+        for (x0, y0, dx, dy) in ADAM7.iter() {
+            for y in (*y0..5).step_by(*dy as usize) {
+                if *x0 >= 5 {
+                    break;
+                }
+                raw.push(0);
+                for x in (*x0..5).step_by(*dx as usize) {
+                    raw.extend_from_slice(&color(x, y));
+                }
+            }
+        }
+        let image = decode(&png([8, RGB, 0, 0, 1], 5, 5, &[], &raw)).unwrap();
+        for y in 0..5 {
+            for x in 0..5 {
+                let [red, green, blue] = color(x, y);
+                assert_eq!(image.get_pixel(x, y), Color::rgb(red, green, blue));
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for format in [
+            PixelFormat::ARgb32,
+            PixelFormat::Rgb24,
+            PixelFormat::Rgb30,
+            PixelFormat::A8,
+        ]
+        .iter()
+        {
+            let image = gradient(*format, 9, 7);
+            let data = encode(&image).unwrap();
+            let decoded = decode(&data).unwrap();
+            assert_same_pixels(&image, &decoded);
+            if *format == PixelFormat::Rgb30 {
+                assert_eq!(&decoded.data[..], &image.data[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let valid = png([8, RGB, 0, 0, 0], 1, 1, &[], &[0, 1, 2, 3]);
+        assert!(decode(&valid).is_ok());
+
+        let mut corrupted = valid.clone();
+        corrupted[20] ^= 1;
+        assert_eq!(
+            decode(&corrupted).err(),
+            Some(ImageError::Invalid("PNG chunk CRC mismatch"))
+        );
+        assert_eq!(
+            decode(&png([8, PALETTE, 0, 0, 0], 1, 1, &[], &[0, 0])).err(),
+            Some(ImageError::Invalid("PNG palette image without PLTE chunk"))
+        );
+        assert_eq!(
+            decode(&png(
+                [8, RGB, 0, 0, 0],
+                1,
+                1,
+                &[(b"ABCD", &[])],
+                &[0, 1, 2, 3]
+            ))
+            .err(),
+            Some(ImageError::Unsupported("PNG critical chunk"))
+        );
+        assert!(decode(&png(
+            [8, RGB, 0, 0, 0],
+            1,
+            1,
+            &[(b"abCD", &[1])],
+            &[0, 1, 2, 3]
+        ))
+        .is_ok());
+        assert_eq!(
+            decode(&png([8, RGB, 0, 0, 0], 1, 1, &[], &[5, 1, 2, 3])).err(),
+            Some(ImageError::Invalid("unknown PNG filter type"))
+        );
+        assert!(decode(&png([8, RGB, 0, 0, 0], 1, 1, &[], &[0, 1, 2, 3, 4])).is_err());
+        assert!(decode(&png([8, RGB, 0, 0, 0], 1, 1, &[], &[0, 1, 2])).is_err());
+        assert!(decode(&png([3, RGB, 0, 0, 0], 1, 1, &[], &[0, 1, 2])).is_err());
+        for length in 0..valid.len() {
+            assert!(decode(&valid[..length]).is_err());
+        }
+    }
+}
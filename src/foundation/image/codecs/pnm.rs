@@ -0,0 +1,359 @@
+//! Netpbm formats: PBM (`P1`, `P4`), PGM (`P2`, `P5`), PPM (`P3`, `P6`) and PAM (`P7`).
+//!
+//! Bitmaps decode to `A1` with black pixels opaque, gray and color images decode to `Rgb24`,
+//! or `Rgb30` when samples have more than 8 bits, images with alpha decode to `ARgb32`.
+
+use super::{check_format, check_length, create_image, to_byte, ImageError};
+use crate::foundation::{
+    colorspace::Color,
+    image::{ImageData, PixelFormat},
+};
+
+/// Channels of samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tuple {
+    BlackAndWhite,
+    Grayscale,
+    Rgb,
+    BlackAndWhiteAlpha,
+    GrayscaleAlpha,
+    RgbAlpha,
+}
+
+impl Tuple {
+    fn from_name(name: &str) -> Result<Self, ImageError> {
+        match name {
+            "BLACKANDWHITE" => Ok(Self::BlackAndWhite),
+            "GRAYSCALE" => Ok(Self::Grayscale),
+            "RGB" => Ok(Self::Rgb),
+            "BLACKANDWHITE_ALPHA" => Ok(Self::BlackAndWhiteAlpha),
+            "GRAYSCALE_ALPHA" => Ok(Self::GrayscaleAlpha),
+            "RGB_ALPHA" => Ok(Self::RgbAlpha),
+            _ => Err(ImageError::Unsupported("PAM tuple type")),
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match self {
+            Self::BlackAndWhite | Self::Grayscale => 1,
+            Self::BlackAndWhiteAlpha | Self::GrayscaleAlpha => 2,
+            Self::Rgb => 3,
+            Self::RgbAlpha => 4,
+        }
+    }
+}
+
+/// Header tokenizer skipping whitespace and comments
+struct Tokens<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.data.get(self.position) {
+            match byte {
+                b'#' => {
+                    while !matches!(self.data.get(self.position), None | Some(b'\n' | b'\r')) {
+                        self.position += 1;
+                    }
+                }
+                byte if byte.is_ascii_whitespace() => self.position += 1,
+                _ => break,
+            }
+        }
+    }
+
+    fn word(&mut self) -> Result<&'a str, ImageError> {
+        self.skip_whitespace();
+        let start = self.position;
+        while matches!(self.data.get(self.position), Some(byte) if !byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(ImageError::Truncated);
+        }
+        std::str::from_utf8(&self.data[start..self.position])
+            .map_err(|_| ImageError::Invalid("PNM header is not ASCII"))
+    }
+
+    fn number(&mut self) -> Result<u32, ImageError> {
+        self.word()?
+            .parse()
+            .map_err(|_| ImageError::Invalid("PNM header value is not a number"))
+    }
+
+    /// Retrieve single `0` or `1` digit of plain PBM, digits need not be separated
+    fn bit(&mut self) -> Result<bool, ImageError> {
+        self.skip_whitespace();
+        let bit = match self.data.get(self.position) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            Some(_) => return Err(ImageError::Invalid("PBM pixel is not 0 or 1")),
+            None => return Err(ImageError::Truncated),
+        };
+        self.position += 1;
+        Ok(bit)
+    }
+}
+
+/// Decode image of any Netpbm format
+pub fn decode(data: &[u8]) -> Result<ImageData, ImageError> {
+    let kind = match data {
+        [b'P', kind @ b'1'..=b'7', ..] => *kind - b'0',
+        _ => return Err(ImageError::UnknownFormat),
+    };
+    let mut tokens = Tokens { data, position: 2 };
+
+    let (width, height, maxval, tuple) = if kind == 7 {
+        let (mut width, mut height, mut depth, mut maxval, mut tuple) =
+            (None, None, None, None, None);
+        loop {
+            match tokens.word()? {
+                "ENDHDR" => break,
+                "WIDTH" => width = Some(tokens.number()?),
+                "HEIGHT" => height = Some(tokens.number()?),
+                "DEPTH" => depth = Some(tokens.number()? as usize),
+                "MAXVAL" => maxval = Some(tokens.number()?),
+                "TUPLTYPE" => tuple = Some(Tuple::from_name(tokens.word()?)?),
+                _ => return Err(ImageError::Invalid("unknown PAM header field")),
+            }
+        }
+        let (width, height, depth, maxval) = match (width, height, depth, maxval) {
+            (Some(width), Some(height), Some(depth), Some(maxval)) => {
+                (width, height, depth, maxval)
+            }
+            _ => return Err(ImageError::Invalid("missing PAM header field")),
+        };
+        let tuple = match (tuple, depth) {
+            (Some(tuple), _) => tuple,
+            (None, 1) => Tuple::Grayscale,
+            (None, 2) => Tuple::GrayscaleAlpha,
+            (None, 3) => Tuple::Rgb,
+            (None, 4) => Tuple::RgbAlpha,
+            _ => return Err(ImageError::Unsupported("PAM depth without tuple type")),
+        };
+        if tuple.depth() != depth {
+            return Err(ImageError::Invalid("PAM depth does not match tuple type"));
+        }
+        (width, height, maxval, tuple)
+    } else {
+        let width = tokens.number()?;
+        let height = tokens.number()?;
+        let (maxval, tuple) = match kind {
+            1 | 4 => (1, Tuple::BlackAndWhite),
+            2 | 5 => (tokens.number()?, Tuple::Grayscale),
+            _ => (tokens.number()?, Tuple::Rgb),
+        };
+        (width, height, maxval, tuple)
+    };
+    if maxval == 0 || maxval > u16::MAX as u32 {
+        return Err(ImageError::Invalid("PNM maximal value is not in 1..=65535"));
+    }
+
+    let format = match tuple {
+        Tuple::BlackAndWhite => PixelFormat::A1,
+        Tuple::Grayscale | Tuple::Rgb if maxval > 255 => PixelFormat::Rgb30,
+        Tuple::Grayscale | Tuple::Rgb => PixelFormat::Rgb24,
+        _ => PixelFormat::ARgb32,
+    };
+    // exactly one whitespace byte separates header from raster
+    let mut position = tokens.position + 1;
+    let sample_size = if maxval > 255 { 2 } else { 1 };
+    // plain formats take at least one character per sample
+    let pixels = width as u64 * height as u64;
+    let required = match kind {
+        1 => pixels,
+        2 | 3 => pixels * tuple.depth() as u64,
+        4 => (width as u64).div_ceil(8) * height as u64,
+        _ => pixels * (tuple.depth() * sample_size) as u64,
+    };
+    check_length(data, position, required)?;
+    let mut image = create_image(format, width, height)?;
+    let scale = maxval as f32;
+    let mut samples = [0.0f32; 4];
+    for y in 0..height {
+        // packed bitmap rows start at byte boundary
+        let row_start = position;
+        for x in 0..width {
+            match kind {
+                1 => samples[0] = if tokens.bit()? { 0.0 } else { 1.0 },
+                4 => {
+                    let byte = *data
+                        .get(row_start + x as usize / 8)
+                        .ok_or(ImageError::Truncated)?;
+                    samples[0] = if byte & (0x80 >> (x % 8)) != 0 {
+                        0.0
+                    } else {
+                        1.0
+                    };
+                }
+                2 | 3 => {
+                    for sample in samples.iter_mut().take(tuple.depth()) {
+                        *sample = tokens.number()?.min(maxval) as f32 / scale;
+                    }
+                }
+                _ => {
+                    let size = tuple.depth() * sample_size;
+                    let bytes = data
+                        .get(position..position + size)
+                        .ok_or(ImageError::Truncated)?;
+                    for (sample, bytes) in samples.iter_mut().zip(bytes.chunks(sample_size)) {
+                        let value = bytes.iter().fold(0u32, |acc, byte| acc << 8 | *byte as u32);
+                        *sample = value.min(maxval) as f32 / scale;
+                    }
+                    position += size;
+                }
+            }
+
+            let color = match tuple {
+                Tuple::BlackAndWhite => Color::new(0.0, 0.0, 0.0, 1.0 - samples[0]),
+                Tuple::BlackAndWhiteAlpha | Tuple::Grayscale | Tuple::GrayscaleAlpha => {
+                    let alpha = if tuple.depth() == 2 { samples[1] } else { 1.0 };
+                    Color::new(samples[0], samples[0], samples[0], alpha)
+                }
+                Tuple::Rgb => Color::new(samples[0], samples[1], samples[2], 1.0),
+                Tuple::RgbAlpha => Color::new(samples[0], samples[1], samples[2], samples[3]),
+            };
+            image.set_pixel(x, y, color);
+        }
+        if kind == 4 {
+            position = row_start + (width as usize).div_ceil(8);
+        }
+    }
+
+    Ok(image.freeze())
+}
+
+/// Encode image as binary Netpbm.
+///
+/// `A1` images are written as PBM with opaque pixels black, `ARgb32` and `A8` as PAM
+/// with alpha, `Rgb30` as PPM with 16 bit samples and other formats as PPM.
+pub fn encode(image: &ImageData) -> Result<Vec<u8>, ImageError> {
+    check_format(image)?;
+    let (width, height) = (image.width, image.height);
+    let mut data = Vec::new();
+    let pixels = (0..height).flat_map(|y| (0..width).map(move |x| (x, y)));
+
+    match image.format {
+        PixelFormat::A1 => {
+            data.extend_from_slice(format!("P4\n{} {}\n", width, height).as_bytes());
+            for y in 0..height {
+                let mut row = vec![0u8; (width as usize).div_ceil(8)];
+                for x in 0..width {
+                    if image.try_get_pixel(x, y).map_or(0.0, |color| color.alpha) >= 0.5 {
+                        row[x as usize / 8] |= 0x80 >> (x % 8);
+                    }
+                }
+                data.extend_from_slice(&row);
+            }
+        }
+        PixelFormat::ARgb32 | PixelFormat::A8 => {
+            let header = format!(
+                "P7\nWIDTH {}\nHEIGHT {}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n",
+                width, height
+            );
+            data.extend_from_slice(header.as_bytes());
+            for (x, y) in pixels {
+                data.extend_from_slice(&pixel(image, x, y).cast::<u8>().to_array());
+            }
+        }
+        PixelFormat::Rgb30 => {
+            data.extend_from_slice(format!("P6\n{} {}\n1023\n", width, height).as_bytes());
+            for (x, y) in pixels {
+                let color = pixel(image, x, y);
+                for value in [color.red, color.green, color.blue].iter() {
+                    let value = (value.clamp(0.0, 1.0) * 1023.0).round() as u16;
+                    data.extend_from_slice(&value.to_be_bytes());
+                }
+            }
+        }
+        _ => {
+            data.extend_from_slice(format!("P6\n{} {}\n255\n", width, height).as_bytes());
+            for (x, y) in pixels {
+                let color = pixel(image, x, y);
+                data.extend_from_slice(&[
+                    to_byte(color.red),
+                    to_byte(color.green),
+                    to_byte(color.blue),
+                ]);
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+/// Retrieve pixel, transparent when missing in data
+fn pixel(image: &ImageData, x: u32, y: u32) -> Color {
+    image
+        .try_get_pixel(x, y)
+        .unwrap_or_else(|| Color::new(0.0, 0.0, 0.0, 0.0))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bytes(image: &ImageData) -> Vec<[u8; 4]> {
+        image
+            .pixels()
+            .map(|color| color.cast::<u8>().to_array())
+            .collect()
+    }
+
+    #[test]
+    fn plain_formats() {
+        let image = decode(b"P1\n# comment\n3 2\n010\n1 1 0").unwrap();
+        assert_eq!(image.format, PixelFormat::A1);
+        let alphas: Vec<u8> = bytes(&image).iter().map(|pixel| pixel[3]).collect();
+        assert_eq!(alphas, vec![0, 255, 0, 255, 255, 0]);
+
+        let image = decode(b"P2 2 1 4 0 2").unwrap();
+        assert_eq!(bytes(&image), vec![[0, 0, 0, 255], [128, 128, 128, 255]]);
+
+        let image = decode(b"P3 1 1 255 255 128 0").unwrap();
+        assert_eq!(bytes(&image), vec![[255, 128, 0, 255]]);
+    }
+
+    #[test]
+    fn binary_formats() {
+        let image = decode(b"P4 10 1\n\xc0\x40").unwrap();
+        let alphas: Vec<u8> = bytes(&image).iter().map(|pixel| pixel[3]).collect();
+        assert_eq!(alphas, vec![255, 255, 0, 0, 0, 0, 0, 0, 0, 255]);
+
+        let image = decode(b"P6 1 1 65535\n\xff\xff\x80\x00\x00\x00").unwrap();
+        assert_eq!(image.format, PixelFormat::Rgb30);
+        assert_eq!(bytes(&image), vec![[255, 128, 0, 255]]);
+
+        let pam = b"P7\nWIDTH 1\nHEIGHT 1\nDEPTH 2\nMAXVAL 255\nTUPLTYPE GRAYSCALE_ALPHA\nENDHDR\n\x40\xff";
+        let image = decode(pam).unwrap();
+        assert_eq!(image.format, PixelFormat::ARgb32);
+        assert_eq!(bytes(&image), vec![[64, 64, 64, 255]]);
+
+        let image = ImageData::new(PixelFormat::Rgb30, 1, 1, vec![0xff; 4].into());
+        let decoded = decode(&encode(&image).unwrap()).unwrap();
+        assert_eq!(decoded.format, PixelFormat::Rgb30);
+        assert_eq!(decoded.get_pixel(0, 0), image.get_pixel(0, 0));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            decode(b"P6 2 2 255\n\0\0\0").err(),
+            Some(ImageError::Truncated)
+        );
+        assert_eq!(
+            decode(b"P7\nWIDTH 1\nHEIGHT 1\nDEPTH 3\nMAXVAL 255\nTUPLTYPE CMYK\nENDHDR\n").err(),
+            Some(ImageError::Unsupported("PAM tuple type"))
+        );
+        assert!(matches!(
+            decode(b"P5 1 1 0\n\0").err(),
+            Some(ImageError::Invalid(_))
+        ));
+        assert!(matches!(
+            decode(b"P1 1 1 2").err(),
+            Some(ImageError::Invalid(_))
+        ));
+    }
+}
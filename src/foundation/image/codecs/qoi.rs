@@ -0,0 +1,227 @@
+//! Quite OK Image format, see [specification](https://qoiformat.org/qoi-specification.pdf).
+//!
+//! Images with 4 channels decode to `ARgb32`, with 3 channels to `Rgb24`.
+
+use super::{check_format, check_length, create_image, to_byte, ImageError, Reader};
+use crate::foundation::{
+    colorspace::Color,
+    image::{ImageData, PixelFormat},
+};
+
+const OP_INDEX: u8 = 0x00;
+const OP_DIFF: u8 = 0x40;
+const OP_LUMA: u8 = 0x80;
+const OP_RUN: u8 = 0xc0;
+const OP_RGB: u8 = 0xfe;
+const OP_RGBA: u8 = 0xff;
+const MASK: u8 = 0xc0;
+
+const END: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 1];
+
+#[inline]
+fn hash([red, green, blue, alpha]: [u8; 4]) -> usize {
+    (red as usize * 3 + green as usize * 5 + blue as usize * 7 + alpha as usize * 11) % 64
+}
+
+/// Decode QOI file
+pub fn decode(data: &[u8]) -> Result<ImageData, ImageError> {
+    let mut reader = Reader::new(data);
+    if reader.bytes(4)? != b"qoif" {
+        return Err(ImageError::UnknownFormat);
+    }
+    let width = reader.u32_be()?;
+    let height = reader.u32_be()?;
+    let format = match reader.u8()? {
+        3 => PixelFormat::Rgb24,
+        4 => PixelFormat::ARgb32,
+        _ => return Err(ImageError::Invalid("QOI channels are not 3 or 4")),
+    };
+    if reader.u8()? > 1 {
+        return Err(ImageError::Invalid("unknown QOI color space"));
+    }
+    // every chunk takes at least one byte and runs cover at most 62 pixels
+    let pixels = width as u64 * height as u64;
+    check_length(data, reader.position, pixels.div_ceil(62))?;
+    let mut image = create_image(format, width, height)?;
+
+    let mut index = [[0u8; 4]; 64];
+    let mut pixel = [0, 0, 0, 255];
+    let mut run = 0;
+    for y in 0..height {
+        for x in 0..width {
+            if run > 0 {
+                run -= 1;
+            } else {
+                let op = reader.u8()?;
+                match op {
+                    OP_RGB => pixel[..3].copy_from_slice(reader.bytes(3)?),
+                    OP_RGBA => pixel.copy_from_slice(reader.bytes(4)?),
+                    _ => match op & MASK {
+                        OP_INDEX => pixel = index[op as usize],
+                        OP_DIFF => {
+                            pixel[0] = pixel[0].wrapping_add((op >> 4) & 3).wrapping_sub(2);
+                            pixel[1] = pixel[1].wrapping_add((op >> 2) & 3).wrapping_sub(2);
+                            pixel[2] = pixel[2].wrapping_add(op & 3).wrapping_sub(2);
+                        }
+                        OP_LUMA => {
+                            let next = reader.u8()?;
+                            let green = (op & 0x3f).wrapping_sub(32);
+                            pixel[0] = pixel[0]
+                                .wrapping_add(green.wrapping_sub(8).wrapping_add(next >> 4));
+                            pixel[1] = pixel[1].wrapping_add(green);
+                            pixel[2] = pixel[2]
+                                .wrapping_add(green.wrapping_sub(8).wrapping_add(next & 0x0f));
+                        }
+                        _ => run = op & 0x3f,
+                    },
+                }
+                index[hash(pixel)] = pixel;
+            }
+            let [red, green, blue, alpha] = pixel;
+            image.set_pixel(x, y, Color::rgba(red, green, blue, alpha));
+        }
+    }
+
+    Ok(image.freeze())
+}
+
+/// Encode QOI file in sRGB color space, images with alpha get 4 channels
+pub fn encode(image: &ImageData) -> Result<Vec<u8>, ImageError> {
+    check_format(image)?;
+    let channels = if image.format.has_alpha() { 4 } else { 3 };
+    let mut data = b"qoif".to_vec();
+    data.extend_from_slice(&image.width.to_be_bytes());
+    data.extend_from_slice(&image.height.to_be_bytes());
+    data.extend_from_slice(&[channels, 0]);
+
+    let mut index = [[0u8; 4]; 64];
+    let mut previous = [0, 0, 0, 255];
+    let mut run = 0u8;
+    let count = image.width as usize * image.height as usize;
+    let pixels = (0..image.height).flat_map(|y| (0..image.width).map(move |x| (x, y)));
+    for (idx, (x, y)) in pixels.enumerate() {
+        let color = image
+            .try_get_pixel(x, y)
+            .unwrap_or_else(|| Color::new(0.0, 0.0, 0.0, 0.0));
+        let pixel = [
+            to_byte(color.red),
+            to_byte(color.green),
+            to_byte(color.blue),
+            to_byte(color.alpha),
+        ];
+
+        if pixel == previous {
+            run += 1;
+            if run == 62 || idx + 1 == count {
+                data.push(OP_RUN | (run - 1));
+                run = 0;
+            }
+            continue;
+        }
+        if run > 0 {
+            data.push(OP_RUN | (run - 1));
+            run = 0;
+        }
+
+        let position = hash(pixel);
+        if index[position] == pixel {
+            data.push(OP_INDEX | position as u8);
+        } else if pixel[3] == previous[3] {
+            let red = pixel[0].wrapping_sub(previous[0]) as i8;
+            let green = pixel[1].wrapping_sub(previous[1]) as i8;
+            let blue = pixel[2].wrapping_sub(previous[2]) as i8;
+            let (red_green, blue_green) = (red.wrapping_sub(green), blue.wrapping_sub(green));
+
+            if (-2..2).contains(&red) && (-2..2).contains(&green) && (-2..2).contains(&blue) {
+                data.push(
+                    OP_DIFF | ((red + 2) as u8) << 4 | ((green + 2) as u8) << 2 | (blue + 2) as u8,
+                );
+            } else if (-32..32).contains(&green)
+                && (-8..8).contains(&red_green)
+                && (-8..8).contains(&blue_green)
+            {
+                data.push(OP_LUMA | (green + 32) as u8);
+                data.push(((red_green + 8) as u8) << 4 | (blue_green + 8) as u8);
+            } else {
+                data.push(OP_RGB);
+                data.extend_from_slice(&pixel[..3]);
+            }
+        } else {
+            data.push(OP_RGBA);
+            data.extend_from_slice(&pixel);
+        }
+        index[position] = pixel;
+        previous = pixel;
+    }
+
+    data.extend_from_slice(&END);
+    Ok(data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::image::codecs::test::{assert_same_pixels, gradient};
+
+    #[test]
+    fn operations() {
+        // rgb, diff, luma, run of two, index of the first pixel, rgba
+        let mut data = b"qoif\0\0\0\x07\0\0\0\x01\x04\x00".to_vec();
+        data.extend_from_slice(&[OP_RGB, 100, 100, 100]);
+        data.push(OP_DIFF | 3 << 4 | 2 << 2 | 1);
+        data.extend_from_slice(&[OP_LUMA | 40, 0x88]);
+        data.push(OP_RUN | 1);
+        data.push(OP_INDEX | hash([100, 100, 100, 255]) as u8);
+        data.extend_from_slice(&[OP_RGBA, 1, 2, 3, 4]);
+        data.extend_from_slice(&END);
+
+        let image = decode(&data).unwrap();
+        let pixels: Vec<[u8; 4]> = image
+            .pixels()
+            .map(|color| color.cast::<u8>().to_array())
+            .collect();
+        assert_eq!(
+            &pixels[..6],
+            &[
+                [100, 100, 100, 255],
+                [101, 100, 99, 255],
+                [109, 108, 107, 255],
+                [109, 108, 107, 255],
+                [109, 108, 107, 255],
+                [100, 100, 100, 255],
+            ]
+        );
+        assert_eq!(pixels[6][3], 4);
+    }
+
+    #[test]
+    fn round_trip() {
+        let image = gradient(PixelFormat::Rgb24, 70, 3);
+        let mut image = image.to_mut();
+        image.fill_rect(
+            crate::foundation::Rect::new((0, 1).into(), (70, 1).into()),
+            Color::rgb(9, 9, 9),
+        );
+        let image = image.freeze();
+        let data = encode(&image).unwrap();
+        assert_eq!(data[12], 3);
+        assert_eq!(&data[data.len() - 8..], &END);
+        assert_same_pixels(&image, &decode(&data).unwrap());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            decode(b"qoif\0\0\0\x01\0\0\0\x01\x03\x00").err(),
+            Some(ImageError::Truncated)
+        );
+        assert!(matches!(
+            decode(b"qoif\0\0\0\x01\0\0\0\x01\x05\x00").err(),
+            Some(ImageError::Invalid(_))
+        ));
+        assert_eq!(
+            decode(b"\0\0\0\0\0\0\0\x01\x05\x00").err(),
+            Some(ImageError::UnknownFormat)
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Truevision TGA with color mapped, true color and gray images, raw or RLE compressed.
+//!
+//! Images with alpha bits decode to `ARgb32`, others to `Rgb24`.
+
+use super::{check_format, check_length, create_image, to_byte, ImageError, Reader};
+use crate::foundation::{
+    colorspace::Color,
+    image::{ImageData, PixelFormat},
+};
+
+const COLOR_MAPPED: u8 = 1;
+const TRUE_COLOR: u8 = 2;
+const GRAY: u8 = 3;
+const RLE: u8 = 8;
+
+/// Image descriptor bit of rows stored from top
+const TOP_TO_BOTTOM: u8 = 0x20;
+/// Image descriptor bit of columns stored from right
+const RIGHT_TO_LEFT: u8 = 0x10;
+
+/// Decode color stored in little endian bytes of color map entry or pixel
+fn decode_color(bytes: &[u8], gray: bool, alpha: bool) -> Color {
+    match (bytes.len(), gray) {
+        (1, _) => Color::rgb(bytes[0], bytes[0], bytes[0]),
+        (2, true) => Color::rgba(
+            bytes[0],
+            bytes[0],
+            bytes[0],
+            if alpha { bytes[1] } else { 255 },
+        ),
+        (2, false) => {
+            let value = u16::from_le_bytes([bytes[0], bytes[1]]);
+            let channel = |shift: u16| ((value >> shift) & 0x1f) as f32 / 31.0;
+            let opaque = !alpha || value & 0x8000 != 0;
+            Color::new(
+                channel(10),
+                channel(5),
+                channel(0),
+                if opaque { 1.0 } else { 0.0 },
+            )
+        }
+        (3, _) => Color::rgb(bytes[2], bytes[1], bytes[0]),
+        _ => Color::rgba(
+            bytes[2],
+            bytes[1],
+            bytes[0],
+            if alpha { bytes[3] } else { 255 },
+        ),
+    }
+}
+
+/// Decode TGA file
+pub fn decode(data: &[u8]) -> Result<ImageData, ImageError> {
+    let mut reader = Reader::new(data);
+    let id_length = reader.u8()? as usize;
+    let map_type = reader.u8()?;
+    let image_type = reader.u8()?;
+    let map_first = reader.u16_le()? as usize;
+    let map_length = reader.u16_le()? as usize;
+    let map_depth = reader.u8()?;
+    reader.bytes(4)?;
+    let width = reader.u16_le()? as u32;
+    let height = reader.u16_le()? as u32;
+    let depth = reader.u8()?;
+    let descriptor = reader.u8()?;
+    reader.bytes(id_length)?;
+
+    let kind = image_type & !RLE;
+    match kind {
+        COLOR_MAPPED | TRUE_COLOR | GRAY if image_type & !0x0b == 0 => {}
+        0 => return Err(ImageError::Invalid("TGA without image data")),
+        32 | 33 => return Err(ImageError::Unsupported("TGA Huffman compression")),
+        _ => return Err(ImageError::Invalid("unknown TGA image type")),
+    }
+    if map_type > 1 {
+        return Err(ImageError::Invalid("unknown TGA color map type"));
+    }
+    if kind == COLOR_MAPPED && map_type == 0 {
+        return Err(ImageError::Invalid(
+            "TGA color mapped image without color map",
+        ));
+    }
+    let alpha = descriptor & 0x0f != 0;
+    let valid_depth = match kind {
+        COLOR_MAPPED => matches!(depth, 8 | 16),
+        TRUE_COLOR => matches!(depth, 15 | 16 | 24 | 32),
+        _ => matches!(depth, 8 | 16),
+    };
+    if !valid_depth {
+        return Err(ImageError::Unsupported("TGA pixel depth"));
+    }
+
+    let map_entry = (map_depth as usize).div_ceil(8);
+    let color_map = if map_type == 1 {
+        if !matches!(map_depth, 15 | 16 | 24 | 32) {
+            return Err(ImageError::Unsupported("TGA color map depth"));
+        }
+        let bytes = reader.bytes(map_length * map_entry)?;
+        bytes
+            .chunks(map_entry)
+            .map(|entry| decode_color(entry, false, alpha))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let format = if alpha {
+        PixelFormat::ARgb32
+    } else {
+        PixelFormat::Rgb24
+    };
+    let size = (depth as usize).div_ceil(8);
+    let count = width as usize * height as usize;
+    // packets take one byte and pixel at least, and cover at most 128 pixels
+    let required = if image_type & RLE != 0 {
+        (count as u64).div_ceil(128) * (size as u64 + 1)
+    } else {
+        count as u64 * size as u64
+    };
+    check_length(data, reader.position, required)?;
+    let mut image = create_image(format, width, height)?;
+
+    let pixels = if image_type & RLE != 0 {
+        let mut pixels = Vec::with_capacity(count * size);
+        while pixels.len() < count * size {
+            let packet = reader.u8()?;
+            let repeat = (packet & 0x7f) as usize + 1;
+            if packet & 0x80 != 0 {
+                let pixel = reader.bytes(size)?;
+                for _ in 0..repeat {
+                    pixels.extend_from_slice(pixel);
+                }
+            } else {
+                pixels.extend_from_slice(reader.bytes(repeat * size)?);
+            }
+        }
+        pixels.truncate(count * size);
+        pixels
+    } else {
+        reader.bytes(count * size)?.to_vec()
+    };
+
+    for (idx, pixel) in pixels.chunks(size).enumerate() {
+        let color = if kind == COLOR_MAPPED {
+            let index = pixel
+                .iter()
+                .rev()
+                .fold(0usize, |acc, byte| acc << 8 | *byte as usize);
+            *index
+                .checked_sub(map_first)
+                .and_then(|index| color_map.get(index))
+                .ok_or(ImageError::Invalid("TGA color index is out of color map"))?
+        } else {
+            decode_color(pixel, kind == GRAY, alpha)
+        };
+
+        let (mut x, mut y) = ((idx % width as usize) as u32, (idx / width as usize) as u32);
+        if descriptor & RIGHT_TO_LEFT != 0 {
+            x = width - 1 - x;
+        }
+        if descriptor & TOP_TO_BOTTOM == 0 {
+            y = height - 1 - y;
+        }
+        image.set_pixel(x, y, color);
+    }
+
+    Ok(image.freeze())
+}
+
+/// Encode true color TGA stored from top, optionally compressed with RLE.
+///
+/// Images with alpha are written with 32 bits, others with 24 bits.
+pub fn encode(image: &ImageData, rle: bool) -> Result<Vec<u8>, ImageError> {
+    check_format(image)?;
+    if image.width > u16::MAX as u32 || image.height > u16::MAX as u32 {
+        return Err(ImageError::TooLarge);
+    }
+    let alpha = image.format.has_alpha();
+    let (depth, alpha_bits) = if alpha { (32, 8) } else { (24, 0) };
+    let image_type = if rle { TRUE_COLOR | RLE } else { TRUE_COLOR };
+
+    let mut data = vec![0, 0, image_type, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    data.extend_from_slice(&(image.width as u16).to_le_bytes());
+    data.extend_from_slice(&(image.height as u16).to_le_bytes());
+    data.extend_from_slice(&[depth, TOP_TO_BOTTOM | alpha_bits]);
+
+    let size = depth as usize / 8;
+    for y in 0..image.height {
+        let row: Vec<[u8; 4]> = (0..image.width)
+            .map(|x| {
+                let color = image
+                    .try_get_pixel(x, y)
+                    .unwrap_or_else(|| Color::new(0.0, 0.0, 0.0, 0.0));
+                [
+                    to_byte(color.blue),
+                    to_byte(color.green),
+                    to_byte(color.red),
+                    to_byte(color.alpha),
+                ]
+            })
+            .collect();
+
+        if !rle {
+            row.iter()
+                .for_each(|pixel| data.extend_from_slice(&pixel[..size]));
+            continue;
+        }
+
+        // packets do not cross rows
+        let mut start = 0;
+        while start < row.len() {
+            let run = row[start..]
+                .iter()
+                .take(128)
+                .take_while(|pixel| **pixel == row[start])
+                .count();
+            if run > 1 {
+                data.push(0x80 | (run - 1) as u8);
+                data.extend_from_slice(&row[start][..size]);
+                start += run;
+                continue;
+            }
+
+            // raw packet until next run of two equal pixels
+            let mut end = start + 1;
+            while end < row.len()
+                && end - start < 128
+                && (end + 1 >= row.len() || row[end] != row[end + 1])
+            {
+                end += 1;
+            }
+            data.push((end - start - 1) as u8);
+            row[start..end]
+                .iter()
+                .for_each(|pixel| data.extend_from_slice(&pixel[..size]));
+            start = end;
+        }
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::image::codecs::test::{assert_same_pixels, gradient};
+
+    fn header(
+        image_type: u8,
+        map: &[u8],
+        width: u16,
+        height: u16,
+        depth: u8,
+        descriptor: u8,
+    ) -> Vec<u8> {
+        let mut data = vec![0, !map.is_empty() as u8, image_type];
+        data.extend_from_slice(if map.is_empty() { &[0; 5] } else { map });
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&width.to_le_bytes());
+        data.extend_from_slice(&height.to_le_bytes());
+        data.extend_from_slice(&[depth, descriptor]);
+        data
+    }
+
+    #[test]
+    fn variants() {
+        // bottom-up gray image
+        let mut data = header(GRAY, &[], 2, 2, 8, 0);
+        data.extend_from_slice(&[0, 64, 128, 255]);
+        let image = decode(&data).unwrap();
+        assert_eq!(image.format, PixelFormat::Rgb24);
+        assert_eq!(image.get_pixel(0, 0), Color::rgb(128, 128, 128));
+        assert_eq!(image.get_pixel(1, 1), Color::rgb(64, 64, 64));
+
+        // RLE compressed color mapped image with packet across rows, map starts at index 1
+        let mut data = header(
+            COLOR_MAPPED | RLE,
+            &[1, 0, 2, 0, 24],
+            3,
+            2,
+            8,
+            TOP_TO_BOTTOM,
+        );
+        data.extend_from_slice(&[0, 0, 255, 255, 0, 0]);
+        data.extend_from_slice(&[0x83, 1, 0x01, 2, 2]);
+        let image = decode(&data).unwrap();
+        let (red, blue) = (Color::rgb(255, 0, 0), Color::rgb(0, 0, 255));
+        let pixels: Vec<Color> = image.pixels().collect();
+        assert_eq!(pixels, vec![red, red, red, red, blue, blue]);
+
+        // 16 bit pixels with alpha bit
+        let mut data = header(TRUE_COLOR, &[], 2, 1, 16, TOP_TO_BOTTOM | 1);
+        data.extend_from_slice(&[0x00, 0xfc, 0x1f, 0x00]);
+        let image = decode(&data).unwrap();
+        assert_eq!(image.format, PixelFormat::ARgb32);
+        assert_eq!(image.get_pixel(0, 0), Color::rgb(255, 0, 0));
+        assert_eq!(image.get_pixel(1, 0).alpha, 0.0);
+    }
+
+    #[test]
+    fn compression() {
+        let mut image = gradient(PixelFormat::ARgb32, 200, 2).to_mut();
+        image.fill_rect(
+            crate::foundation::Rect::new((0, 0).into(), (150, 1).into()),
+            Color::rgb(1, 2, 3),
+        );
+        let image = image.freeze();
+        let raw = encode(&image, false).unwrap();
+        let compressed = encode(&image, true).unwrap();
+        assert_eq!(raw.len(), 18 + 200 * 2 * 4);
+        assert!(compressed.len() < raw.len());
+        assert_same_pixels(&image, &decode(&raw).unwrap());
+        assert_same_pixels(&image, &decode(&compressed).unwrap());
+    }
+
+    #[test]
+    fn errors() {
+        let data = header(TRUE_COLOR, &[], 1, 1, 24, 0);
+        assert_eq!(decode(&data).err(), Some(ImageError::Truncated));
+        let data = header(32, &[], 1, 1, 24, 0);
+        assert_eq!(
+            decode(&data).err(),
+            Some(ImageError::Unsupported("TGA Huffman compression"))
+        );
+        let data = header(TRUE_COLOR, &[], 1, 1, 12, 0);
+        assert_eq!(
+            decode(&data).err(),
+            Some(ImageError::Unsupported("TGA pixel depth"))
+        );
+    }
+}
@@ -0,0 +1,542 @@
+//! Zlib streams with deflate compression, see RFC 1950 and RFC 1951.
+//!
+//! Decompression supports all block types, compression uses LZ77 with fixed Huffman codes
+//! and falls back to stored blocks for incompressible data.
+
+use super::ImageError;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// Order of code length code lengths in dynamic block header
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+const WINDOW: usize = 32768;
+const MAX_MATCH: usize = 258;
+const HASH_BITS: u32 = 15;
+const MAX_CHAIN: usize = 64;
+
+/// Reader of bits starting from the least significant bit of every byte
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    buffer: u64,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn bits(&mut self, count: u32) -> Result<u32, ImageError> {
+        while self.count < count {
+            let byte = *self.data.get(self.position).ok_or(ImageError::Truncated)?;
+            self.buffer |= (byte as u64) << self.count;
+            self.position += 1;
+            self.count += 8;
+        }
+        let value = (self.buffer & ((1u64 << count) - 1)) as u32;
+        self.buffer >>= count;
+        self.count -= count;
+        Ok(value)
+    }
+
+    /// Drop bits up to byte boundary
+    fn align(&mut self) {
+        let skip = self.count % 8;
+        self.buffer >>= skip;
+        self.count -= skip;
+    }
+
+    /// Retrieve byte aligned bytes after buffered bits
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], ImageError> {
+        // aligned buffer holds whole bytes not consumed yet
+        self.position -= (self.count / 8) as usize;
+        self.buffer = 0;
+        self.count = 0;
+        let bytes = self
+            .data
+            .get(self.position..self.position + count)
+            .ok_or(ImageError::Truncated)?;
+        self.position += count;
+        Ok(bytes)
+    }
+}
+
+/// Canonical Huffman code
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, ImageError> {
+        let mut counts = [0u16; 16];
+        for length in lengths {
+            counts[*length as usize] += 1;
+        }
+        counts[0] = 0;
+
+        let mut left = 1i32;
+        for count in counts.iter().skip(1) {
+            left = (left << 1) - *count as i32;
+            if left < 0 {
+                return Err(ImageError::Invalid("over-subscribed deflate Huffman code"));
+            }
+        }
+
+        let mut offsets = [0u16; 16];
+        for length in 1..15 {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, length) in lengths.iter().enumerate() {
+            if *length != 0 {
+                symbols[offsets[*length as usize] as usize] = symbol as u16;
+                offsets[*length as usize] += 1;
+            }
+        }
+        Ok(Self { counts, symbols })
+    }
+
+    fn decode(&self, reader: &mut BitReader<'_>) -> Result<u16, ImageError> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for count in self.counts.iter().skip(1) {
+            code |= reader.bits(1)? as i32;
+            let count = *count as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(ImageError::Invalid("invalid deflate Huffman code"))
+    }
+}
+
+/// Decompress zlib stream, fails when output would exceed limit
+pub(super) fn decompress(data: &[u8], limit: usize) -> Result<Vec<u8>, ImageError> {
+    let (method, flags) = match data {
+        [method, flags, ..] => (*method, *flags),
+        _ => return Err(ImageError::Truncated),
+    };
+    // header read as big endian number is multiple of 31
+    let check = u16::from_be_bytes([method, flags]) % 31;
+    if method & 0x0f != 8 || method >> 4 > 7 || check != 0 {
+        return Err(ImageError::Invalid("malformed zlib header"));
+    }
+    if flags & 0x20 != 0 {
+        return Err(ImageError::Unsupported("zlib preset dictionary"));
+    }
+
+    let mut reader = BitReader {
+        data: &data[2..],
+        position: 0,
+        buffer: 0,
+        count: 0,
+    };
+    let mut output = Vec::new();
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => {
+                reader.align();
+                let header = reader.bytes(4)?;
+                let length = u16::from_le_bytes([header[0], header[1]]);
+                if length != !u16::from_le_bytes([header[2], header[3]]) {
+                    return Err(ImageError::Invalid("deflate stored block length mismatch"));
+                }
+                if output.len() + length as usize > limit {
+                    return Err(ImageError::Invalid("deflate data is longer than expected"));
+                }
+                output.extend_from_slice(reader.bytes(length as usize)?);
+            }
+            1 => {
+                let mut lengths = [0u8; 288];
+                lengths[..144].iter_mut().for_each(|length| *length = 8);
+                lengths[144..256].iter_mut().for_each(|length| *length = 9);
+                lengths[256..280].iter_mut().for_each(|length| *length = 7);
+                lengths[280..].iter_mut().for_each(|length| *length = 8);
+                let literals = Huffman::new(&lengths)?;
+                let distances = Huffman::new(&[5; 30])?;
+                inflate_block(&mut reader, &literals, &distances, &mut output, limit)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut reader)?;
+                inflate_block(&mut reader, &literals, &distances, &mut output, limit)?;
+            }
+            _ => return Err(ImageError::Invalid("unknown deflate block type")),
+        }
+        if last {
+            break;
+        }
+    }
+
+    reader.align();
+    let checksum = reader.bytes(4)?;
+    if u32::from_be_bytes([checksum[0], checksum[1], checksum[2], checksum[3]]) != adler32(&output)
+    {
+        return Err(ImageError::Invalid("zlib checksum mismatch"));
+    }
+    Ok(output)
+}
+
+/// Read Huffman codes of dynamic block
+fn dynamic_codes(reader: &mut BitReader<'_>) -> Result<(Huffman, Huffman), ImageError> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_count = reader.bits(4)? as usize + 4;
+    if literal_count > 286 || distance_count > 30 {
+        return Err(ImageError::Invalid("too many deflate codes"));
+    }
+
+    let mut code_lengths = [0u8; 19];
+    for idx in CODE_LENGTH_ORDER.iter().take(code_count) {
+        code_lengths[*idx] = reader.bits(3)? as u8;
+    }
+    let code = Huffman::new(&code_lengths)?;
+
+    let mut lengths = vec![0u8; literal_count + distance_count];
+    let mut idx = 0;
+    while idx < lengths.len() {
+        let symbol = code.decode(reader)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *idx.checked_sub(1).and_then(|idx| lengths.get(idx)).ok_or(
+                    ImageError::Invalid("deflate length repeat without previous length"),
+                )?;
+                (previous, 3 + reader.bits(2)? as usize)
+            }
+            17 => (0, 3 + reader.bits(3)? as usize),
+            _ => (0, 11 + reader.bits(7)? as usize),
+        };
+        if idx + repeat > lengths.len() {
+            return Err(ImageError::Invalid("deflate code lengths overflow"));
+        }
+        lengths[idx..idx + repeat]
+            .iter_mut()
+            .for_each(|length| *length = value);
+        idx += repeat;
+    }
+    if lengths[256] == 0 {
+        return Err(ImageError::Invalid("deflate block without end code"));
+    }
+
+    Ok((
+        Huffman::new(&lengths[..literal_count])?,
+        Huffman::new(&lengths[literal_count..])?,
+    ))
+}
+
+/// Decode compressed block until end code
+fn inflate_block(
+    reader: &mut BitReader<'_>,
+    literals: &Huffman,
+    distances: &Huffman,
+    output: &mut Vec<u8>,
+    limit: usize,
+) -> Result<(), ImageError> {
+    loop {
+        let symbol = literals.decode(reader)? as usize;
+        match symbol {
+            0..=255 => {
+                if output.len() >= limit {
+                    return Err(ImageError::Invalid("deflate data is longer than expected"));
+                }
+                output.push(symbol as u8);
+            }
+            256 => return Ok(()),
+            _ => {
+                let idx = symbol - 257;
+                if idx >= LENGTH_BASE.len() {
+                    return Err(ImageError::Invalid("invalid deflate length code"));
+                }
+                let length =
+                    LENGTH_BASE[idx] as usize + reader.bits(LENGTH_EXTRA[idx] as u32)? as usize;
+                let idx = distances.decode(reader)? as usize;
+                if idx >= DISTANCE_BASE.len() {
+                    return Err(ImageError::Invalid("invalid deflate distance code"));
+                }
+                let distance =
+                    DISTANCE_BASE[idx] as usize + reader.bits(DISTANCE_EXTRA[idx] as u32)? as usize;
+                if distance > output.len() {
+                    return Err(ImageError::Invalid("deflate distance is too far back"));
+                }
+                if output.len() + length > limit {
+                    return Err(ImageError::Invalid("deflate data is longer than expected"));
+                }
+                // copies may overlap
+                let start = output.len() - distance;
+                for idx in start..start + length {
+                    output.push(output[idx]);
+                }
+            }
+        }
+    }
+}
+
+/// Writer of bits starting from the least significant bit of every byte
+struct BitWriter {
+    output: Vec<u8>,
+    buffer: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    fn bits(&mut self, value: u32, count: u32) {
+        self.buffer |= (value as u64) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.output.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Write Huffman code starting from its most significant bit
+    fn code(&mut self, code: u32, length: u32) {
+        self.bits(code.reverse_bits() >> (32 - length), length);
+    }
+
+    fn flush(&mut self) {
+        if self.count > 0 {
+            self.output.push(self.buffer as u8);
+        }
+        self.buffer = 0;
+        self.count = 0;
+    }
+}
+
+/// Write literal or length symbol with fixed Huffman code
+fn fixed_literal(writer: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => writer.code(0x30 + symbol, 8),
+        144..=255 => writer.code(0x190 + symbol - 144, 9),
+        256..=279 => writer.code(symbol - 256, 7),
+        _ => writer.code(0xc0 + symbol - 280, 8),
+    }
+}
+
+/// Retrieve index of the last base not greater than value
+fn base_index(bases: &[u16], value: usize) -> usize {
+    bases
+        .iter()
+        .rposition(|base| *base as usize <= value)
+        .unwrap_or(0)
+}
+
+/// Hash of three bytes starting at index
+#[inline]
+fn hash(data: &[u8], idx: usize) -> usize {
+    let value = u32::from_le_bytes([data[idx], data[idx + 1], data[idx + 2], 0]);
+    (value.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize
+}
+
+/// Add position to hash chains
+#[inline]
+fn insert(data: &[u8], idx: usize, head: &mut [usize], previous: &mut [usize]) {
+    if idx + 3 <= data.len() {
+        let key = hash(data, idx);
+        previous[idx] = head[key];
+        head[key] = idx;
+    }
+}
+
+/// Compress data into zlib stream
+pub(super) fn compress(data: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter {
+        output: vec![0x78, 0x9c],
+        buffer: 0,
+        count: 0,
+    };
+
+    writer.bits(1, 1);
+    writer.bits(1, 2);
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut previous = vec![usize::MAX; data.len()];
+
+    let mut idx = 0;
+    while idx < data.len() {
+        let (mut best_length, mut best_distance) = (0, 0);
+        if idx + 3 <= data.len() {
+            let mut candidate = head[hash(data, idx)];
+            let max = (data.len() - idx).min(MAX_MATCH);
+            for _ in 0..MAX_CHAIN {
+                if candidate == usize::MAX || idx - candidate > WINDOW {
+                    break;
+                }
+                let length = data[candidate..]
+                    .iter()
+                    .zip(data[idx..idx + max].iter())
+                    .take_while(|(left, right)| left == right)
+                    .count();
+                if length > best_length {
+                    best_length = length;
+                    best_distance = idx - candidate;
+                    if length == max {
+                        break;
+                    }
+                }
+                candidate = previous[candidate];
+            }
+        }
+
+        if best_length >= 3 {
+            let code = base_index(&LENGTH_BASE, best_length);
+            fixed_literal(&mut writer, 257 + code as u32);
+            writer.bits(
+                (best_length - LENGTH_BASE[code] as usize) as u32,
+                LENGTH_EXTRA[code] as u32,
+            );
+            let code = base_index(&DISTANCE_BASE, best_distance);
+            writer.code(code as u32, 5);
+            writer.bits(
+                (best_distance - DISTANCE_BASE[code] as usize) as u32,
+                DISTANCE_EXTRA[code] as u32,
+            );
+            for position in idx..idx + best_length {
+                insert(data, position, &mut head, &mut previous);
+            }
+            idx += best_length;
+        } else {
+            fixed_literal(&mut writer, data[idx] as u32);
+            insert(data, idx, &mut head, &mut previous);
+            idx += 1;
+        }
+    }
+    fixed_literal(&mut writer, 256);
+    writer.flush();
+
+    // stored blocks are smaller for incompressible data
+    let stored_size = 2 + data.len() + 5 * (data.len() / 65535 + 1);
+    let mut output = if writer.output.len() > stored_size {
+        let mut output = vec![0x78, 0x01];
+        let mut blocks = data.chunks(65535).peekable();
+        if blocks.peek().is_none() {
+            output.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+        }
+        while let Some(block) = blocks.next() {
+            let length = block.len() as u16;
+            output.push(blocks.peek().is_none() as u8);
+            output.extend_from_slice(&length.to_le_bytes());
+            output.extend_from_slice(&(!length).to_le_bytes());
+            output.extend_from_slice(block);
+        }
+        output
+    } else {
+        writer.output
+    };
+    output.extend_from_slice(&adler32(data).to_be_bytes());
+    output
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MODULO: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    // sums do not overflow u32 within chunks of 5552 bytes
+    for chunk in data.chunks(5552) {
+        for byte in chunk {
+            a += *byte as u32;
+            b += a;
+        }
+        a %= MODULO;
+        b %= MODULO;
+    }
+    b << 16 | a
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&text[idx..idx + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn decompress_blocks() {
+        // fixed codes
+        let data = [
+            120, 218, 203, 72, 205, 201, 201, 87, 200, 64, 39, 1, 104, 3, 8, 177,
+        ];
+        assert_eq!(
+            decompress(&data, 100).unwrap(),
+            b"hello hello hello hello".to_vec()
+        );
+
+        // dynamic codes
+        let data = hex(concat!(
+            "78da95d1c70140401404d0bb2aa604399423accc67ed4ad5530087b9bfdb33adc26abb7240a1e5",
+            "9851cb0917bd9d960db22b0df38231bf2f54d238e6437b94f6291d503aa47444e998d209a5534a",
+            "67dc0e99f9bff900a689c475"
+        ));
+        let text: String = (0..12)
+            .map(|idx| format!("the quick brown fox {} jumps over the lazy dog\n", idx))
+            .collect();
+        assert_eq!(decompress(&data, 1000).unwrap(), text.as_bytes());
+        assert!(decompress(&data, 100).is_err());
+
+        // stored empty block
+        let data = [120, 1, 1, 0, 0, 255, 255, 0, 0, 0, 1];
+        assert_eq!(decompress(&data, 0).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn compress_round_trip() {
+        let mut seed = 1u32;
+        let noise: Vec<u8> = (0..70000)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                seed as u8
+            })
+            .collect();
+        let repeated: Vec<u8> = (0..50000).map(|idx| (idx % 300 / 7) as u8).collect();
+
+        for data in [
+            &b""[..],
+            b"a",
+            b"aaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            &noise,
+            &repeated,
+        ]
+        .iter()
+        {
+            let compressed = compress(data);
+            assert_eq!(&decompress(&compressed, data.len()).unwrap(), data);
+        }
+        assert!(compress(&repeated).len() < 2000);
+        assert!(compress(&noise).len() < noise.len() + 20);
+    }
+
+    #[test]
+    fn malformed() {
+        let data = compress(b"some data some data some data");
+        assert!(decompress(&data[..data.len() - 1], 100).is_err());
+        let mut corrupted = data.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            decompress(&corrupted, 100),
+            Err(ImageError::Invalid("zlib checksum mismatch"))
+        );
+        assert!(decompress(&[0x78, 0x9d], 100).is_err());
+        assert!(decompress(&[0x78, 0x9c, 0xff], 100).is_err());
+    }
+}
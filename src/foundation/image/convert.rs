@@ -0,0 +1,336 @@
+use super::{ImageData, ImageDataMut, PixelFormat};
+use crate::foundation::colorspace::Color;
+
+/// Dithering applied when converted channels lose bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dither {
+    /// Round every pixel to the nearest value
+    #[default]
+    None,
+    /// Add 4x4 Bayer threshold pattern before rounding, stable for animations
+    Ordered,
+    /// Diffuse rounding error to neighbour pixels with Floyd-Steinberg weights
+    FloydSteinberg,
+}
+
+/// Options of pixel format conversion.
+///
+/// Alpha is handled by these rules:
+///
+/// * Formats without alpha (`Rgb24`, `Rgb16_565`, `Rgb30`) get colors composited over `background`.
+/// * Alpha only formats (`A8`, `A1`) keep alpha of the source and drop colors. Sources without alpha
+///   give opaque pixels, or their luminance when `luminance_to_alpha` is set.
+/// * Alpha only sources give black pixels with their alpha.
+/// * `Invalid` sources give transparent pixels, conversion to `Invalid` gives empty image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvertOptions {
+    /// Dithering used when target format has fewer bits per channel than source format
+    pub dither: Dither,
+    /// Color behind translucent pixels when target format has no alpha
+    pub background: Color,
+    /// Use luminance of colors as alpha when converting to alpha only formats
+    pub luminance_to_alpha: bool,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self {
+            dither: Dither::None,
+            background: Color::rgb(0, 0, 0),
+            luminance_to_alpha: false,
+        }
+    }
+}
+
+/// 4x4 Bayer matrix
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+impl PixelFormat {
+    /// Retrieve bits of red, green, blue and alpha channels, zero for missing channels
+    pub fn channel_bits(&self) -> [u32; 4] {
+        match self {
+            Self::Invalid => [0, 0, 0, 0],
+            Self::ARgb32 => [8, 8, 8, 8],
+            Self::Rgb24 => [8, 8, 8, 0],
+            Self::A8 => [0, 0, 0, 8],
+            Self::A1 => [0, 0, 0, 1],
+            Self::Rgb16_565 => [5, 6, 5, 0],
+            Self::Rgb30 => [10, 10, 10, 0],
+        }
+    }
+}
+
+impl ImageData {
+    /// Convert image to pixel format with default options, without dithering
+    pub fn convert(&self, format: PixelFormat) -> ImageData {
+        self.convert_with(format, &ConvertOptions::default())
+    }
+
+    /// Convert image to pixel format, the result has minimal stride.
+    ///
+    /// Pixels missing in data are treated as transparent.
+    pub fn convert_with(&self, format: PixelFormat, options: &ConvertOptions) -> ImageData {
+        if let PixelFormat::Invalid = format {
+            return ImageData::with_stride(format, self.width, self.height, 0, Default::default());
+        }
+
+        let mut target = ImageDataMut::new(format, self.width, self.height);
+        if self.format.channel_bits() == format.channel_bits()
+            && self.format.has_color() == format.has_color()
+        {
+            // same layout, copy rows
+            for y in 0..self.height {
+                if let (Some(src), Some(dst)) = (self.row(y), target.row_mut(y)) {
+                    dst.copy_from_slice(src);
+                }
+            }
+            return target.freeze();
+        }
+
+        let source_bits = self.format.channel_bits();
+        let target_bits = format.channel_bits();
+        let mut dither_bits = [0u32; 4];
+        for channel in 0..4 {
+            let source = if channel == 3 && source_bits[3] == 0 {
+                if options.luminance_to_alpha && self.format.has_color() {
+                    8
+                } else {
+                    0
+                }
+            } else {
+                source_bits[channel]
+            };
+            if target_bits[channel] > 0 && target_bits[channel] < source {
+                dither_bits[channel] = target_bits[channel];
+            }
+        }
+        let dither = if dither_bits == [0; 4] {
+            Dither::None
+        } else {
+            options.dither
+        };
+
+        let width = self.width as usize;
+        let mut errors = vec![[0.0f32; 4]; width + 2];
+        let mut next_errors = vec![[0.0f32; 4]; width + 2];
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let color = self
+                    .try_get_pixel(x, y)
+                    .unwrap_or_else(|| Color::new(0.0, 0.0, 0.0, 0.0));
+                let mut channels = self.prepare(color, format, options);
+
+                match dither {
+                    Dither::None => {}
+                    Dither::Ordered => {
+                        let threshold =
+                            (BAYER[y as usize % 4][x as usize % 4] as f32 + 0.5) / 16.0 - 0.5;
+                        for (channel, bits) in channels.iter_mut().zip(dither_bits.iter()) {
+                            if *bits > 0 {
+                                *channel += threshold / ((1u32 << bits) - 1) as f32;
+                            }
+                        }
+                    }
+                    Dither::FloydSteinberg => {
+                        let idx = x as usize + 1;
+                        for channel in 0..4 {
+                            if dither_bits[channel] > 0 {
+                                channels[channel] += errors[idx][channel];
+                            }
+                        }
+                    }
+                }
+
+                let [red, green, blue, alpha] = channels;
+                let color = Color::new(red, green, blue, alpha);
+                target.set_pixel(x, y, color);
+
+                if let Dither::FloydSteinberg = dither {
+                    let max = |bits: u32| ((1u32 << bits) - 1) as f32;
+                    let idx = x as usize + 1;
+                    for channel in 0..4 {
+                        let bits = dither_bits[channel];
+                        if bits == 0 {
+                            continue;
+                        }
+                        let value = channels[channel].clamp(0.0, 1.0);
+                        let quantized = (value * max(bits)).round() / max(bits);
+                        let error = value - quantized;
+                        errors[idx + 1][channel] += error * 7.0 / 16.0;
+                        next_errors[idx - 1][channel] += error * 3.0 / 16.0;
+                        next_errors[idx][channel] += error * 5.0 / 16.0;
+                        next_errors[idx + 1][channel] += error / 16.0;
+                    }
+                }
+            }
+
+            if let Dither::FloydSteinberg = dither {
+                std::mem::swap(&mut errors, &mut next_errors);
+                next_errors.iter_mut().for_each(|error| *error = [0.0; 4]);
+            }
+        }
+
+        target.freeze()
+    }
+
+    /// Apply alpha rules to source color, returns straight red, green, blue and alpha
+    fn prepare(&self, color: Color, format: PixelFormat, options: &ConvertOptions) -> [f32; 4] {
+        let Color {
+            red,
+            green,
+            blue,
+            alpha,
+        } = color;
+
+        if !format.has_color() {
+            let alpha = if self.format.has_alpha() {
+                alpha
+            } else if options.luminance_to_alpha {
+                color.luminance()
+            } else {
+                1.0
+            };
+            return [0.0, 0.0, 0.0, alpha];
+        }
+
+        if format.has_alpha() {
+            return [red, green, blue, alpha];
+        }
+
+        let background = options.background;
+        let over = |value: f32, back: f32| value * alpha + back * (1.0 - alpha);
+        [
+            over(red, background.red),
+            over(green, background.green),
+            over(blue, background.blue),
+            1.0,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FORMATS: [PixelFormat; 7] = [
+        PixelFormat::Invalid,
+        PixelFormat::ARgb32,
+        PixelFormat::Rgb24,
+        PixelFormat::A8,
+        PixelFormat::A1,
+        PixelFormat::Rgb16_565,
+        PixelFormat::Rgb30,
+    ];
+
+    fn gradient(format: PixelFormat, width: u32, height: u32) -> ImageData {
+        let mut image = ImageDataMut::new(format, width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let value = x as f32 / (width - 1) as f32;
+                image.try_set_pixel(x, y, Color::new(value, 1.0 - value, 0.5, value));
+            }
+        }
+        image.freeze()
+    }
+
+    #[test]
+    fn all_pairs() {
+        for source in FORMATS.iter() {
+            let image = gradient(*source, 9, 3);
+            for target in FORMATS.iter() {
+                for dither in [Dither::None, Dither::Ordered, Dither::FloydSteinberg].iter() {
+                    let options = ConvertOptions {
+                        dither: *dither,
+                        ..Default::default()
+                    };
+                    let converted = image.convert_with(*target, &options);
+                    assert_eq!((converted.width, converted.height), (9, 3));
+                    assert_eq!(converted.data.len(), converted.stride * 3);
+                    if target.bits_per_pixel() > 0 {
+                        assert_eq!(
+                            converted.pixels().count(),
+                            27,
+                            "{:?} -> {:?}",
+                            source,
+                            target
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn alpha_rules() {
+        let mut image = ImageDataMut::new(PixelFormat::ARgb32, 2, 1);
+        image.set_pixel(0, 0, Color::rgba(255, 0, 0, 128));
+        image.set_pixel(1, 0, Color::rgb(255, 255, 255));
+        let image = image.freeze();
+
+        let rgb = image.convert(PixelFormat::Rgb24);
+        assert_eq!(rgb.get_pixel(0, 0), Color::rgb(128, 0, 0));
+
+        let options = ConvertOptions {
+            background: Color::rgb(255, 255, 255),
+            ..Default::default()
+        };
+        let rgb = image.convert_with(PixelFormat::Rgb16_565, &options);
+        assert_eq!(
+            rgb.get_pixel(0, 0),
+            PixelFormat::Rgb16_565.decode(PixelFormat::Rgb16_565.encode(Color::rgb(255, 127, 127)))
+        );
+
+        let alpha = image.convert(PixelFormat::A8);
+        assert_eq!(&alpha.data[..2], &[128, 255]);
+
+        let opaque = rgb.convert(PixelFormat::A8);
+        assert_eq!(&opaque.data[..2], &[255, 255]);
+        let luminance = image.convert(PixelFormat::Rgb24).convert_with(
+            PixelFormat::A8,
+            &ConvertOptions {
+                luminance_to_alpha: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(luminance.data[1], 255);
+        assert!(luminance.data[0] < 60);
+
+        let back = alpha.convert(PixelFormat::ARgb32);
+        assert_eq!(back.get_pixel(0, 0), Color::rgba(0, 0, 0, 128));
+    }
+
+    #[test]
+    fn dithering() {
+        // flat 50% alpha becomes half of the bits set
+        let mut image = ImageDataMut::new(PixelFormat::A8, 16, 16);
+        image.fill(Color::rgba(0, 0, 0, 128));
+        let image = image.freeze();
+
+        let count = |image: &ImageData| image.pixels().filter(|color| color.alpha > 0.5).count();
+        assert_eq!(count(&image.convert(PixelFormat::A1)), 256);
+        for dither in [Dither::Ordered, Dither::FloydSteinberg].iter() {
+            let options = ConvertOptions {
+                dither: *dither,
+                ..Default::default()
+            };
+            let ones = count(&image.convert_with(PixelFormat::A1, &options));
+            assert!((120..=136).contains(&ones), "{:?} {}", dither, ones);
+        }
+
+        // average of dithered 565 stays close to the 10 bit source
+        let mut image = ImageDataMut::new(PixelFormat::Rgb30, 32, 8);
+        image.fill(Color::new(0.51, 0.51, 0.51, 1.0));
+        let options = ConvertOptions {
+            dither: Dither::FloydSteinberg,
+            ..Default::default()
+        };
+        let converted = image
+            .freeze()
+            .convert_with(PixelFormat::Rgb16_565, &options);
+        let sum: f32 = converted.pixels().map(|color| color.red).sum();
+        assert!((sum / 256.0 - 0.51).abs() < 0.005, "{}", sum / 256.0);
+    }
+}
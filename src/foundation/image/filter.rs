@@ -0,0 +1,794 @@
+use super::{premultiply, unpremultiply, ImageData, ImageDataMut, PixelFormat};
+
+/// Specifies how filters sample pixels outside of image
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeMode {
+    /// Repeat the nearest edge pixel
+    #[default]
+    Clamp,
+    /// Continue from the opposite edge, for tiled images
+    Wrap,
+    /// Reflect image at its edges, edge pixels are repeated
+    Mirror,
+    /// Pixels outside are transparent black
+    Transparent,
+}
+
+impl EdgeMode {
+    /// Map position into image of size, or `None` for transparent pixel
+    #[inline]
+    fn index(self, position: i64, size: usize) -> Option<usize> {
+        let size = size as i64;
+        if (0..size).contains(&position) {
+            return Some(position as usize);
+        }
+        let position = match self {
+            Self::Clamp => position.clamp(0, size - 1),
+            Self::Wrap => position.rem_euclid(size),
+            Self::Mirror => {
+                let position = position.rem_euclid(2 * size);
+                if position >= size {
+                    2 * size - 1 - position
+                } else {
+                    position
+                }
+            }
+            Self::Transparent => return None,
+        };
+        Some(position as usize)
+    }
+}
+
+/// Convolution kernel with weights stored row by row, centered at `(width / 2, height / 2)`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Kernel {
+    /// Kernel width
+    pub width: u32,
+    /// Kernel height
+    pub height: u32,
+    /// Weights of pixels
+    pub weights: Vec<f32>,
+}
+
+impl Kernel {
+    /// Create kernel with weights stored row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if count of weights is not `width * height`.
+    pub fn new(width: u32, height: u32, weights: Vec<f32>) -> Self {
+        assert_eq!(
+            weights.len(),
+            width as usize * height as usize,
+            "kernel {}x{} needs {} weights",
+            width,
+            height,
+            width as usize * height as usize
+        );
+        Self {
+            width,
+            height,
+            weights,
+        }
+    }
+
+    /// Scale weights to sum of one, kernels with zero sum are kept
+    pub fn normalized(mut self) -> Self {
+        let sum: f32 = self.weights.iter().sum();
+        if sum.abs() > f32::EPSILON {
+            self.weights.iter_mut().for_each(|weight| *weight /= sum);
+        }
+        self
+    }
+
+    /// Create square kernel averaging pixels within radius.
+    ///
+    /// # Panics
+    ///
+    /// Panics if kernel side `2 * radius + 1` does not fit `u32` or count of weights does not fit `usize`.
+    pub fn box_blur(radius: u32) -> Self {
+        let size = radius
+            .checked_mul(2)
+            .and_then(|size| size.checked_add(1))
+            .expect("box kernel radius is too large");
+        let count = (size as usize)
+            .checked_mul(size as usize)
+            .expect("box kernel radius is too large");
+        Self::new(size, size, vec![1.0; count]).normalized()
+    }
+
+    /// Create square Gaussian kernel with standard deviation, its radius is `3 * sigma`.
+    ///
+    /// Sigma that is not positive and finite gives 1x1 identity kernel.
+    pub fn gaussian(sigma: f32) -> Self {
+        let weights = gaussian_weights(sigma, usize::MAX);
+        let size = weights.len() as u32;
+        let weights = weights
+            .iter()
+            .flat_map(|y| weights.iter().map(move |x| x * y))
+            .collect();
+        Self::new(size, size, weights)
+    }
+
+    /// Create kernel sharpening details
+    pub fn sharpen() -> Self {
+        Self::new(3, 3, vec![0.0, -1.0, 0.0, -1.0, 5.0, -1.0, 0.0, -1.0, 0.0])
+    }
+
+    /// Create kernel of relief lit from top left, its sum is zero
+    pub fn emboss() -> Self {
+        Self::new(3, 3, vec![-2.0, -1.0, 0.0, -1.0, 0.0, 1.0, 0.0, 1.0, 2.0])
+    }
+
+    /// Create Laplacian kernel detecting edges in all directions
+    pub fn laplacian() -> Self {
+        Self::new(3, 3, vec![0.0, 1.0, 0.0, 1.0, -4.0, 1.0, 0.0, 1.0, 0.0])
+    }
+
+    /// Create Sobel kernel of horizontal gradient, which detects vertical edges
+    pub fn sobel_x() -> Self {
+        Self::new(3, 3, vec![-1.0, 0.0, 1.0, -2.0, 0.0, 2.0, -1.0, 0.0, 1.0])
+    }
+
+    /// Create Sobel kernel of vertical gradient, which detects horizontal edges
+    pub fn sobel_y() -> Self {
+        Self::new(3, 3, vec![-1.0, -2.0, -1.0, 0.0, 0.0, 0.0, 1.0, 2.0, 1.0])
+    }
+}
+
+/// Normalized weights of one-dimensional Gaussian with radius `3 * sigma`, limited to `max_radius`.
+///
+/// Sigma that is not positive and finite gives identity.
+fn gaussian_weights(sigma: f32, max_radius: usize) -> Vec<f32> {
+    if !sigma.is_finite() || sigma <= 0.0 {
+        return vec![1.0];
+    }
+    let radius = (3.0 * sigma).ceil().min(max_radius as f32) as i64;
+    let weights: Vec<f32> = (-radius..=radius)
+        .map(|x| (-(x as f32 / sigma).powi(2) / 2.0).exp())
+        .collect();
+    let sum: f32 = weights.iter().sum();
+    weights.iter().map(|weight| weight / sum).collect()
+}
+
+/// Radii of three box blurs approximating Gaussian blur, limited to `max_radius`
+fn box_radii(sigma: f32, max_radius: usize) -> [u32; 3] {
+    let sigma = sigma as f64;
+    let variance = 12.0 * sigma * sigma;
+    let ideal = (variance / 3.0 + 1.0).sqrt();
+    let mut lower = ideal.floor();
+    if lower % 2.0 == 0.0 {
+        lower -= 1.0;
+    }
+    let lower = lower.max(1.0);
+    let upper = lower + 2.0;
+    let lower_count =
+        ((variance - 3.0 * lower * lower - 12.0 * lower - 9.0) / (-4.0 * lower - 4.0)).round();
+    [0.0, 1.0, 2.0].map(|idx| {
+        let size = if idx < lower_count { lower } else { upper };
+        ((size - 1.0) / 2.0).min(max_radius as f64) as u32
+    })
+}
+
+/// Premultiplied pixels of image, filters work on them to avoid color bleeding from transparent pixels
+struct Buffer {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 4]>,
+}
+
+impl Buffer {
+    /// Load image, pixels missing in data are transparent
+    fn new(image: &ImageData) -> Self {
+        let pixels = (0..image.height)
+            .flat_map(|y| (0..image.width).map(move |x| (x, y)))
+            .map(|(x, y)| image.try_get_pixel(x, y).map_or([0.0; 4], premultiply))
+            .collect();
+        Self {
+            width: image.width as usize,
+            height: image.height as usize,
+            pixels,
+        }
+    }
+
+    fn map<F>(&self, function: F) -> Buffer
+    where
+        F: Fn(usize, usize) -> [f32; 4],
+    {
+        let pixels = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .map(|(x, y)| function(x, y))
+            .collect();
+        Buffer {
+            width: self.width,
+            height: self.height,
+            pixels,
+        }
+    }
+
+    #[inline]
+    fn get(&self, x: i64, y: i64, edge: EdgeMode) -> [f32; 4] {
+        match (edge.index(x, self.width), edge.index(y, self.height)) {
+            (Some(x), Some(y)) => self.pixels[y * self.width + x],
+            _ => [0.0; 4],
+        }
+    }
+
+    /// Sample pixel at offset along row or column from pixel
+    #[inline]
+    fn along(&self, x: usize, y: usize, offset: i64, horizontal: bool, edge: EdgeMode) -> [f32; 4] {
+        if horizontal {
+            self.get(x as i64 + offset, y as i64, edge)
+        } else {
+            self.get(x as i64, y as i64 + offset, edge)
+        }
+    }
+
+    /// Convolve all components with kernel
+    fn convolve(&self, kernel: &Kernel, edge: EdgeMode) -> Buffer {
+        let (center_x, center_y) = ((kernel.width / 2) as i64, (kernel.height / 2) as i64);
+        self.map(|x, y| {
+            let mut sum = [0.0; 4];
+            for (idx, weight) in kernel.weights.iter().enumerate() {
+                if *weight == 0.0 {
+                    continue;
+                }
+                let dx = (idx % kernel.width as usize) as i64 - center_x;
+                let dy = (idx / kernel.width as usize) as i64 - center_y;
+                let value = self.get(x as i64 + dx, y as i64 + dy, edge);
+                for (sum, value) in sum.iter_mut().zip(value.iter()) {
+                    *sum += value * weight;
+                }
+            }
+            sum
+        })
+    }
+
+    /// Convolve rows or columns with centered weights
+    fn convolve_axis(&self, weights: &[f32], horizontal: bool, edge: EdgeMode) -> Buffer {
+        let center = (weights.len() / 2) as i64;
+        self.map(|x, y| {
+            let mut sum = [0.0; 4];
+            for (idx, weight) in weights.iter().enumerate() {
+                let value = self.along(x, y, idx as i64 - center, horizontal, edge);
+                for (sum, value) in sum.iter_mut().zip(value.iter()) {
+                    *sum += value * weight;
+                }
+            }
+            sum
+        })
+    }
+
+    /// Average rows or columns within radius with running sum, in constant time per pixel.
+    ///
+    /// Radius is limited to length of line.
+    fn box_axis(&self, radius: u32, horizontal: bool, edge: EdgeMode) -> Buffer {
+        let (lines, length) = if horizontal {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+        let radius = (radius as usize).min(length) as i64;
+        let scale = 1.0 / (2 * radius + 1) as f32;
+        let mut result = Buffer {
+            width: self.width,
+            height: self.height,
+            pixels: vec![[0.0; 4]; self.pixels.len()],
+        };
+        for line in 0..lines {
+            let position = |pos: usize| {
+                if horizontal {
+                    (pos, line)
+                } else {
+                    (line, pos)
+                }
+            };
+            // offsets are relative to the first pixel of line
+            let (first_x, first_y) = position(0);
+            let sample = |offset: i64| self.along(first_x, first_y, offset, horizontal, edge);
+            let mut sum = [0.0; 4];
+            for offset in -radius..=radius {
+                let value = sample(offset);
+                sum.iter_mut()
+                    .zip(value.iter())
+                    .for_each(|(sum, value)| *sum += value);
+            }
+            for pos in 0..length {
+                let (x, y) = position(pos);
+                result.pixels[y * self.width + x] = sum.map(|value| value * scale);
+                let entering = sample(pos as i64 + radius + 1);
+                let leaving = sample(pos as i64 - radius);
+                for (channel, sum) in sum.iter_mut().enumerate() {
+                    *sum += entering[channel] - leaving[channel];
+                }
+            }
+        }
+        result
+    }
+
+    /// Take minimum or maximum of rows or columns within radius, which is limited to length of line
+    fn extreme_axis(&self, radius: u32, horizontal: bool, edge: EdgeMode, maximum: bool) -> Buffer {
+        let length = if horizontal { self.width } else { self.height };
+        let radius = (radius as usize).min(length) as i64;
+        self.map(|x, y| {
+            let mut result = self.along(x, y, -radius, horizontal, edge);
+            for offset in -radius + 1..=radius {
+                let value = self.along(x, y, offset, horizontal, edge);
+                for (result, value) in result.iter_mut().zip(value.iter()) {
+                    *result = if maximum {
+                        result.max(*value)
+                    } else {
+                        result.min(*value)
+                    };
+                }
+            }
+            result
+        })
+    }
+
+    /// Store into image of format, premultiplied colors are clamped to alpha
+    fn into_image(self, format: PixelFormat) -> ImageData {
+        let mut image = ImageDataMut::new(format, self.width as u32, self.height as u32);
+        for (idx, pixel) in self.pixels.into_iter().enumerate() {
+            let alpha = pixel[3].clamp(0.0, 1.0);
+            let [red, green, blue] = [0, 1, 2].map(|channel| pixel[channel].clamp(0.0, alpha));
+            let (x, y) = (idx % self.width, idx / self.width);
+            image.set_pixel(x as u32, y as u32, unpremultiply([red, green, blue, alpha]));
+        }
+        image.freeze()
+    }
+}
+
+impl ImageData {
+    /// Build image with the same size and format from premultiplied buffer, empty images are copied
+    fn filtered<F>(&self, filter: F) -> ImageData
+    where
+        F: FnOnce(Buffer) -> Buffer,
+    {
+        if let PixelFormat::Invalid = self.format {
+            return self.clone();
+        }
+        if self.width == 0 || self.height == 0 {
+            return self.clone();
+        }
+        filter(Buffer::new(self)).into_image(self.format)
+    }
+
+    /// Radius of blurs reaching across the whole image, larger ones are cut down to it
+    fn max_radius(&self) -> usize {
+        self.width.max(self.height) as usize
+    }
+
+    /// Build gray image from relative luminance of pixels, alpha is kept
+    fn luminance_filtered<F>(&self, edge: EdgeMode, filter: F) -> ImageData
+    where
+        F: Fn(&dyn Fn(i64, i64) -> f32) -> f32,
+    {
+        self.filtered(|buffer| {
+            let luminances = buffer.map(|x, y| {
+                [
+                    unpremultiply(buffer.pixels[y * buffer.width + x]).luminance(),
+                    0.0,
+                    0.0,
+                    0.0,
+                ]
+            });
+            buffer.map(|x, y| {
+                let value = filter(&|dx, dy| luminances.get(x as i64 + dx, y as i64 + dy, edge)[0])
+                    .clamp(0.0, 1.0);
+                let alpha = buffer.pixels[y * buffer.width + x][3];
+                [value * alpha, value * alpha, value * alpha, alpha]
+            })
+        })
+    }
+
+    /// Convolve premultiplied colors and alpha with kernel, the result has the same format
+    pub fn convolve(&self, kernel: &Kernel, edge: EdgeMode) -> ImageData {
+        self.filtered(|buffer| buffer.convolve(kernel, edge))
+    }
+
+    /// Average pixels within square of radius, in constant time per pixel.
+    ///
+    /// Radius is limited to image size along each axis.
+    pub fn box_blur(&self, radius: u32, edge: EdgeMode) -> ImageData {
+        self.filtered(|buffer| {
+            buffer
+                .box_axis(radius, true, edge)
+                .box_axis(radius, false, edge)
+        })
+    }
+
+    /// Blur with Gaussian of standard deviation in two separable passes
+    pub fn gaussian_blur(&self, sigma: f32, edge: EdgeMode) -> ImageData {
+        let weights = gaussian_weights(sigma, self.max_radius());
+        self.filtered(|buffer| {
+            buffer
+                .convolve_axis(&weights, true, edge)
+                .convolve_axis(&weights, false, edge)
+        })
+    }
+
+    /// Approximate Gaussian blur with three box blurs, in constant time per pixel for finite sigma
+    pub fn fast_gaussian_blur(&self, sigma: f32, edge: EdgeMode) -> ImageData {
+        if !sigma.is_finite() || sigma <= 0.0 {
+            return self.filtered(|buffer| buffer);
+        }
+        let radii = box_radii(sigma, self.max_radius());
+        self.filtered(|buffer| {
+            radii.iter().fold(buffer, |buffer, radius| {
+                buffer
+                    .box_axis(*radius, true, edge)
+                    .box_axis(*radius, false, edge)
+            })
+        })
+    }
+
+    /// Sharpen details with 3x3 kernel
+    pub fn sharpen(&self, edge: EdgeMode) -> ImageData {
+        self.convolve(&Kernel::sharpen(), edge)
+    }
+
+    /// Sharpen by adding difference from Gaussian blur scaled by amount.
+    ///
+    /// Differences of color components below threshold are ignored to keep noise down,
+    /// alpha is kept. Edge mode applies to the blur.
+    pub fn unsharp_mask(
+        &self,
+        sigma: f32,
+        amount: f32,
+        threshold: f32,
+        edge: EdgeMode,
+    ) -> ImageData {
+        let weights = gaussian_weights(sigma, self.max_radius());
+        self.filtered(|buffer| {
+            let blurred = buffer
+                .convolve_axis(&weights, true, edge)
+                .convolve_axis(&weights, false, edge);
+            buffer.map(|x, y| {
+                let idx = y * buffer.width + x;
+                let (original, blurred) = (buffer.pixels[idx], blurred.pixels[idx]);
+                let mut result = original;
+                for channel in 0..3 {
+                    let difference = original[channel] - blurred[channel];
+                    if difference.abs() >= threshold {
+                        result[channel] += amount * difference;
+                    }
+                }
+                result
+            })
+        })
+    }
+
+    /// Convert into gray relief lit from top left, flat areas are middle gray
+    pub fn emboss(&self, edge: EdgeMode) -> ImageData {
+        let kernel = Kernel::emboss();
+        self.luminance_filtered(edge, |luminance| {
+            let mut sum = 0.0;
+            for (idx, weight) in kernel.weights.iter().enumerate() {
+                sum += weight * luminance(idx as i64 % 3 - 1, idx as i64 / 3 - 1);
+            }
+            // flat areas become middle gray
+            sum + 0.5
+        })
+    }
+
+    /// Detect edges as gray magnitude of Sobel gradient of luminance
+    pub fn sobel(&self, edge: EdgeMode) -> ImageData {
+        let (kernel_x, kernel_y) = (Kernel::sobel_x(), Kernel::sobel_y());
+        self.luminance_filtered(edge, |luminance| {
+            let (mut gx, mut gy) = (0.0f32, 0.0f32);
+            for idx in 0..9 {
+                let value = luminance(idx as i64 % 3 - 1, idx as i64 / 3 - 1);
+                gx += kernel_x.weights[idx] * value;
+                gy += kernel_y.weights[idx] * value;
+            }
+            gx.hypot(gy)
+        })
+    }
+
+    /// Detect edges as gray absolute value of Laplacian of luminance
+    pub fn laplacian(&self, edge: EdgeMode) -> ImageData {
+        let kernel = Kernel::laplacian();
+        self.luminance_filtered(edge, |luminance| {
+            let mut sum = 0.0f32;
+            for (idx, weight) in kernel.weights.iter().enumerate() {
+                sum += weight * luminance(idx as i64 % 3 - 1, idx as i64 / 3 - 1);
+            }
+            sum.abs()
+        })
+    }
+
+    /// Replace every component by its median within square of radius, removes speckle noise.
+    ///
+    /// Radius is limited to image size along each axis.
+    pub fn median(&self, radius: u32, edge: EdgeMode) -> ImageData {
+        self.filtered(|buffer| {
+            let radius_x = (radius as usize).min(buffer.width);
+            let radius_y = (radius as usize).min(buffer.height);
+            let mut window: Vec<f32> =
+                Vec::with_capacity((2 * radius_x + 1).saturating_mul(2 * radius_y + 1));
+            let (radius_x, radius_y) = (radius_x as i64, radius_y as i64);
+            let mut pixels = Vec::with_capacity(buffer.pixels.len());
+            for y in 0..buffer.height as i64 {
+                for x in 0..buffer.width as i64 {
+                    let mut result = [0.0; 4];
+                    for (channel, result) in result.iter_mut().enumerate() {
+                        window.clear();
+                        for dy in -radius_y..=radius_y {
+                            for dx in -radius_x..=radius_x {
+                                window.push(buffer.get(x + dx, y + dy, edge)[channel]);
+                            }
+                        }
+                        let middle = window.len() / 2;
+                        *result = *window
+                            .select_nth_unstable_by(middle, |a, b| a.total_cmp(b))
+                            .1;
+                    }
+                    pixels.push(result);
+                }
+            }
+            Buffer { pixels, ..buffer }
+        })
+    }
+
+    /// Take maximum of every component within square of radius, grows bright and opaque areas.
+    ///
+    /// Radius is limited to image size along each axis.
+    pub fn dilate(&self, radius: u32, edge: EdgeMode) -> ImageData {
+        self.filtered(|buffer| {
+            buffer
+                .extreme_axis(radius, true, edge, true)
+                .extreme_axis(radius, false, edge, true)
+        })
+    }
+
+    /// Take minimum of every component within square of radius, shrinks bright and opaque areas.
+    ///
+    /// Radius is limited to image size along each axis.
+    pub fn erode(&self, radius: u32, edge: EdgeMode) -> ImageData {
+        self.filtered(|buffer| {
+            buffer
+                .extreme_axis(radius, true, edge, false)
+                .extreme_axis(radius, false, edge, false)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::colorspace::Color;
+
+    /// Opaque gray image from values of rows
+    fn gray(rows: &[&[u8]]) -> ImageData {
+        let mut image =
+            ImageDataMut::new(PixelFormat::ARgb32, rows[0].len() as u32, rows.len() as u32);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, value) in row.iter().enumerate() {
+                image.set_pixel(x as u32, y as u32, Color::rgb(*value, *value, *value));
+            }
+        }
+        image.freeze()
+    }
+
+    fn values(image: &ImageData) -> Vec<u8> {
+        image
+            .pixels()
+            .map(|color| color.cast::<u8>().to_array()[0])
+            .collect()
+    }
+
+    #[test]
+    fn edge_modes() {
+        let indices = |edge: EdgeMode| -> Vec<Option<usize>> {
+            (-3..6).map(|position| edge.index(position, 3)).collect()
+        };
+        let some = |values: &[usize]| values.iter().map(|value| Some(*value)).collect::<Vec<_>>();
+        assert_eq!(indices(EdgeMode::Clamp), some(&[0, 0, 0, 0, 1, 2, 2, 2, 2]));
+        assert_eq!(indices(EdgeMode::Wrap), some(&[0, 1, 2, 0, 1, 2, 0, 1, 2]));
+        assert_eq!(
+            indices(EdgeMode::Mirror),
+            some(&[2, 1, 0, 0, 1, 2, 2, 1, 0])
+        );
+        assert_eq!(
+            indices(EdgeMode::Transparent),
+            [
+                None,
+                None,
+                None,
+                Some(0),
+                Some(1),
+                Some(2),
+                None,
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn blurs() {
+        let image = gray(&[&[0, 0, 255, 0, 0, 0]]);
+        assert_eq!(
+            values(&image.box_blur(1, EdgeMode::Clamp)),
+            [0, 85, 85, 85, 0, 0]
+        );
+        assert_eq!(
+            values(&image.convolve(&Kernel::box_blur(1), EdgeMode::Wrap)),
+            [0, 85, 85, 85, 0, 0]
+        );
+
+        // transparent edges fade out
+        let white = gray(&[&[255, 255, 255]]);
+        let blurred = white.box_blur(1, EdgeMode::Transparent);
+        let alphas: Vec<u8> = blurred
+            .pixels()
+            .map(|color| color.cast::<u8>().to_array()[3])
+            .collect();
+        assert_eq!(alphas, [57, 85, 57]);
+        assert_eq!(values(&blurred), [255, 255, 255]);
+
+        // blurs keep total of the row and uniform images
+        let image = gray(&[&[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]]);
+        let exact = image.gaussian_blur(2.0, EdgeMode::Clamp);
+        let fast = image.fast_gaussian_blur(2.0, EdgeMode::Clamp);
+        let total = |image: &ImageData| values(image).iter().map(|v| *v as i32).sum::<i32>();
+        assert!((total(&exact) - 255).abs() <= 10, "{}", total(&exact));
+        assert!((total(&fast) - 255).abs() <= 10, "{}", total(&fast));
+        for (exact, fast) in values(&exact).iter().zip(values(&fast).iter()) {
+            assert!(
+                (*exact as i32 - *fast as i32).abs() <= 6,
+                "{} {}",
+                exact,
+                fast
+            );
+        }
+        assert_eq!(values(&exact)[10], 51);
+
+        let uniform = gray(&[&[100; 5], &[100; 5]]);
+        for filtered in [
+            uniform.gaussian_blur(1.5, EdgeMode::Mirror),
+            uniform.fast_gaussian_blur(3.0, EdgeMode::Wrap),
+            uniform.sharpen(EdgeMode::Clamp),
+            uniform.unsharp_mask(1.0, 1.0, 0.0, EdgeMode::Mirror),
+        ] {
+            assert_eq!(values(&filtered), [100; 10]);
+        }
+    }
+
+    #[test]
+    fn large_sigma() {
+        let image = gray(&[&[0, 0, 255, 0], &[0, 0, 0, 0]]);
+        // radius is limited to image, which gets spread over all pixels
+        for filtered in [
+            image.gaussian_blur(20000.0, EdgeMode::Clamp),
+            image.fast_gaussian_blur(20000.0, EdgeMode::Wrap),
+            image.unsharp_mask(20000.0, 1.0, 0.0, EdgeMode::Clamp),
+        ] {
+            assert_eq!((filtered.width, filtered.height), (4, 2));
+        }
+        assert!(values(&image.gaussian_blur(20000.0, EdgeMode::Wrap))
+            .iter()
+            .all(|value| (20..=50).contains(value)));
+        assert_eq!(box_radii(20000.0, 4), [4, 4, 4]);
+        assert_eq!(gaussian_weights(20000.0, 4).len(), 9);
+
+        // infinite sigma is rejected like NaN
+        for sigma in [f32::INFINITY, f32::NAN, -1.0] {
+            assert_eq!(
+                values(&image.gaussian_blur(sigma, EdgeMode::Clamp)),
+                values(&image)
+            );
+            assert_eq!(
+                values(&image.fast_gaussian_blur(sigma, EdgeMode::Clamp)),
+                values(&image)
+            );
+            assert_eq!(Kernel::gaussian(sigma).weights, [1.0]);
+        }
+    }
+
+    #[test]
+    fn sharpening() {
+        let image = gray(&[&[100, 100, 100, 150, 150, 150]]);
+        assert_eq!(
+            values(&image.sharpen(EdgeMode::Clamp)),
+            [100, 100, 50, 200, 150, 150]
+        );
+        let sharpened = values(&image.unsharp_mask(1.0, 1.0, 0.0, EdgeMode::Clamp));
+        assert!(sharpened[2] < 100 && sharpened[3] > 150, "{:?}", sharpened);
+        assert_eq!((sharpened[0], sharpened[5]), (100, 150));
+        // wrapped ends meet as another step
+        let wrapped = values(&image.unsharp_mask(1.0, 1.0, 0.0, EdgeMode::Wrap));
+        assert!(wrapped[0] < 100 && wrapped[5] > 150, "{:?}", wrapped);
+        // threshold above step keeps image
+        assert_eq!(
+            values(&image.unsharp_mask(1.0, 1.0, 0.5, EdgeMode::Clamp)),
+            values(&image)
+        );
+    }
+
+    #[test]
+    fn edges() {
+        let image = gray(&[&[0, 0, 255, 255], &[0, 0, 255, 255], &[0, 0, 255, 255]]);
+        assert_eq!(
+            values(&image.sobel(EdgeMode::Clamp)),
+            [0, 255, 255, 0, 0, 255, 255, 0, 0, 255, 255, 0]
+        );
+        assert_eq!(
+            values(&image.laplacian(EdgeMode::Clamp)),
+            [0, 255, 255, 0, 0, 255, 255, 0, 0, 255, 255, 0]
+        );
+        // flat areas are middle gray, edge facing light is bright
+        let embossed = values(&image.emboss(EdgeMode::Clamp));
+        assert!((127..=128).contains(&embossed[0]));
+        assert_eq!(embossed[3], embossed[0]);
+        assert_eq!(&embossed[1..3], [255, 255]);
+    }
+
+    #[test]
+    fn rank_filters() {
+        let image = gray(&[&[10, 10, 10, 10], &[10, 255, 10, 10], &[10, 10, 10, 10]]);
+        assert_eq!(values(&image.median(1, EdgeMode::Clamp)), [10; 12]);
+        assert_eq!(
+            values(&image.dilate(1, EdgeMode::Clamp)),
+            [255, 255, 255, 10, 255, 255, 255, 10, 255, 255, 255, 10]
+        );
+        assert_eq!(values(&image.erode(1, EdgeMode::Clamp)), [10; 12]);
+        // opening removes the speck
+        assert_eq!(
+            values(&image.erode(1, EdgeMode::Clamp).dilate(1, EdgeMode::Clamp)),
+            [10; 12]
+        );
+    }
+
+    #[test]
+    fn large_radius() {
+        let image = gray(&[&[10, 10, 10, 10], &[10, 255, 10, 10], &[10, 10, 10, 10]]);
+        let uniform = gray(&[&[100; 4], &[100; 4], &[100; 4]]);
+        for edge in [EdgeMode::Clamp, EdgeMode::Wrap, EdgeMode::Mirror] {
+            assert_eq!(values(&uniform.box_blur(u32::MAX, edge)), [100; 12]);
+            assert_eq!(values(&image.median(u32::MAX, edge)), [10; 12]);
+            assert_eq!(values(&image.dilate(u32::MAX, edge)), [255; 12]);
+            assert_eq!(values(&image.erode(u32::MAX, edge)), [10; 12]);
+        }
+        // window reaching over the whole image
+        let blurred = values(&image.box_blur(u32::MAX, EdgeMode::Wrap));
+        assert!(
+            blurred.iter().all(|value| (20..=50).contains(value)),
+            "{:?}",
+            blurred
+        );
+    }
+
+    #[test]
+    fn empty() {
+        for (width, height) in [(0, 5), (5, 0)] {
+            let image = ImageDataMut::new(PixelFormat::ARgb32, width, height).freeze();
+            for filtered in [
+                image.box_blur(1, EdgeMode::Clamp),
+                image.fast_gaussian_blur(2.0, EdgeMode::Wrap),
+                image.gaussian_blur(1.0, EdgeMode::Mirror),
+                image.sobel(EdgeMode::Clamp),
+                image.median(1, EdgeMode::Wrap),
+            ] {
+                assert_eq!((filtered.width, filtered.height), (width, height));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn kernel_size() {
+        Kernel::new(3, 3, vec![1.0; 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "too large")]
+    fn box_kernel_radius() {
+        Kernel::box_blur(u32::MAX / 2 + 1);
+    }
+}
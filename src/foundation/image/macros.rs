@@ -0,0 +1,168 @@
+//! Pixel access shared by image types with `format`, `width`, `height`, `stride`
+//! and `data` fields, and `first_column` method with the bit offset of `A1` views.
+
+/// Implements read access
+macro_rules! impl_pixel_read {
+    ($image:ty) => {
+        impl $image {
+            /// Retrieve color of pixel.
+            ///
+            /// # Panics
+            ///
+            /// Panics if pixel is out of image bounds or data.
+            pub fn get_pixel(&self, x: u32, y: u32) -> Color {
+                self.try_get_pixel(x, y).unwrap_or_else(|| {
+                    panic!(
+                        "pixel ({}, {}) is out of {}x{} image",
+                        x, y, self.width, self.height
+                    )
+                })
+            }
+
+            /// Retrieve color of pixel, or `None` if pixel is out of image bounds or data
+            pub fn try_get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+                use $crate::foundation::image::{decode_at, pixel_offset};
+
+                if x >= self.width || y >= self.height {
+                    return None;
+                }
+                let column = x + self.first_column();
+                let offset = pixel_offset(self.format, self.stride, column, y);
+                if offset + self.format.bytes_per_pixel().max(1) <= self.data.len() {
+                    Some(decode_at(self.format, &self.data, offset, column))
+                } else {
+                    None
+                }
+            }
+
+            /// Retrieve color of pixel without bounds checking.
+            ///
+            /// # Safety
+            ///
+            /// Pixel must lie inside image and data must contain all its bytes.
+            pub unsafe fn get_pixel_unchecked(&self, x: u32, y: u32) -> Color {
+                use $crate::foundation::image::{decode_at, pixel_offset};
+
+                let column = x + self.first_column();
+                let offset = pixel_offset(self.format, self.stride, column, y);
+                let size = self.format.bytes_per_pixel().max(1);
+                let bytes = self.data.get_unchecked(offset..offset + size);
+                decode_at(self.format, bytes, 0, column)
+            }
+
+            /// Retrieve bytes of row without padding, or `None` if row is out of image bounds or data.
+            ///
+            /// Rows of `A1` views not starting at byte boundary begin with bits of preceding pixels.
+            pub fn row(&self, y: u32) -> Option<&[u8]> {
+                if y >= self.height {
+                    return None;
+                }
+                let start = y as usize * self.stride;
+                let len = self.format.row_bytes(self.width + self.first_column());
+                self.data.get(start..start + len)
+            }
+
+            /// Iterate over bytes of rows without padding, stops at the first incomplete row
+            pub fn rows(&self) -> impl Iterator<Item = &[u8]> + '_ {
+                (0..self.height).map_while(move |y| self.row(y))
+            }
+
+            /// Iterate over colors of pixels row by row
+            pub fn pixels(&self) -> impl Iterator<Item = Color> + '_ {
+                (0..self.height)
+                    .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+                    .map_while(move |(x, y)| self.try_get_pixel(x, y))
+            }
+        }
+    };
+}
+
+/// Implements write access
+macro_rules! impl_pixel_write {
+    ($image:ty) => {
+        impl $image {
+            /// Set color of pixel.
+            ///
+            /// # Panics
+            ///
+            /// Panics if pixel is out of image bounds or data.
+            pub fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+                if !self.try_set_pixel(x, y, color) {
+                    panic!(
+                        "pixel ({}, {}) is out of {}x{} image",
+                        x, y, self.width, self.height
+                    );
+                }
+            }
+
+            /// Set color of pixel, returns `false` and keeps image when pixel is out of image bounds or data
+            pub fn try_set_pixel(&mut self, x: u32, y: u32, color: Color) -> bool {
+                use $crate::foundation::image::{encode_at, pixel_offset};
+
+                if x >= self.width || y >= self.height {
+                    return false;
+                }
+                let column = x + self.first_column();
+                let offset = pixel_offset(self.format, self.stride, column, y);
+                if offset + self.format.bytes_per_pixel().max(1) <= self.data.len() {
+                    encode_at(self.format, &mut self.data, offset, column, color);
+                    true
+                } else {
+                    false
+                }
+            }
+
+            /// Set color of pixel without bounds checking.
+            ///
+            /// # Safety
+            ///
+            /// Pixel must lie inside image and data must contain all its bytes.
+            pub unsafe fn set_pixel_unchecked(&mut self, x: u32, y: u32, color: Color) {
+                use $crate::foundation::image::{encode_at, pixel_offset};
+
+                let column = x + self.first_column();
+                let offset = pixel_offset(self.format, self.stride, column, y);
+                let size = self.format.bytes_per_pixel().max(1);
+                let bytes = self.data.get_unchecked_mut(offset..offset + size);
+                encode_at(self.format, bytes, 0, column, color);
+            }
+
+            /// Retrieve mutable bytes of row without padding, or `None` if row is out of image bounds or data.
+            ///
+            /// Rows of `A1` views not starting at byte boundary begin with bits of preceding pixels.
+            pub fn row_mut(&mut self, y: u32) -> Option<&mut [u8]> {
+                if y >= self.height {
+                    return None;
+                }
+                let start = y as usize * self.stride;
+                let end = start + self.format.row_bytes(self.width + self.first_column());
+                self.data.get_mut(start..end)
+            }
+
+            /// Iterate over mutable bytes of rows without padding, stops at the first incomplete row
+            pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [u8]> + '_ {
+                let row_bytes = self.format.row_bytes(self.width + self.first_column());
+                let height = self.height as usize;
+                // rows would overlap when stride is shorter than row, so such images have no rows
+                let (stride, height) = if self.stride >= row_bytes && self.stride > 0 {
+                    (self.stride, height)
+                } else {
+                    (1, 0)
+                };
+                self.data
+                    .chunks_mut(stride)
+                    .take(height)
+                    .map_while(move |row| row.get_mut(..row_bytes))
+            }
+
+            /// Fill every pixel with color
+            pub fn fill(&mut self, color: Color) {
+                for y in 0..self.height {
+                    for x in 0..self.width {
+                        self.try_set_pixel(x, y, color);
+                    }
+                }
+            }
+        }
+    };
+}
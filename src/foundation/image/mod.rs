@@ -0,0 +1,300 @@
+use bytes::{Bytes, BytesMut};
+
+use super::{colorspace::Color, Endian};
+
+#[macro_use]
+mod macros;
+
+mod codecs;
+pub use codecs::*;
+
+mod convert;
+pub use convert::*;
+
+mod filter;
+pub use filter::*;
+
+mod resize;
+pub use resize::*;
+
+mod transform;
+
+mod view;
+pub use view::*;
+
+/// Describes pixel format properties
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PixelFormat {
+    /// Invalid pixel format
+    Invalid,
+    /// Represents ARgb32 format
+    ARgb32,
+    /// Represents Rgb24 format
+    Rgb24,
+    /// Represents A8 format
+    A8,
+    /// Represents A1 format
+    A1,
+    /// Represents Rgb16_565 format
+    Rgb16_565,
+    /// Represents Rgb30 format
+    Rgb30,
+}
+
+impl Default for PixelFormat {
+    fn default() -> Self {
+        PixelFormat::ARgb32
+    }
+}
+
+/// Represens image data with parameters.
+///
+/// Rows start every `stride` bytes, pixels are stored in native byte order
+/// like cairo image surfaces, `A1` pixels are packed starting from the least significant bit.
+#[derive(Debug, Clone, Default)]
+pub struct ImageData {
+    /// Image format
+    pub format: PixelFormat,
+    /// Image width
+    pub width: u32,
+    /// Image height
+    pub height: u32,
+    /// Count of bytes between starts of rows
+    pub stride: usize,
+    /// Image data
+    pub data: Bytes,
+}
+
+/// Represens mutable image data with parameters, see [ImageData]
+#[derive(Debug, Clone)]
+pub struct ImageDataMut {
+    /// Image format
+    pub format: PixelFormat,
+    /// Image width
+    pub width: u32,
+    /// Image height
+    pub height: u32,
+    /// Count of bytes between starts of rows
+    pub stride: usize,
+    /// Image data
+    pub data: BytesMut,
+}
+
+/// Byte offset of pixel, the bit inside byte is `x % 8` for `A1`
+#[inline]
+fn pixel_offset(format: PixelFormat, stride: usize, x: u32, y: u32) -> usize {
+    let column = match format {
+        PixelFormat::A1 => x as usize / 8,
+        _ => x as usize * format.bytes_per_pixel(),
+    };
+    y as usize * stride + column
+}
+
+#[inline]
+fn decode_at(format: PixelFormat, data: &[u8], offset: usize, x: u32) -> Color {
+    match format {
+        PixelFormat::Invalid => Color::new(0.0, 0.0, 0.0, 0.0),
+        PixelFormat::A1 => format.decode((data[offset] >> (x % 8)) as u32),
+        _ => format.read_pixel(&data[offset..], Endian::Native),
+    }
+}
+
+#[inline]
+fn encode_at(format: PixelFormat, data: &mut [u8], offset: usize, x: u32, color: Color) {
+    match format {
+        PixelFormat::Invalid => {}
+        PixelFormat::A1 => {
+            let mask = 1u8 << (x % 8);
+            if format.encode(color) != 0 {
+                data[offset] |= mask;
+            } else {
+                data[offset] &= !mask;
+            }
+        }
+        _ => format.write_pixel(color, Endian::Native, &mut data[offset..]),
+    }
+}
+
+/// Premultiply color components by alpha
+#[inline]
+pub(crate) fn premultiply(color: Color) -> [f32; 4] {
+    let alpha = color.alpha.clamp(0.0, 1.0);
+    [
+        color.red * alpha,
+        color.green * alpha,
+        color.blue * alpha,
+        alpha,
+    ]
+}
+
+/// Divide premultiplied components by alpha
+#[inline]
+pub(crate) fn unpremultiply([red, green, blue, alpha]: [f32; 4]) -> Color {
+    let alpha = alpha.clamp(0.0, 1.0);
+    if alpha <= 0.0 {
+        return Color::new(0.0, 0.0, 0.0, 0.0);
+    }
+    let channel = |value: f32| (value / alpha).clamp(0.0, 1.0);
+    Color::new(channel(red), channel(green), channel(blue), alpha)
+}
+
+impl_pixel_read!(ImageData);
+impl_pixel_read!(ImageDataMut);
+impl_pixel_write!(ImageDataMut);
+
+impl ImageData {
+    /// Create image data with params, rows have minimal stride aligned to 4 bytes
+    pub fn new(format: PixelFormat, width: u32, height: u32, data: Bytes) -> Self {
+        Self::with_stride(format, width, height, format.stride_for_width(width), data)
+    }
+
+    /// Create image data with explicit row stride
+    pub fn with_stride(
+        format: PixelFormat,
+        width: u32,
+        height: u32,
+        stride: usize,
+        data: Bytes,
+    ) -> Self {
+        Self {
+            format,
+            width,
+            height,
+            stride,
+            data,
+        }
+    }
+
+    #[inline]
+    fn first_column(&self) -> u32 {
+        0
+    }
+
+    /// Copy image into mutable image data
+    pub fn to_mut(&self) -> ImageDataMut {
+        ImageDataMut::with_stride(
+            self.format,
+            self.width,
+            self.height,
+            self.stride,
+            BytesMut::from(&self.data[..]),
+        )
+    }
+}
+
+impl ImageDataMut {
+    /// Create image with all bytes zeroed, which is transparent or black
+    pub fn new(format: PixelFormat, width: u32, height: u32) -> Self {
+        let stride = format.stride_for_width(width);
+        let data = BytesMut::zeroed(stride * height as usize);
+        Self::with_stride(format, width, height, stride, data)
+    }
+
+    /// Create image data with explicit row stride
+    pub fn with_stride(
+        format: PixelFormat,
+        width: u32,
+        height: u32,
+        stride: usize,
+        data: BytesMut,
+    ) -> Self {
+        Self {
+            format,
+            width,
+            height,
+            stride,
+            data,
+        }
+    }
+
+    #[inline]
+    fn first_column(&self) -> u32 {
+        0
+    }
+
+    /// Convert into immutable image data without copying
+    pub fn freeze(self) -> ImageData {
+        ImageData::with_stride(
+            self.format,
+            self.width,
+            self.height,
+            self.stride,
+            self.data.freeze(),
+        )
+    }
+}
+
+impl From<ImageDataMut> for ImageData {
+    fn from(image: ImageDataMut) -> Self {
+        image.freeze()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pixel_access() {
+        let mut image = ImageDataMut::new(PixelFormat::ARgb32, 3, 2);
+        assert_eq!(image.stride, 12);
+        image.set_pixel(2, 1, Color::rgb(10, 20, 30));
+        assert_eq!(image.get_pixel(2, 1), Color::rgb(10, 20, 30));
+        assert_eq!(image.get_pixel(0, 0), Color::new(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(image.try_get_pixel(3, 0), None);
+        assert!(!image.try_set_pixel(0, 2, Color::rgb(1, 2, 3)));
+        assert_eq!(
+            unsafe { image.get_pixel_unchecked(2, 1) },
+            Color::rgb(10, 20, 30)
+        );
+
+        let image = image.freeze();
+        assert_eq!(&image.data[20..24], &(0xff0a_141eu32).to_ne_bytes(),);
+        assert_eq!(image.get_pixel(2, 1), Color::rgb(10, 20, 30));
+        assert_eq!(image.pixels().count(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of 3x2 image")]
+    fn pixel_out_of_bounds() {
+        ImageDataMut::new(PixelFormat::A8, 3, 2).set_pixel(3, 0, Color::rgb(1, 2, 3));
+    }
+
+    #[test]
+    fn bit_packing() {
+        let mut image = ImageDataMut::new(PixelFormat::A1, 10, 2);
+        assert_eq!(image.stride, 4);
+        image.set_pixel(0, 0, Color::rgb(0, 0, 0));
+        image.set_pixel(9, 0, Color::rgb(0, 0, 0));
+        unsafe { image.set_pixel_unchecked(3, 1, Color::rgb(0, 0, 0)) };
+        assert_eq!(image.row(0), Some(&[0b1, 0b10][..]));
+        assert_eq!(image.row(1), Some(&[0b1000, 0][..]));
+
+        image.set_pixel(0, 0, Color::new(0.0, 0.0, 0.0, 0.2));
+        assert_eq!(image.get_pixel(0, 0).alpha, 0.0);
+        assert_eq!(image.get_pixel(9, 0).alpha, 1.0);
+    }
+
+    #[test]
+    fn stride_and_rows() {
+        // 2x2 Rgb16_565 rows padded to 6 bytes, the last row is not padded
+        let data = Bytes::from(vec![0xff, 0xff, 0, 0, 9, 9, 0, 0, 0x1f, 0]);
+        let image = ImageData::with_stride(PixelFormat::Rgb16_565, 2, 2, 6, data);
+        let rows: Vec<&[u8]> = image.rows().collect();
+        assert_eq!(rows, vec![&[0xff, 0xff, 0, 0][..], &[0, 0, 0x1f, 0][..]]);
+        assert_eq!(image.get_pixel(0, 0), Color::rgb(255, 255, 255));
+        assert_eq!(image.get_pixel(1, 1), Color::rgb(0, 0, 255));
+
+        let mut image = image.to_mut();
+        for row in image.rows_mut() {
+            row.iter_mut().for_each(|byte| *byte = 0);
+        }
+        assert_eq!(&image.data[..], &[0, 0, 0, 0, 9, 9, 0, 0, 0, 0]);
+
+        let truncated = ImageData::new(PixelFormat::ARgb32, 2, 2, Bytes::from(vec![0; 12]));
+        assert_eq!(truncated.rows().count(), 1);
+        assert!(truncated.try_get_pixel(0, 1).is_some());
+        assert_eq!(truncated.try_get_pixel(1, 1), None);
+    }
+}
@@ -0,0 +1,341 @@
+use super::{premultiply, unpremultiply, ImageData, ImageDataMut, PixelFormat};
+use crate::foundation::colorspace::{from_linear, to_linear, Color};
+
+/// Resampling filter used to resize images
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResizeFilter {
+    /// Take the nearest source pixel, keeps hard edges of pixel art
+    Nearest,
+    /// Average of source pixels covered by target pixel, good for downscaling
+    Box,
+    /// Linear interpolation between neighbour pixels
+    Bilinear,
+    /// Catmull-Rom cubic spline, sharp bicubic interpolation
+    CatmullRom,
+    /// Mitchell-Netravali cubic with `B = C = 1/3`, smooth bicubic interpolation with less ringing
+    Mitchell,
+    /// Windowed sinc with three lobes, sharpest result with slight ringing
+    #[default]
+    Lanczos3,
+}
+
+impl ResizeFilter {
+    /// Retrieve radius of filter in source pixels when upscaling
+    pub fn support(&self) -> f32 {
+        match self {
+            Self::Nearest | Self::Box => 0.5,
+            Self::Bilinear => 1.0,
+            Self::CatmullRom | Self::Mitchell => 2.0,
+            Self::Lanczos3 => 3.0,
+        }
+    }
+
+    /// Retrieve weight of sample at distance from center
+    pub fn weight(&self, x: f32) -> f32 {
+        let x = x.abs();
+        match self {
+            Self::Nearest | Self::Box => {
+                if x <= 0.5 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Self::Bilinear => (1.0 - x).max(0.0),
+            Self::CatmullRom => cubic(x, 0.0, 0.5),
+            Self::Mitchell => cubic(x, 1.0 / 3.0, 1.0 / 3.0),
+            Self::Lanczos3 => {
+                if x < 3.0 {
+                    sinc(x) * sinc(x / 3.0)
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// Cubic filter family of Mitchell and Netravali
+fn cubic(x: f32, b: f32, c: f32) -> f32 {
+    if x < 1.0 {
+        ((12.0 - 9.0 * b - 6.0 * c) * x * x * x
+            + (-18.0 + 12.0 * b + 6.0 * c) * x * x
+            + (6.0 - 2.0 * b))
+            / 6.0
+    } else if x < 2.0 {
+        ((-b - 6.0 * c) * x * x * x
+            + (6.0 * b + 30.0 * c) * x * x
+            + (-12.0 * b - 48.0 * c) * x
+            + (8.0 * b + 24.0 * c))
+            / 6.0
+    } else {
+        0.0
+    }
+}
+
+fn sinc(x: f32) -> f32 {
+    if x == 0.0 {
+        1.0
+    } else {
+        let x = x * std::f32::consts::PI;
+        x.sin() / x
+    }
+}
+
+/// Options of image resizing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResizeOptions {
+    /// Resampling filter
+    pub filter: ResizeFilter,
+    /// Average colors in linear light instead of sRGB encoded values,
+    /// keeps brightness of fine details when downscaling
+    pub gamma_correct: bool,
+}
+
+impl ResizeOptions {
+    /// Create options with params
+    pub fn with_parameters(filter: ResizeFilter, gamma_correct: bool) -> Self {
+        Self {
+            filter,
+            gamma_correct,
+        }
+    }
+}
+
+/// Source pixels with weights contributing to one target pixel
+struct Contribution {
+    start: usize,
+    weights: Vec<f32>,
+}
+
+/// Compute contributions of source pixels to every target pixel along one axis
+fn contributions(source: u32, target: u32, filter: ResizeFilter) -> Vec<Contribution> {
+    let scale = source as f32 / target as f32;
+    let last = source as usize - 1;
+
+    if let ResizeFilter::Nearest = filter {
+        return (0..target)
+            .map(|idx| Contribution {
+                start: (((idx as f32 + 0.5) * scale) as usize).min(last),
+                weights: vec![1.0],
+            })
+            .collect();
+    }
+
+    // widen filter when downscaling so every source pixel contributes
+    let filter_scale = scale.max(1.0);
+    let support = filter.support() * filter_scale;
+    (0..target)
+        .map(|idx| {
+            let center = (idx as f32 + 0.5) * scale;
+            let start = ((center - support).floor().max(0.0) as usize).min(last);
+            let end = ((center + support).ceil() as usize).clamp(start + 1, source as usize);
+            let mut weights: Vec<f32> = (start..end)
+                .map(|pos| filter.weight((pos as f32 + 0.5 - center) / filter_scale))
+                .collect();
+            let sum: f32 = weights.iter().sum();
+            if sum.abs() > f32::EPSILON {
+                weights.iter_mut().for_each(|weight| *weight /= sum);
+            } else {
+                weights = vec![1.0];
+                return Contribution {
+                    start: (center as usize).min(last),
+                    weights,
+                };
+            }
+            Contribution { start, weights }
+        })
+        .collect()
+}
+
+impl ImageData {
+    /// Resize image with filter, colors are averaged without gamma correction
+    pub fn resize(&self, width: u32, height: u32, filter: ResizeFilter) -> ImageData {
+        self.resize_with(
+            width,
+            height,
+            &ResizeOptions::with_parameters(filter, false),
+        )
+    }
+
+    /// Resize image in two separable passes, the result has the same format and minimal stride.
+    ///
+    /// Colors are filtered with premultiplied alpha, so transparent pixels do not bleed
+    /// their color into edges. Pixels missing in data are treated as transparent.
+    pub fn resize_with(&self, width: u32, height: u32, options: &ResizeOptions) -> ImageData {
+        if let PixelFormat::Invalid = self.format {
+            return ImageData::with_stride(self.format, width, height, 0, Default::default());
+        }
+
+        let mut target = ImageDataMut::new(self.format, width, height);
+        if width == 0 || height == 0 || self.width == 0 || self.height == 0 {
+            return target.freeze();
+        }
+
+        let gamma_correct = options.gamma_correct && self.format.has_color();
+        let pixels: Vec<[f32; 4]> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let color = self
+                    .try_get_pixel(x, y)
+                    .unwrap_or_else(|| Color::new(0.0, 0.0, 0.0, 0.0));
+                let decode = |value: f32| {
+                    if gamma_correct {
+                        to_linear(value)
+                    } else {
+                        value
+                    }
+                };
+                premultiply(Color::new(
+                    decode(color.red),
+                    decode(color.green),
+                    decode(color.blue),
+                    color.alpha,
+                ))
+            })
+            .collect();
+
+        // horizontal pass into intermediate image with target width and source height
+        let columns = contributions(self.width, width, options.filter);
+        let source_width = self.width as usize;
+        let mut horizontal = Vec::with_capacity(width as usize * self.height as usize);
+        for row in pixels.chunks(source_width) {
+            for contribution in columns.iter() {
+                horizontal.push(weighted_sum(contribution, |idx| row[idx]));
+            }
+        }
+
+        // vertical pass
+        let rows = contributions(self.height, height, options.filter);
+        let target_width = width as usize;
+        for (y, contribution) in rows.iter().enumerate() {
+            for x in 0..target_width {
+                let color = unpremultiply(weighted_sum(contribution, |idx| {
+                    horizontal[idx * target_width + x]
+                }));
+                let encode = |value: f32| {
+                    if gamma_correct {
+                        from_linear(value)
+                    } else {
+                        value
+                    }
+                };
+                let color = Color::new(
+                    encode(color.red),
+                    encode(color.green),
+                    encode(color.blue),
+                    color.alpha,
+                );
+                target.set_pixel(x as u32, y as u32, color);
+            }
+        }
+
+        target.freeze()
+    }
+}
+
+#[inline]
+fn weighted_sum<F>(contribution: &Contribution, sample: F) -> [f32; 4]
+where
+    F: Fn(usize) -> [f32; 4],
+{
+    let mut sum = [0.0; 4];
+    for (offset, weight) in contribution.weights.iter().enumerate() {
+        let value = sample(contribution.start + offset);
+        for (sum, value) in sum.iter_mut().zip(value.iter()) {
+            *sum += value * weight;
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FILTERS: [ResizeFilter; 6] = [
+        ResizeFilter::Nearest,
+        ResizeFilter::Box,
+        ResizeFilter::Bilinear,
+        ResizeFilter::CatmullRom,
+        ResizeFilter::Mitchell,
+        ResizeFilter::Lanczos3,
+    ];
+
+    fn checker(width: u32, height: u32) -> ImageData {
+        let mut image = ImageDataMut::new(PixelFormat::Rgb24, width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let value = if (x + y) % 2 == 0 { 255 } else { 0 };
+                image.set_pixel(x, y, Color::rgb(value, value, value));
+            }
+        }
+        image.freeze()
+    }
+
+    fn bytes(color: Color) -> [u8; 4] {
+        color.cast::<u8>().to_array()
+    }
+
+    #[test]
+    fn filters() {
+        let image = checker(8, 6);
+        for filter in FILTERS.iter() {
+            // all filters except approximating Mitchell keep image of the same size
+            if *filter != ResizeFilter::Mitchell {
+                let same = image.resize(8, 6, *filter);
+                assert_eq!(&same.data[..], &image.data[..], "{:?}", filter);
+            }
+
+            let larger = image.resize(13, 9, *filter);
+            assert_eq!((larger.width, larger.height, larger.stride), (13, 9, 52));
+        }
+
+        // integer upscaling with nearest duplicates pixels
+        let doubled = image.resize(16, 12, ResizeFilter::Nearest);
+        for (x, y) in [(0, 0), (1, 1), (2, 0), (5, 3), (15, 11)].iter() {
+            assert_eq!(doubled.get_pixel(*x, *y), image.get_pixel(x / 2, y / 2));
+        }
+
+        // box downscaling averages covered pixels
+        let gray = image.resize(4, 3, ResizeFilter::Box);
+        assert!(gray
+            .pixels()
+            .all(|color| bytes(color) == [128, 128, 128, 255]));
+        let gray = image.resize(1, 1, ResizeFilter::Lanczos3);
+        assert_eq!(bytes(gray.get_pixel(0, 0)), [128, 128, 128, 255]);
+
+        // gamma correct average of black and white is lighter
+        let options = ResizeOptions::with_parameters(ResizeFilter::Box, true);
+        let light = image.resize_with(4, 3, &options);
+        assert_eq!(bytes(light.get_pixel(0, 0)), [188, 188, 188, 255]);
+
+        assert_eq!(image.resize(0, 3, ResizeFilter::Box).data.len(), 0);
+    }
+
+    #[test]
+    fn premultiplied_alpha() {
+        // opaque red next to transparent green must not turn brownish
+        let mut image = ImageDataMut::new(PixelFormat::ARgb32, 2, 1);
+        image.set_pixel(0, 0, Color::rgb(255, 0, 0));
+        image.set_pixel(1, 0, Color::new(0.0, 1.0, 0.0, 0.0));
+        let image = image.freeze();
+
+        for filter in FILTERS[1..].iter() {
+            let resized = image.resize(1, 1, *filter);
+            assert_eq!(
+                bytes(resized.get_pixel(0, 0)),
+                [255, 0, 0, 128],
+                "{:?}",
+                filter
+            );
+        }
+
+        let stretched = image.resize(5, 1, ResizeFilter::Bilinear);
+        for color in stretched.pixels().filter(|color| color.alpha > 0.5) {
+            assert_eq!([color.red, color.green], [1.0, 0.0]);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use super::{premultiply, unpremultiply, ImageData, ImageDataMut, PixelFormat, ResizeFilter};
+use crate::foundation::{colorspace::Color, raster::Transform};
+
+impl ImageData {
+    /// Build image of size where every pixel is copied from source pixel given by map.
+    ///
+    /// Pixels are copied as bytes, so the result is exact in every format.
+    /// Pixels missing in data stay zeroed.
+    fn remap<F>(&self, width: u32, height: u32, map: F) -> ImageData
+    where
+        F: Fn(u32, u32) -> (u32, u32),
+    {
+        if let PixelFormat::Invalid = self.format {
+            return ImageData::with_stride(self.format, width, height, 0, Default::default());
+        }
+        let mut target = ImageDataMut::new(self.format, width, height);
+        let size = self.format.bytes_per_pixel();
+        for y in 0..height {
+            for x in 0..width {
+                let (source_x, source_y) = map(x, y);
+                if size == 0 {
+                    if let Some(color) = self.try_get_pixel(source_x, source_y) {
+                        target.set_pixel(x, y, color);
+                    }
+                    continue;
+                }
+                let offset = source_y as usize * self.stride + source_x as usize * size;
+                if let Some(bytes) = self.data.get(offset..offset + size) {
+                    let offset = y as usize * target.stride + x as usize * size;
+                    target.data[offset..offset + size].copy_from_slice(bytes);
+                }
+            }
+        }
+        target.freeze()
+    }
+
+    /// Mirror image left to right
+    pub fn flip_horizontal(&self) -> ImageData {
+        let last = self.width.saturating_sub(1);
+        self.remap(self.width, self.height, |x, y| (last - x, y))
+    }
+
+    /// Mirror image top to bottom
+    pub fn flip_vertical(&self) -> ImageData {
+        let last = self.height.saturating_sub(1);
+        self.remap(self.width, self.height, |x, y| (x, last - y))
+    }
+
+    /// Rotate image clockwise by quarter turn
+    pub fn rotate90(&self) -> ImageData {
+        let last = self.height.saturating_sub(1);
+        self.remap(self.height, self.width, |x, y| (y, last - x))
+    }
+
+    /// Rotate image by half turn
+    pub fn rotate180(&self) -> ImageData {
+        let (last_x, last_y) = (self.width.saturating_sub(1), self.height.saturating_sub(1));
+        self.remap(self.width, self.height, |x, y| (last_x - x, last_y - y))
+    }
+
+    /// Rotate image clockwise by three quarter turns, which is counter-clockwise quarter turn
+    pub fn rotate270(&self) -> ImageData {
+        let last = self.width.saturating_sub(1);
+        self.remap(self.height, self.width, |x, y| (last - y, x))
+    }
+
+    /// Swap rows and columns, mirroring image along its main diagonal
+    pub fn transpose(&self) -> ImageData {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Map image with affine transformation into new image of size, the result has the same format.
+    ///
+    /// Transformation maps source coordinates to target ones, pixel centers lie at half pixels.
+    /// Target pixels are resampled with filter, which is widened when the image shrinks.
+    /// Area outside of source image is filled with background, and edges are blended into it.
+    pub fn warp(
+        &self,
+        transform: &Transform,
+        width: u32,
+        height: u32,
+        filter: ResizeFilter,
+        background: Color,
+    ) -> ImageData {
+        if let PixelFormat::Invalid = self.format {
+            return ImageData::with_stride(self.format, width, height, 0, Default::default());
+        }
+        let mut target = ImageDataMut::new(self.format, width, height);
+        let inverse = match transform.inverse() {
+            Some(inverse) => inverse,
+            None => {
+                target.fill(background);
+                return target.freeze();
+            }
+        };
+
+        let background = premultiply(background);
+        // footprint of target pixel along source axes, never wider than the source
+        let footprint = |length: f64, size: u32| (length as f32).min(size as f32).max(1.0);
+        let scale_x = footprint(inverse.a.hypot(inverse.c), self.width);
+        let scale_y = footprint(inverse.b.hypot(inverse.d), self.height);
+        let mut weights_x = Vec::new();
+        let mut weights_y = Vec::new();
+
+        for y in 0..height {
+            for x in 0..width {
+                let (u, v) = inverse.apply(x as f64 + 0.5, y as f64 + 0.5);
+                let color = if let ResizeFilter::Nearest = filter {
+                    self.sample(u.floor(), v.floor(), background)
+                } else {
+                    let start_x = taps(u as f32, filter, scale_x, &mut weights_x);
+                    let start_y = taps(v as f32, filter, scale_y, &mut weights_y);
+                    if weights_x.is_empty() || weights_y.is_empty() {
+                        self.sample(u.floor(), v.floor(), background)
+                    } else {
+                        let mut sum = [0.0; 4];
+                        for (row, weight_y) in weights_y.iter().enumerate() {
+                            for (column, weight_x) in weights_x.iter().enumerate() {
+                                let value = self.sample(
+                                    (start_x + column as i64) as f64,
+                                    (start_y + row as i64) as f64,
+                                    background,
+                                );
+                                for (sum, value) in sum.iter_mut().zip(value.iter()) {
+                                    *sum += value * weight_x * weight_y;
+                                }
+                            }
+                        }
+                        sum
+                    }
+                };
+                target.set_pixel(x, y, unpremultiply(color));
+            }
+        }
+
+        target.freeze()
+    }
+
+    /// Retrieve premultiplied color of pixel or background outside of image
+    fn sample(&self, x: f64, y: f64, background: [f32; 4]) -> [f32; 4] {
+        if x < 0.0 || y < 0.0 || !(x.is_finite() && y.is_finite()) {
+            return background;
+        }
+        match self.try_get_pixel(x as u32, y as u32) {
+            Some(color) => premultiply(color),
+            None => background,
+        }
+    }
+}
+
+/// Compute normalized filter weights of pixels around coordinate, returns the first pixel
+fn taps(center: f32, filter: ResizeFilter, scale: f32, weights: &mut Vec<f32>) -> i64 {
+    weights.clear();
+    if !center.is_finite() {
+        return 0;
+    }
+    let support = filter.support() * scale;
+    let start = (center - support - 0.5).ceil() as i64;
+    let end = (center + support - 0.5).floor() as i64;
+    weights.extend((start..=end).map(|pos| filter.weight((pos as f32 + 0.5 - center) / scale)));
+    let sum: f32 = weights.iter().sum();
+    if sum.abs() > f32::EPSILON {
+        weights.iter_mut().for_each(|weight| *weight /= sum);
+    } else {
+        weights.clear();
+    }
+    start
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Image with distinct red value in every pixel
+    fn numbered(format: PixelFormat, width: u32, height: u32) -> ImageData {
+        let mut image = ImageDataMut::new(format, width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let value = (y * width + x) as u8 * 20;
+                image.set_pixel(x, y, Color::rgb(value, 255 - value, 0));
+            }
+        }
+        image.freeze()
+    }
+
+    fn reds(image: &ImageData) -> Vec<u8> {
+        image
+            .pixels()
+            .map(|color| color.cast::<u8>().to_array()[0])
+            .collect()
+    }
+
+    #[test]
+    fn lossless() {
+        // 0 20 40
+        // 60 80 100
+        let image = numbered(PixelFormat::Rgb24, 3, 2);
+        assert_eq!(reds(&image.flip_horizontal()), [40, 20, 0, 100, 80, 60]);
+        assert_eq!(reds(&image.flip_vertical()), [60, 80, 100, 0, 20, 40]);
+        assert_eq!(reds(&image.rotate180()), [100, 80, 60, 40, 20, 0]);
+
+        let rotated = image.rotate90();
+        assert_eq!((rotated.width, rotated.height), (2, 3));
+        assert_eq!(reds(&rotated), [60, 0, 80, 20, 100, 40]);
+        assert_eq!(reds(&image.rotate270()), [40, 100, 20, 80, 0, 60]);
+        assert_eq!(reds(&image.transpose()), [0, 60, 20, 80, 40, 100]);
+        assert_eq!(
+            reds(&rotated.rotate90().rotate90().rotate90()),
+            reds(&image)
+        );
+
+        // packed bits
+        let mut image = ImageDataMut::new(PixelFormat::A1, 9, 2);
+        image.set_pixel(0, 0, Color::rgb(0, 0, 0));
+        image.set_pixel(8, 1, Color::rgb(0, 0, 0));
+        let image = image.freeze();
+        let alphas = |image: &ImageData| -> Vec<(u32, u32)> {
+            (0..image.height)
+                .flat_map(|y| (0..image.width).map(move |x| (x, y)))
+                .filter(|&(x, y)| image.get_pixel(x, y).alpha > 0.0)
+                .collect()
+        };
+        assert_eq!(alphas(&image.flip_horizontal()), [(8, 0), (0, 1)]);
+        assert_eq!(alphas(&image.rotate90()), [(1, 0), (0, 8)]);
+    }
+
+    #[test]
+    fn warps() {
+        let image = numbered(PixelFormat::ARgb32, 3, 2);
+        let background = Color::rgb(0, 0, 255);
+
+        let same = image.warp(
+            &Transform::IDENTITY,
+            3,
+            2,
+            ResizeFilter::Lanczos3,
+            background,
+        );
+        assert_eq!(reds(&same), reds(&image));
+
+        // quarter turn around origin, moved back into view
+        let transform = Transform::rotation(std::f64::consts::FRAC_PI_2)
+            .then(&Transform::translation(2.0, 0.0));
+        let rotated = image.warp(&transform, 2, 3, ResizeFilter::Nearest, background);
+        assert_eq!(reds(&rotated), reds(&image.rotate90()));
+
+        let shifted = image.warp(
+            &Transform::translation(1.0, 0.0),
+            3,
+            2,
+            ResizeFilter::Nearest,
+            background,
+        );
+        assert_eq!(
+            shifted.get_pixel(0, 0).cast::<u8>().to_array(),
+            [0, 0, 255, 255]
+        );
+        assert_eq!(reds(&shifted)[1..3], [0, 20]);
+
+        // bilinear upscaling interpolates between pixel centers
+        let scaled = image.warp(
+            &Transform::scaling(2.0, 1.0),
+            6,
+            2,
+            ResizeFilter::Bilinear,
+            background,
+        );
+        let row: Vec<u8> = reds(&scaled)[1..5].to_vec();
+        assert_eq!(row, [5, 15, 25, 35]);
+        // left edge blends into background
+        assert_eq!(scaled.get_pixel(0, 0).cast::<u8>().to_array()[2], 64);
+
+        let transparent = image.warp(
+            &Transform::scaling(0.0, 1.0),
+            2,
+            2,
+            ResizeFilter::Bilinear,
+            Color::rgba(0, 0, 0, 0),
+        );
+        assert!(transparent.pixels().all(|color| color.alpha == 0.0));
+    }
+}
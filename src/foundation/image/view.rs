@@ -0,0 +1,488 @@
+use std::ops::Range;
+
+use super::{pixel_offset, ImageData, ImageDataMut, PixelFormat};
+use crate::foundation::{
+    colorspace::{BlendMode, Color},
+    Point, Rect,
+};
+
+/// Borrowed rectangle of image pixels
+#[derive(Debug, Clone, Copy)]
+pub struct ImageView<'a> {
+    /// Image format
+    pub format: PixelFormat,
+    /// View width
+    pub width: u32,
+    /// View height
+    pub height: u32,
+    /// Count of bytes between starts of rows
+    pub stride: usize,
+    /// Image data starting at the first pixel of view
+    pub data: &'a [u8],
+    first_column: u32,
+}
+
+/// Mutably borrowed rectangle of image pixels
+#[derive(Debug)]
+pub struct ImageViewMut<'a> {
+    /// Image format
+    pub format: PixelFormat,
+    /// View width
+    pub width: u32,
+    /// View height
+    pub height: u32,
+    /// Count of bytes between starts of rows
+    pub stride: usize,
+    /// Image data starting at the first pixel of view
+    pub data: &'a mut [u8],
+    first_column: u32,
+}
+
+impl_pixel_read!(ImageView<'_>);
+impl_pixel_read!(ImageViewMut<'_>);
+impl_pixel_write!(ImageViewMut<'_>);
+
+/// Clip rectangle to image bounds, returns ranges of columns and rows
+fn clip(rect: Rect<u32>, width: u32, height: u32) -> (Range<u32>, Range<u32>) {
+    let x = rect.origin.x.min(width)..rect.origin.x.saturating_add(rect.size.width).min(width);
+    let y = rect.origin.y.min(height)..rect.origin.y.saturating_add(rect.size.height).min(height);
+    (x, y)
+}
+
+/// Retrieve byte offset and first column of sub view starting at pixel
+fn sub_view(format: PixelFormat, stride: usize, first_column: u32, x: u32, y: u32) -> (usize, u32) {
+    let column = first_column + x;
+    match format {
+        PixelFormat::A1 => (y as usize * stride + column as usize / 8, column % 8),
+        _ => (pixel_offset(format, stride, column, y), 0),
+    }
+}
+
+/// Clip copied span along one axis, returns source range and destination position of its start
+fn clip_axis(
+    source_len: u32,
+    start: u32,
+    len: u32,
+    target_len: u32,
+    position: i32,
+) -> Option<(Range<u32>, u32)> {
+    let start = start as i64;
+    // target position of source index `idx` is `position + idx - start`
+    let low = start.max(start - position as i64);
+    let high = (start + len as i64)
+        .min(source_len as i64)
+        .min(start - position as i64 + target_len as i64);
+    if low < high {
+        Some((
+            low as u32..high as u32,
+            (position as i64 + low - start) as u32,
+        ))
+    } else {
+        None
+    }
+}
+
+impl<'a> ImageView<'a> {
+    /// Create view of image data with explicit row stride
+    pub fn new(
+        format: PixelFormat,
+        width: u32,
+        height: u32,
+        stride: usize,
+        data: &'a [u8],
+    ) -> Self {
+        Self {
+            format,
+            width,
+            height,
+            stride,
+            data,
+            first_column: 0,
+        }
+    }
+
+    #[inline]
+    fn first_column(&self) -> u32 {
+        self.first_column
+    }
+
+    /// Retrieve view of rectangle, clipped to the view bounds
+    pub fn view(&self, rect: Rect<u32>) -> ImageView<'a> {
+        let (x, y) = clip(rect, self.width, self.height);
+        let (offset, first_column) = sub_view(
+            self.format,
+            self.stride,
+            self.first_column,
+            x.start,
+            y.start,
+        );
+        ImageView {
+            format: self.format,
+            width: x.end - x.start,
+            height: y.end - y.start,
+            stride: self.stride,
+            data: self.data.get(offset..).unwrap_or(&[]),
+            first_column,
+        }
+    }
+
+    /// Copy pixels into new image data with minimal stride
+    pub fn to_image_data(&self) -> ImageData {
+        let mut image = ImageDataMut::new(self.format, self.width, self.height);
+        image.copy_from(
+            self,
+            Rect::new(Point::new(0, 0), (self.width, self.height).into()),
+            Point::new(0, 0),
+        );
+        image.freeze()
+    }
+}
+
+impl<'a> ImageViewMut<'a> {
+    /// Create mutable view of image data with explicit row stride
+    pub fn new(
+        format: PixelFormat,
+        width: u32,
+        height: u32,
+        stride: usize,
+        data: &'a mut [u8],
+    ) -> Self {
+        Self {
+            format,
+            width,
+            height,
+            stride,
+            data,
+            first_column: 0,
+        }
+    }
+
+    #[inline]
+    fn first_column(&self) -> u32 {
+        self.first_column
+    }
+
+    /// Retrieve immutable view of all pixels
+    pub fn as_view(&self) -> ImageView<'_> {
+        ImageView {
+            format: self.format,
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+            data: self.data,
+            first_column: self.first_column,
+        }
+    }
+
+    /// Retrieve view of rectangle, clipped to the view bounds
+    pub fn view(&self, rect: Rect<u32>) -> ImageView<'_> {
+        self.as_view().view(rect)
+    }
+
+    /// Retrieve mutable view of rectangle, clipped to the view bounds
+    pub fn view_mut(&mut self, rect: Rect<u32>) -> ImageViewMut<'_> {
+        ImageViewMut {
+            format: self.format,
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+            data: &mut *self.data,
+            first_column: self.first_column,
+        }
+        .into_view(rect)
+    }
+
+    /// Convert into mutable view of rectangle, clipped to the view bounds
+    pub fn into_view(self, rect: Rect<u32>) -> ImageViewMut<'a> {
+        let (x, y) = clip(rect, self.width, self.height);
+        let (offset, first_column) = sub_view(
+            self.format,
+            self.stride,
+            self.first_column,
+            x.start,
+            y.start,
+        );
+        let offset = offset.min(self.data.len());
+        ImageViewMut {
+            format: self.format,
+            width: x.end - x.start,
+            height: y.end - y.start,
+            stride: self.stride,
+            data: &mut self.data[offset..],
+            first_column,
+        }
+    }
+
+    /// Fill rectangle with color, clipped to the view bounds, pixels are replaced without blending
+    pub fn fill_rect(&mut self, rect: Rect<u32>, color: Color) {
+        self.view_mut(rect).fill(color);
+    }
+
+    /// Copy rectangle of source to position of this view, pixels are converted to the view format.
+    ///
+    /// Source rectangle is clipped to the source bounds, copied pixels to the view bounds.
+    pub fn copy_from(
+        &mut self,
+        source: &ImageView<'_>,
+        source_rect: Rect<u32>,
+        position: Point<i32>,
+    ) {
+        let size = self.format.bytes_per_pixel();
+        if source.format != self.format || size == 0 {
+            self.blit(source, source_rect, position, |source, _| source);
+            return;
+        }
+
+        // same byte addressable format, copy spans of rows
+        let r = source_rect;
+        let columns = clip_axis(
+            source.width,
+            r.origin.x,
+            r.size.width,
+            self.width,
+            position.x,
+        );
+        let rows = clip_axis(
+            source.height,
+            r.origin.y,
+            r.size.height,
+            self.height,
+            position.y,
+        );
+        if let (Some((columns, target_x)), Some((rows, target_y))) = (columns, rows) {
+            let source_span = columns.start as usize * size..columns.end as usize * size;
+            let target_start = target_x as usize * size;
+            for (row, source_y) in rows.enumerate() {
+                let target = self.row_mut(target_y + row as u32);
+                if let (Some(src), Some(dst)) = (source.row(source_y), target) {
+                    if let (Some(src), Some(dst)) = (
+                        src.get(source_span.clone()),
+                        dst.get_mut(target_start..target_start + source_span.len()),
+                    ) {
+                        dst.copy_from_slice(src);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draw source on position of this view, compositing pixels with blend mode
+    pub fn draw_image(&mut self, source: &ImageView<'_>, position: Point<i32>, mode: BlendMode) {
+        let rect = Rect::new(Point::new(0, 0), (source.width, source.height).into());
+        self.blit(source, rect, position, |source, backdrop| {
+            source.blend(backdrop, mode)
+        });
+    }
+
+    fn blit<F>(&mut self, source: &ImageView<'_>, rect: Rect<u32>, position: Point<i32>, mix: F)
+    where
+        F: Fn(Color, Color) -> Color,
+    {
+        let columns = clip_axis(
+            source.width,
+            rect.origin.x,
+            rect.size.width,
+            self.width,
+            position.x,
+        );
+        let rows = clip_axis(
+            source.height,
+            rect.origin.y,
+            rect.size.height,
+            self.height,
+            position.y,
+        );
+        let ((columns, target_x), (rows, target_y)) = match (columns, rows) {
+            (Some(columns), Some(rows)) => (columns, rows),
+            _ => return,
+        };
+
+        for (row, source_y) in rows.enumerate() {
+            let y = target_y + row as u32;
+            for (column, source_x) in columns.clone().enumerate() {
+                let x = target_x + column as u32;
+                if let (Some(color), Some(backdrop)) = (
+                    source.try_get_pixel(source_x, source_y),
+                    self.try_get_pixel(x, y),
+                ) {
+                    self.try_set_pixel(x, y, mix(color, backdrop));
+                }
+            }
+        }
+    }
+}
+
+impl ImageData {
+    /// Retrieve view of all pixels
+    pub fn as_view(&self) -> ImageView<'_> {
+        ImageView::new(
+            self.format,
+            self.width,
+            self.height,
+            self.stride,
+            &self.data,
+        )
+    }
+
+    /// Retrieve view of rectangle, clipped to the image bounds
+    pub fn view(&self, rect: Rect<u32>) -> ImageView<'_> {
+        self.as_view().view(rect)
+    }
+
+    /// Copy rectangle, clipped to the image bounds, into new image data
+    pub fn crop(&self, rect: Rect<u32>) -> ImageData {
+        self.view(rect).to_image_data()
+    }
+}
+
+impl ImageDataMut {
+    /// Retrieve view of all pixels
+    pub fn as_view(&self) -> ImageView<'_> {
+        ImageView::new(
+            self.format,
+            self.width,
+            self.height,
+            self.stride,
+            &self.data,
+        )
+    }
+
+    /// Retrieve mutable view of all pixels
+    pub fn as_view_mut(&mut self) -> ImageViewMut<'_> {
+        ImageViewMut::new(
+            self.format,
+            self.width,
+            self.height,
+            self.stride,
+            &mut self.data,
+        )
+    }
+
+    /// Retrieve view of rectangle, clipped to the image bounds
+    pub fn view(&self, rect: Rect<u32>) -> ImageView<'_> {
+        self.as_view().view(rect)
+    }
+
+    /// Retrieve mutable view of rectangle, clipped to the image bounds
+    pub fn view_mut(&mut self, rect: Rect<u32>) -> ImageViewMut<'_> {
+        self.as_view_mut().into_view(rect)
+    }
+
+    /// Copy rectangle, clipped to the image bounds, into new image data
+    pub fn crop(&self, rect: Rect<u32>) -> ImageData {
+        self.view(rect).to_image_data()
+    }
+
+    /// Fill rectangle with color, see [ImageViewMut::fill_rect]
+    pub fn fill_rect(&mut self, rect: Rect<u32>, color: Color) {
+        self.as_view_mut().fill_rect(rect, color);
+    }
+
+    /// Copy rectangle of source to position, see [ImageViewMut::copy_from]
+    pub fn copy_from(
+        &mut self,
+        source: &ImageView<'_>,
+        source_rect: Rect<u32>,
+        position: Point<i32>,
+    ) {
+        self.as_view_mut().copy_from(source, source_rect, position);
+    }
+
+    /// Draw source on position with blend mode, see [ImageViewMut::draw_image]
+    pub fn draw_image(&mut self, source: &ImageView<'_>, position: Point<i32>, mode: BlendMode) {
+        self.as_view_mut().draw_image(source, position, mode);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rect(x: u32, y: u32, width: u32, height: u32) -> Rect<u32> {
+        Rect::new(Point::new(x, y), (width, height).into())
+    }
+
+    fn numbered(width: u32, height: u32) -> ImageData {
+        let mut image = ImageDataMut::new(PixelFormat::A8, width, height);
+        for (idx, byte) in image.rows_mut().flatten().enumerate() {
+            *byte = idx as u8;
+        }
+        image.freeze()
+    }
+
+    #[test]
+    fn views() {
+        let image = numbered(4, 4);
+        let view = image.view(rect(1, 2, 10, 10));
+        assert_eq!((view.width, view.height), (3, 2));
+        assert_eq!(view.row(0), Some(&[9, 10, 11][..]));
+
+        let nested = view.view(rect(1, 1, 1, 1));
+        assert_eq!(nested.get_pixel(0, 0), image.get_pixel(2, 3));
+        assert_eq!(nested.try_get_pixel(1, 0), None);
+
+        let crop = image.crop(rect(1, 1, 2, 2));
+        assert_eq!(crop.stride, 4);
+        assert_eq!(
+            crop.rows().collect::<Vec<_>>(),
+            vec![&[5, 6][..], &[9, 10][..]]
+        );
+
+        let empty = image.view(rect(5, 0, 2, 2));
+        assert_eq!((empty.width, empty.height), (0, 2));
+        assert_eq!(empty.pixels().count(), 0);
+    }
+
+    #[test]
+    fn bit_views() {
+        let mut image = ImageDataMut::new(PixelFormat::A1, 20, 2);
+        let mut view = image.view_mut(rect(5, 1, 10, 1));
+        view.fill(Color::rgb(0, 0, 0));
+        assert_eq!(view.get_pixel(9, 0).alpha, 1.0);
+
+        assert_eq!(image.row(0), Some(&[0, 0, 0][..]));
+        assert_eq!(image.row(1), Some(&[0b1110_0000, 0b0111_1111, 0][..]));
+
+        let crop = image.crop(rect(4, 1, 12, 1));
+        assert_eq!(crop.row(0), Some(&[0b1111_1110, 0b0111][..]));
+    }
+
+    #[test]
+    fn copy_with_clipping() {
+        let source = numbered(4, 4);
+        let mut target = ImageDataMut::new(PixelFormat::ARgb32, 3, 3);
+        target.fill(Color::rgb(255, 0, 0));
+
+        // source rectangle hangs out of source, position out of target
+        target.copy_from(&source.as_view(), rect(2, 2, 5, 5), Point::new(-1, 2));
+        let alpha: Vec<u8> = target
+            .pixels()
+            .map(|color| color.cast::<u8>().alpha)
+            .collect();
+        assert_eq!(alpha, vec![255, 255, 255, 255, 255, 255, 11, 255, 255]);
+
+        target.copy_from(&source.as_view(), rect(0, 0, 4, 4), Point::new(5, 0));
+        target.copy_from(&source.as_view(), rect(0, 0, 4, 4), Point::new(-4, 0));
+        assert_eq!(target.get_pixel(1, 2), Color::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn fill_and_draw() {
+        let mut target = ImageDataMut::new(PixelFormat::Rgb24, 4, 4);
+        target.fill_rect(rect(1, 1, 8, 2), Color::rgb(100, 100, 100));
+        assert_eq!(target.get_pixel(0, 1), Color::rgb(0, 0, 0));
+        assert_eq!(target.get_pixel(3, 2), Color::rgb(100, 100, 100));
+
+        let mut sprite = ImageDataMut::new(PixelFormat::ARgb32, 2, 2);
+        sprite.fill(Color::new(1.0, 1.0, 1.0, 0.5));
+        sprite.set_pixel(1, 1, Color::new(0.0, 0.0, 0.0, 0.0));
+        let sprite = sprite.freeze();
+
+        target.draw_image(&sprite.as_view(), Point::new(2, 2), BlendMode::Normal);
+        assert_eq!(target.get_pixel(2, 2), Color::rgb(178, 178, 178));
+        assert_eq!(target.get_pixel(3, 3), Color::rgb(0, 0, 0));
+
+        target.draw_image(&sprite.as_view(), Point::new(0, 0), BlendMode::Multiply);
+        assert_eq!(target.get_pixel(1, 1), Color::rgb(100, 100, 100));
+    }
+}
@@ -2,10 +2,15 @@
 
 pub mod colorschemes;
 pub mod colorspace;
+pub mod palettes;
+pub mod raster;
 
 mod angle;
 pub use angle::*;
 
+mod ansi;
+pub use ansi::*;
+
 mod box2d;
 pub use box2d::*;
 
@@ -27,6 +32,9 @@ pub use offset::*;
 // mod point3d;
 // pub use point3d::*;
 
+mod pixel;
+pub use pixel::*;
+
 mod rect;
 pub use rect::*;
 
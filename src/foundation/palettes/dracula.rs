@@ -0,0 +1,49 @@
+//! [Dracula](https://draculatheme.com/contribute) dark theme palette.
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::Color;
+
+pub const BACKGROUND: Color = Color::rgb(40, 42, 54);
+pub const CURRENT_LINE: Color = Color::rgb(68, 71, 90);
+pub const FOREGROUND: Color = Color::rgb(248, 248, 242);
+pub const COMMENT: Color = Color::rgb(98, 114, 164);
+pub const CYAN: Color = Color::rgb(139, 233, 253);
+pub const GREEN: Color = Color::rgb(80, 250, 123);
+pub const ORANGE: Color = Color::rgb(255, 184, 108);
+pub const PINK: Color = Color::rgb(255, 121, 198);
+pub const PURPLE: Color = Color::rgb(189, 147, 249);
+pub const RED: Color = Color::rgb(255, 85, 85);
+pub const YELLOW: Color = Color::rgb(241, 250, 140);
+
+const NAMES: [&str; 11] = [
+    "background",
+    "current-line",
+    "foreground",
+    "comment",
+    "cyan",
+    "green",
+    "orange",
+    "pink",
+    "purple",
+    "red",
+    "yellow",
+];
+
+const COLORS: [Color; 11] = [
+    BACKGROUND,
+    CURRENT_LINE,
+    FOREGROUND,
+    COMMENT,
+    CYAN,
+    GREEN,
+    ORANGE,
+    PINK,
+    PURPLE,
+    RED,
+    YELLOW,
+];
+
+/// Dracula palette with 11 colors
+pub const PALETTE: Palette = Palette::new("Dracula", "https://draculatheme.com/contribute", &NAMES, &COLORS);
@@ -0,0 +1,28 @@
+//! Four shades of green of the original Game Boy (DMG) screen, from the darkest to the lightest.
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::Color;
+
+pub const DARKEST: Color = Color::rgb(15, 56, 15);
+pub const DARK: Color = Color::rgb(48, 98, 48);
+pub const LIGHT: Color = Color::rgb(139, 172, 15);
+pub const LIGHTEST: Color = Color::rgb(155, 188, 15);
+
+const NAMES: [&str; 4] = [
+    "darkest",
+    "dark",
+    "light",
+    "lightest",
+];
+
+const COLORS: [Color; 4] = [
+    DARKEST,
+    DARK,
+    LIGHT,
+    LIGHTEST,
+];
+
+/// Game Boy palette with 4 shades of green
+pub const PALETTE: Palette = Palette::new("Game Boy", "https://en.wikipedia.org/wiki/Game_Boy", &NAMES, &COLORS);
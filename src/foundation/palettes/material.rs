@@ -0,0 +1,786 @@
+//! [Material Design 2](https://m2.material.io/design/color/the-color-system.html) color palette.
+//!
+//! Shades go from `50` to `900`, most hues have accent shades `A100` to `A700`.
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::Color;
+
+pub const RED_50: Color = Color::rgb(255, 235, 238);
+pub const RED_100: Color = Color::rgb(255, 205, 210);
+pub const RED_200: Color = Color::rgb(239, 154, 154);
+pub const RED_300: Color = Color::rgb(229, 115, 115);
+pub const RED_400: Color = Color::rgb(239, 83, 80);
+pub const RED_500: Color = Color::rgb(244, 67, 54);
+pub const RED_600: Color = Color::rgb(229, 57, 53);
+pub const RED_700: Color = Color::rgb(211, 47, 47);
+pub const RED_800: Color = Color::rgb(198, 40, 40);
+pub const RED_900: Color = Color::rgb(183, 28, 28);
+pub const RED_A100: Color = Color::rgb(255, 138, 128);
+pub const RED_A200: Color = Color::rgb(255, 82, 82);
+pub const RED_A400: Color = Color::rgb(255, 23, 68);
+pub const RED_A700: Color = Color::rgb(213, 0, 0);
+pub const PINK_50: Color = Color::rgb(252, 228, 236);
+pub const PINK_100: Color = Color::rgb(248, 187, 208);
+pub const PINK_200: Color = Color::rgb(244, 143, 177);
+pub const PINK_300: Color = Color::rgb(240, 98, 146);
+pub const PINK_400: Color = Color::rgb(236, 64, 122);
+pub const PINK_500: Color = Color::rgb(233, 30, 99);
+pub const PINK_600: Color = Color::rgb(216, 27, 96);
+pub const PINK_700: Color = Color::rgb(194, 24, 91);
+pub const PINK_800: Color = Color::rgb(173, 20, 87);
+pub const PINK_900: Color = Color::rgb(136, 14, 79);
+pub const PINK_A100: Color = Color::rgb(255, 128, 171);
+pub const PINK_A200: Color = Color::rgb(255, 64, 129);
+pub const PINK_A400: Color = Color::rgb(245, 0, 87);
+pub const PINK_A700: Color = Color::rgb(197, 17, 98);
+pub const PURPLE_50: Color = Color::rgb(243, 229, 245);
+pub const PURPLE_100: Color = Color::rgb(225, 190, 231);
+pub const PURPLE_200: Color = Color::rgb(206, 147, 216);
+pub const PURPLE_300: Color = Color::rgb(186, 104, 200);
+pub const PURPLE_400: Color = Color::rgb(171, 71, 188);
+pub const PURPLE_500: Color = Color::rgb(156, 39, 176);
+pub const PURPLE_600: Color = Color::rgb(142, 36, 170);
+pub const PURPLE_700: Color = Color::rgb(123, 31, 162);
+pub const PURPLE_800: Color = Color::rgb(106, 27, 154);
+pub const PURPLE_900: Color = Color::rgb(74, 20, 140);
+pub const PURPLE_A100: Color = Color::rgb(234, 128, 252);
+pub const PURPLE_A200: Color = Color::rgb(224, 64, 251);
+pub const PURPLE_A400: Color = Color::rgb(213, 0, 249);
+pub const PURPLE_A700: Color = Color::rgb(170, 0, 255);
+pub const DEEP_PURPLE_50: Color = Color::rgb(237, 231, 246);
+pub const DEEP_PURPLE_100: Color = Color::rgb(209, 196, 233);
+pub const DEEP_PURPLE_200: Color = Color::rgb(179, 157, 219);
+pub const DEEP_PURPLE_300: Color = Color::rgb(149, 117, 205);
+pub const DEEP_PURPLE_400: Color = Color::rgb(126, 87, 194);
+pub const DEEP_PURPLE_500: Color = Color::rgb(103, 58, 183);
+pub const DEEP_PURPLE_600: Color = Color::rgb(94, 53, 177);
+pub const DEEP_PURPLE_700: Color = Color::rgb(81, 45, 168);
+pub const DEEP_PURPLE_800: Color = Color::rgb(69, 39, 160);
+pub const DEEP_PURPLE_900: Color = Color::rgb(49, 27, 146);
+pub const DEEP_PURPLE_A100: Color = Color::rgb(179, 136, 255);
+pub const DEEP_PURPLE_A200: Color = Color::rgb(124, 77, 255);
+pub const DEEP_PURPLE_A400: Color = Color::rgb(101, 31, 255);
+pub const DEEP_PURPLE_A700: Color = Color::rgb(98, 0, 234);
+pub const INDIGO_50: Color = Color::rgb(232, 234, 246);
+pub const INDIGO_100: Color = Color::rgb(197, 202, 233);
+pub const INDIGO_200: Color = Color::rgb(159, 168, 218);
+pub const INDIGO_300: Color = Color::rgb(121, 134, 203);
+pub const INDIGO_400: Color = Color::rgb(92, 107, 192);
+pub const INDIGO_500: Color = Color::rgb(63, 81, 181);
+pub const INDIGO_600: Color = Color::rgb(57, 73, 171);
+pub const INDIGO_700: Color = Color::rgb(48, 63, 159);
+pub const INDIGO_800: Color = Color::rgb(40, 53, 147);
+pub const INDIGO_900: Color = Color::rgb(26, 35, 126);
+pub const INDIGO_A100: Color = Color::rgb(140, 158, 255);
+pub const INDIGO_A200: Color = Color::rgb(83, 109, 254);
+pub const INDIGO_A400: Color = Color::rgb(61, 90, 254);
+pub const INDIGO_A700: Color = Color::rgb(48, 79, 254);
+pub const BLUE_50: Color = Color::rgb(227, 242, 253);
+pub const BLUE_100: Color = Color::rgb(187, 222, 251);
+pub const BLUE_200: Color = Color::rgb(144, 202, 249);
+pub const BLUE_300: Color = Color::rgb(100, 181, 246);
+pub const BLUE_400: Color = Color::rgb(66, 165, 245);
+pub const BLUE_500: Color = Color::rgb(33, 150, 243);
+pub const BLUE_600: Color = Color::rgb(30, 136, 229);
+pub const BLUE_700: Color = Color::rgb(25, 118, 210);
+pub const BLUE_800: Color = Color::rgb(21, 101, 192);
+pub const BLUE_900: Color = Color::rgb(13, 71, 161);
+pub const BLUE_A100: Color = Color::rgb(130, 177, 255);
+pub const BLUE_A200: Color = Color::rgb(68, 138, 255);
+pub const BLUE_A400: Color = Color::rgb(41, 121, 255);
+pub const BLUE_A700: Color = Color::rgb(41, 98, 255);
+pub const LIGHT_BLUE_50: Color = Color::rgb(225, 245, 254);
+pub const LIGHT_BLUE_100: Color = Color::rgb(179, 229, 252);
+pub const LIGHT_BLUE_200: Color = Color::rgb(129, 212, 250);
+pub const LIGHT_BLUE_300: Color = Color::rgb(79, 195, 247);
+pub const LIGHT_BLUE_400: Color = Color::rgb(41, 182, 246);
+pub const LIGHT_BLUE_500: Color = Color::rgb(3, 169, 244);
+pub const LIGHT_BLUE_600: Color = Color::rgb(3, 155, 229);
+pub const LIGHT_BLUE_700: Color = Color::rgb(2, 136, 209);
+pub const LIGHT_BLUE_800: Color = Color::rgb(2, 119, 189);
+pub const LIGHT_BLUE_900: Color = Color::rgb(1, 87, 155);
+pub const LIGHT_BLUE_A100: Color = Color::rgb(128, 216, 255);
+pub const LIGHT_BLUE_A200: Color = Color::rgb(64, 196, 255);
+pub const LIGHT_BLUE_A400: Color = Color::rgb(0, 176, 255);
+pub const LIGHT_BLUE_A700: Color = Color::rgb(0, 145, 234);
+pub const CYAN_50: Color = Color::rgb(224, 247, 250);
+pub const CYAN_100: Color = Color::rgb(178, 235, 242);
+pub const CYAN_200: Color = Color::rgb(128, 222, 234);
+pub const CYAN_300: Color = Color::rgb(77, 208, 225);
+pub const CYAN_400: Color = Color::rgb(38, 198, 218);
+pub const CYAN_500: Color = Color::rgb(0, 188, 212);
+pub const CYAN_600: Color = Color::rgb(0, 172, 193);
+pub const CYAN_700: Color = Color::rgb(0, 151, 167);
+pub const CYAN_800: Color = Color::rgb(0, 131, 143);
+pub const CYAN_900: Color = Color::rgb(0, 96, 100);
+pub const CYAN_A100: Color = Color::rgb(132, 255, 255);
+pub const CYAN_A200: Color = Color::rgb(24, 255, 255);
+pub const CYAN_A400: Color = Color::rgb(0, 229, 255);
+pub const CYAN_A700: Color = Color::rgb(0, 184, 212);
+pub const TEAL_50: Color = Color::rgb(224, 242, 241);
+pub const TEAL_100: Color = Color::rgb(178, 223, 219);
+pub const TEAL_200: Color = Color::rgb(128, 203, 196);
+pub const TEAL_300: Color = Color::rgb(77, 182, 172);
+pub const TEAL_400: Color = Color::rgb(38, 166, 154);
+pub const TEAL_500: Color = Color::rgb(0, 150, 136);
+pub const TEAL_600: Color = Color::rgb(0, 137, 123);
+pub const TEAL_700: Color = Color::rgb(0, 121, 107);
+pub const TEAL_800: Color = Color::rgb(0, 105, 92);
+pub const TEAL_900: Color = Color::rgb(0, 77, 64);
+pub const TEAL_A100: Color = Color::rgb(167, 255, 235);
+pub const TEAL_A200: Color = Color::rgb(100, 255, 218);
+pub const TEAL_A400: Color = Color::rgb(29, 233, 182);
+pub const TEAL_A700: Color = Color::rgb(0, 191, 165);
+pub const GREEN_50: Color = Color::rgb(232, 245, 233);
+pub const GREEN_100: Color = Color::rgb(200, 230, 201);
+pub const GREEN_200: Color = Color::rgb(165, 214, 167);
+pub const GREEN_300: Color = Color::rgb(129, 199, 132);
+pub const GREEN_400: Color = Color::rgb(102, 187, 106);
+pub const GREEN_500: Color = Color::rgb(76, 175, 80);
+pub const GREEN_600: Color = Color::rgb(67, 160, 71);
+pub const GREEN_700: Color = Color::rgb(56, 142, 60);
+pub const GREEN_800: Color = Color::rgb(46, 125, 50);
+pub const GREEN_900: Color = Color::rgb(27, 94, 32);
+pub const GREEN_A100: Color = Color::rgb(185, 246, 202);
+pub const GREEN_A200: Color = Color::rgb(105, 240, 174);
+pub const GREEN_A400: Color = Color::rgb(0, 230, 118);
+pub const GREEN_A700: Color = Color::rgb(0, 200, 83);
+pub const LIGHT_GREEN_50: Color = Color::rgb(241, 248, 233);
+pub const LIGHT_GREEN_100: Color = Color::rgb(220, 237, 200);
+pub const LIGHT_GREEN_200: Color = Color::rgb(197, 225, 165);
+pub const LIGHT_GREEN_300: Color = Color::rgb(174, 213, 129);
+pub const LIGHT_GREEN_400: Color = Color::rgb(156, 204, 101);
+pub const LIGHT_GREEN_500: Color = Color::rgb(139, 195, 74);
+pub const LIGHT_GREEN_600: Color = Color::rgb(124, 179, 66);
+pub const LIGHT_GREEN_700: Color = Color::rgb(104, 159, 56);
+pub const LIGHT_GREEN_800: Color = Color::rgb(85, 139, 47);
+pub const LIGHT_GREEN_900: Color = Color::rgb(51, 105, 30);
+pub const LIGHT_GREEN_A100: Color = Color::rgb(204, 255, 144);
+pub const LIGHT_GREEN_A200: Color = Color::rgb(178, 255, 89);
+pub const LIGHT_GREEN_A400: Color = Color::rgb(118, 255, 3);
+pub const LIGHT_GREEN_A700: Color = Color::rgb(100, 221, 23);
+pub const LIME_50: Color = Color::rgb(249, 251, 231);
+pub const LIME_100: Color = Color::rgb(240, 244, 195);
+pub const LIME_200: Color = Color::rgb(230, 238, 156);
+pub const LIME_300: Color = Color::rgb(220, 231, 117);
+pub const LIME_400: Color = Color::rgb(212, 225, 87);
+pub const LIME_500: Color = Color::rgb(205, 220, 57);
+pub const LIME_600: Color = Color::rgb(192, 202, 51);
+pub const LIME_700: Color = Color::rgb(175, 180, 43);
+pub const LIME_800: Color = Color::rgb(158, 157, 36);
+pub const LIME_900: Color = Color::rgb(130, 119, 23);
+pub const LIME_A100: Color = Color::rgb(244, 255, 129);
+pub const LIME_A200: Color = Color::rgb(238, 255, 65);
+pub const LIME_A400: Color = Color::rgb(198, 255, 0);
+pub const LIME_A700: Color = Color::rgb(174, 234, 0);
+pub const YELLOW_50: Color = Color::rgb(255, 253, 231);
+pub const YELLOW_100: Color = Color::rgb(255, 249, 196);
+pub const YELLOW_200: Color = Color::rgb(255, 245, 157);
+pub const YELLOW_300: Color = Color::rgb(255, 241, 118);
+pub const YELLOW_400: Color = Color::rgb(255, 238, 88);
+pub const YELLOW_500: Color = Color::rgb(255, 235, 59);
+pub const YELLOW_600: Color = Color::rgb(253, 216, 53);
+pub const YELLOW_700: Color = Color::rgb(251, 192, 45);
+pub const YELLOW_800: Color = Color::rgb(249, 168, 37);
+pub const YELLOW_900: Color = Color::rgb(245, 127, 23);
+pub const YELLOW_A100: Color = Color::rgb(255, 255, 141);
+pub const YELLOW_A200: Color = Color::rgb(255, 255, 0);
+pub const YELLOW_A400: Color = Color::rgb(255, 234, 0);
+pub const YELLOW_A700: Color = Color::rgb(255, 214, 0);
+pub const AMBER_50: Color = Color::rgb(255, 248, 225);
+pub const AMBER_100: Color = Color::rgb(255, 236, 179);
+pub const AMBER_200: Color = Color::rgb(255, 224, 130);
+pub const AMBER_300: Color = Color::rgb(255, 213, 79);
+pub const AMBER_400: Color = Color::rgb(255, 202, 40);
+pub const AMBER_500: Color = Color::rgb(255, 193, 7);
+pub const AMBER_600: Color = Color::rgb(255, 179, 0);
+pub const AMBER_700: Color = Color::rgb(255, 160, 0);
+pub const AMBER_800: Color = Color::rgb(255, 143, 0);
+pub const AMBER_900: Color = Color::rgb(255, 111, 0);
+pub const AMBER_A100: Color = Color::rgb(255, 229, 127);
+pub const AMBER_A200: Color = Color::rgb(255, 215, 64);
+pub const AMBER_A400: Color = Color::rgb(255, 196, 0);
+pub const AMBER_A700: Color = Color::rgb(255, 171, 0);
+pub const ORANGE_50: Color = Color::rgb(255, 243, 224);
+pub const ORANGE_100: Color = Color::rgb(255, 224, 178);
+pub const ORANGE_200: Color = Color::rgb(255, 204, 128);
+pub const ORANGE_300: Color = Color::rgb(255, 183, 77);
+pub const ORANGE_400: Color = Color::rgb(255, 167, 38);
+pub const ORANGE_500: Color = Color::rgb(255, 152, 0);
+pub const ORANGE_600: Color = Color::rgb(251, 140, 0);
+pub const ORANGE_700: Color = Color::rgb(245, 124, 0);
+pub const ORANGE_800: Color = Color::rgb(239, 108, 0);
+pub const ORANGE_900: Color = Color::rgb(230, 81, 0);
+pub const ORANGE_A100: Color = Color::rgb(255, 209, 128);
+pub const ORANGE_A200: Color = Color::rgb(255, 171, 64);
+pub const ORANGE_A400: Color = Color::rgb(255, 145, 0);
+pub const ORANGE_A700: Color = Color::rgb(255, 109, 0);
+pub const DEEP_ORANGE_50: Color = Color::rgb(251, 233, 231);
+pub const DEEP_ORANGE_100: Color = Color::rgb(255, 204, 188);
+pub const DEEP_ORANGE_200: Color = Color::rgb(255, 171, 145);
+pub const DEEP_ORANGE_300: Color = Color::rgb(255, 138, 101);
+pub const DEEP_ORANGE_400: Color = Color::rgb(255, 112, 67);
+pub const DEEP_ORANGE_500: Color = Color::rgb(255, 87, 34);
+pub const DEEP_ORANGE_600: Color = Color::rgb(244, 81, 30);
+pub const DEEP_ORANGE_700: Color = Color::rgb(230, 74, 25);
+pub const DEEP_ORANGE_800: Color = Color::rgb(216, 67, 21);
+pub const DEEP_ORANGE_900: Color = Color::rgb(191, 54, 12);
+pub const DEEP_ORANGE_A100: Color = Color::rgb(255, 158, 128);
+pub const DEEP_ORANGE_A200: Color = Color::rgb(255, 110, 64);
+pub const DEEP_ORANGE_A400: Color = Color::rgb(255, 61, 0);
+pub const DEEP_ORANGE_A700: Color = Color::rgb(221, 44, 0);
+pub const BROWN_50: Color = Color::rgb(239, 235, 233);
+pub const BROWN_100: Color = Color::rgb(215, 204, 200);
+pub const BROWN_200: Color = Color::rgb(188, 170, 164);
+pub const BROWN_300: Color = Color::rgb(161, 136, 127);
+pub const BROWN_400: Color = Color::rgb(141, 110, 99);
+pub const BROWN_500: Color = Color::rgb(121, 85, 72);
+pub const BROWN_600: Color = Color::rgb(109, 76, 65);
+pub const BROWN_700: Color = Color::rgb(93, 64, 55);
+pub const BROWN_800: Color = Color::rgb(78, 52, 46);
+pub const BROWN_900: Color = Color::rgb(62, 39, 35);
+pub const GREY_50: Color = Color::rgb(250, 250, 250);
+pub const GREY_100: Color = Color::rgb(245, 245, 245);
+pub const GREY_200: Color = Color::rgb(238, 238, 238);
+pub const GREY_300: Color = Color::rgb(224, 224, 224);
+pub const GREY_400: Color = Color::rgb(189, 189, 189);
+pub const GREY_500: Color = Color::rgb(158, 158, 158);
+pub const GREY_600: Color = Color::rgb(117, 117, 117);
+pub const GREY_700: Color = Color::rgb(97, 97, 97);
+pub const GREY_800: Color = Color::rgb(66, 66, 66);
+pub const GREY_900: Color = Color::rgb(33, 33, 33);
+pub const BLUE_GREY_50: Color = Color::rgb(236, 239, 241);
+pub const BLUE_GREY_100: Color = Color::rgb(207, 216, 220);
+pub const BLUE_GREY_200: Color = Color::rgb(176, 190, 197);
+pub const BLUE_GREY_300: Color = Color::rgb(144, 164, 174);
+pub const BLUE_GREY_400: Color = Color::rgb(120, 144, 156);
+pub const BLUE_GREY_500: Color = Color::rgb(96, 125, 139);
+pub const BLUE_GREY_600: Color = Color::rgb(84, 110, 122);
+pub const BLUE_GREY_700: Color = Color::rgb(69, 90, 100);
+pub const BLUE_GREY_800: Color = Color::rgb(55, 71, 79);
+pub const BLUE_GREY_900: Color = Color::rgb(38, 50, 56);
+pub const BLACK: Color = Color::rgb(0, 0, 0);
+pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+const NAMES: [&str; 256] = [
+    "red-50",
+    "red-100",
+    "red-200",
+    "red-300",
+    "red-400",
+    "red-500",
+    "red-600",
+    "red-700",
+    "red-800",
+    "red-900",
+    "red-a100",
+    "red-a200",
+    "red-a400",
+    "red-a700",
+    "pink-50",
+    "pink-100",
+    "pink-200",
+    "pink-300",
+    "pink-400",
+    "pink-500",
+    "pink-600",
+    "pink-700",
+    "pink-800",
+    "pink-900",
+    "pink-a100",
+    "pink-a200",
+    "pink-a400",
+    "pink-a700",
+    "purple-50",
+    "purple-100",
+    "purple-200",
+    "purple-300",
+    "purple-400",
+    "purple-500",
+    "purple-600",
+    "purple-700",
+    "purple-800",
+    "purple-900",
+    "purple-a100",
+    "purple-a200",
+    "purple-a400",
+    "purple-a700",
+    "deep-purple-50",
+    "deep-purple-100",
+    "deep-purple-200",
+    "deep-purple-300",
+    "deep-purple-400",
+    "deep-purple-500",
+    "deep-purple-600",
+    "deep-purple-700",
+    "deep-purple-800",
+    "deep-purple-900",
+    "deep-purple-a100",
+    "deep-purple-a200",
+    "deep-purple-a400",
+    "deep-purple-a700",
+    "indigo-50",
+    "indigo-100",
+    "indigo-200",
+    "indigo-300",
+    "indigo-400",
+    "indigo-500",
+    "indigo-600",
+    "indigo-700",
+    "indigo-800",
+    "indigo-900",
+    "indigo-a100",
+    "indigo-a200",
+    "indigo-a400",
+    "indigo-a700",
+    "blue-50",
+    "blue-100",
+    "blue-200",
+    "blue-300",
+    "blue-400",
+    "blue-500",
+    "blue-600",
+    "blue-700",
+    "blue-800",
+    "blue-900",
+    "blue-a100",
+    "blue-a200",
+    "blue-a400",
+    "blue-a700",
+    "light-blue-50",
+    "light-blue-100",
+    "light-blue-200",
+    "light-blue-300",
+    "light-blue-400",
+    "light-blue-500",
+    "light-blue-600",
+    "light-blue-700",
+    "light-blue-800",
+    "light-blue-900",
+    "light-blue-a100",
+    "light-blue-a200",
+    "light-blue-a400",
+    "light-blue-a700",
+    "cyan-50",
+    "cyan-100",
+    "cyan-200",
+    "cyan-300",
+    "cyan-400",
+    "cyan-500",
+    "cyan-600",
+    "cyan-700",
+    "cyan-800",
+    "cyan-900",
+    "cyan-a100",
+    "cyan-a200",
+    "cyan-a400",
+    "cyan-a700",
+    "teal-50",
+    "teal-100",
+    "teal-200",
+    "teal-300",
+    "teal-400",
+    "teal-500",
+    "teal-600",
+    "teal-700",
+    "teal-800",
+    "teal-900",
+    "teal-a100",
+    "teal-a200",
+    "teal-a400",
+    "teal-a700",
+    "green-50",
+    "green-100",
+    "green-200",
+    "green-300",
+    "green-400",
+    "green-500",
+    "green-600",
+    "green-700",
+    "green-800",
+    "green-900",
+    "green-a100",
+    "green-a200",
+    "green-a400",
+    "green-a700",
+    "light-green-50",
+    "light-green-100",
+    "light-green-200",
+    "light-green-300",
+    "light-green-400",
+    "light-green-500",
+    "light-green-600",
+    "light-green-700",
+    "light-green-800",
+    "light-green-900",
+    "light-green-a100",
+    "light-green-a200",
+    "light-green-a400",
+    "light-green-a700",
+    "lime-50",
+    "lime-100",
+    "lime-200",
+    "lime-300",
+    "lime-400",
+    "lime-500",
+    "lime-600",
+    "lime-700",
+    "lime-800",
+    "lime-900",
+    "lime-a100",
+    "lime-a200",
+    "lime-a400",
+    "lime-a700",
+    "yellow-50",
+    "yellow-100",
+    "yellow-200",
+    "yellow-300",
+    "yellow-400",
+    "yellow-500",
+    "yellow-600",
+    "yellow-700",
+    "yellow-800",
+    "yellow-900",
+    "yellow-a100",
+    "yellow-a200",
+    "yellow-a400",
+    "yellow-a700",
+    "amber-50",
+    "amber-100",
+    "amber-200",
+    "amber-300",
+    "amber-400",
+    "amber-500",
+    "amber-600",
+    "amber-700",
+    "amber-800",
+    "amber-900",
+    "amber-a100",
+    "amber-a200",
+    "amber-a400",
+    "amber-a700",
+    "orange-50",
+    "orange-100",
+    "orange-200",
+    "orange-300",
+    "orange-400",
+    "orange-500",
+    "orange-600",
+    "orange-700",
+    "orange-800",
+    "orange-900",
+    "orange-a100",
+    "orange-a200",
+    "orange-a400",
+    "orange-a700",
+    "deep-orange-50",
+    "deep-orange-100",
+    "deep-orange-200",
+    "deep-orange-300",
+    "deep-orange-400",
+    "deep-orange-500",
+    "deep-orange-600",
+    "deep-orange-700",
+    "deep-orange-800",
+    "deep-orange-900",
+    "deep-orange-a100",
+    "deep-orange-a200",
+    "deep-orange-a400",
+    "deep-orange-a700",
+    "brown-50",
+    "brown-100",
+    "brown-200",
+    "brown-300",
+    "brown-400",
+    "brown-500",
+    "brown-600",
+    "brown-700",
+    "brown-800",
+    "brown-900",
+    "grey-50",
+    "grey-100",
+    "grey-200",
+    "grey-300",
+    "grey-400",
+    "grey-500",
+    "grey-600",
+    "grey-700",
+    "grey-800",
+    "grey-900",
+    "blue-grey-50",
+    "blue-grey-100",
+    "blue-grey-200",
+    "blue-grey-300",
+    "blue-grey-400",
+    "blue-grey-500",
+    "blue-grey-600",
+    "blue-grey-700",
+    "blue-grey-800",
+    "blue-grey-900",
+    "black",
+    "white",
+];
+
+const COLORS: [Color; 256] = [
+    RED_50,
+    RED_100,
+    RED_200,
+    RED_300,
+    RED_400,
+    RED_500,
+    RED_600,
+    RED_700,
+    RED_800,
+    RED_900,
+    RED_A100,
+    RED_A200,
+    RED_A400,
+    RED_A700,
+    PINK_50,
+    PINK_100,
+    PINK_200,
+    PINK_300,
+    PINK_400,
+    PINK_500,
+    PINK_600,
+    PINK_700,
+    PINK_800,
+    PINK_900,
+    PINK_A100,
+    PINK_A200,
+    PINK_A400,
+    PINK_A700,
+    PURPLE_50,
+    PURPLE_100,
+    PURPLE_200,
+    PURPLE_300,
+    PURPLE_400,
+    PURPLE_500,
+    PURPLE_600,
+    PURPLE_700,
+    PURPLE_800,
+    PURPLE_900,
+    PURPLE_A100,
+    PURPLE_A200,
+    PURPLE_A400,
+    PURPLE_A700,
+    DEEP_PURPLE_50,
+    DEEP_PURPLE_100,
+    DEEP_PURPLE_200,
+    DEEP_PURPLE_300,
+    DEEP_PURPLE_400,
+    DEEP_PURPLE_500,
+    DEEP_PURPLE_600,
+    DEEP_PURPLE_700,
+    DEEP_PURPLE_800,
+    DEEP_PURPLE_900,
+    DEEP_PURPLE_A100,
+    DEEP_PURPLE_A200,
+    DEEP_PURPLE_A400,
+    DEEP_PURPLE_A700,
+    INDIGO_50,
+    INDIGO_100,
+    INDIGO_200,
+    INDIGO_300,
+    INDIGO_400,
+    INDIGO_500,
+    INDIGO_600,
+    INDIGO_700,
+    INDIGO_800,
+    INDIGO_900,
+    INDIGO_A100,
+    INDIGO_A200,
+    INDIGO_A400,
+    INDIGO_A700,
+    BLUE_50,
+    BLUE_100,
+    BLUE_200,
+    BLUE_300,
+    BLUE_400,
+    BLUE_500,
+    BLUE_600,
+    BLUE_700,
+    BLUE_800,
+    BLUE_900,
+    BLUE_A100,
+    BLUE_A200,
+    BLUE_A400,
+    BLUE_A700,
+    LIGHT_BLUE_50,
+    LIGHT_BLUE_100,
+    LIGHT_BLUE_200,
+    LIGHT_BLUE_300,
+    LIGHT_BLUE_400,
+    LIGHT_BLUE_500,
+    LIGHT_BLUE_600,
+    LIGHT_BLUE_700,
+    LIGHT_BLUE_800,
+    LIGHT_BLUE_900,
+    LIGHT_BLUE_A100,
+    LIGHT_BLUE_A200,
+    LIGHT_BLUE_A400,
+    LIGHT_BLUE_A700,
+    CYAN_50,
+    CYAN_100,
+    CYAN_200,
+    CYAN_300,
+    CYAN_400,
+    CYAN_500,
+    CYAN_600,
+    CYAN_700,
+    CYAN_800,
+    CYAN_900,
+    CYAN_A100,
+    CYAN_A200,
+    CYAN_A400,
+    CYAN_A700,
+    TEAL_50,
+    TEAL_100,
+    TEAL_200,
+    TEAL_300,
+    TEAL_400,
+    TEAL_500,
+    TEAL_600,
+    TEAL_700,
+    TEAL_800,
+    TEAL_900,
+    TEAL_A100,
+    TEAL_A200,
+    TEAL_A400,
+    TEAL_A700,
+    GREEN_50,
+    GREEN_100,
+    GREEN_200,
+    GREEN_300,
+    GREEN_400,
+    GREEN_500,
+    GREEN_600,
+    GREEN_700,
+    GREEN_800,
+    GREEN_900,
+    GREEN_A100,
+    GREEN_A200,
+    GREEN_A400,
+    GREEN_A700,
+    LIGHT_GREEN_50,
+    LIGHT_GREEN_100,
+    LIGHT_GREEN_200,
+    LIGHT_GREEN_300,
+    LIGHT_GREEN_400,
+    LIGHT_GREEN_500,
+    LIGHT_GREEN_600,
+    LIGHT_GREEN_700,
+    LIGHT_GREEN_800,
+    LIGHT_GREEN_900,
+    LIGHT_GREEN_A100,
+    LIGHT_GREEN_A200,
+    LIGHT_GREEN_A400,
+    LIGHT_GREEN_A700,
+    LIME_50,
+    LIME_100,
+    LIME_200,
+    LIME_300,
+    LIME_400,
+    LIME_500,
+    LIME_600,
+    LIME_700,
+    LIME_800,
+    LIME_900,
+    LIME_A100,
+    LIME_A200,
+    LIME_A400,
+    LIME_A700,
+    YELLOW_50,
+    YELLOW_100,
+    YELLOW_200,
+    YELLOW_300,
+    YELLOW_400,
+    YELLOW_500,
+    YELLOW_600,
+    YELLOW_700,
+    YELLOW_800,
+    YELLOW_900,
+    YELLOW_A100,
+    YELLOW_A200,
+    YELLOW_A400,
+    YELLOW_A700,
+    AMBER_50,
+    AMBER_100,
+    AMBER_200,
+    AMBER_300,
+    AMBER_400,
+    AMBER_500,
+    AMBER_600,
+    AMBER_700,
+    AMBER_800,
+    AMBER_900,
+    AMBER_A100,
+    AMBER_A200,
+    AMBER_A400,
+    AMBER_A700,
+    ORANGE_50,
+    ORANGE_100,
+    ORANGE_200,
+    ORANGE_300,
+    ORANGE_400,
+    ORANGE_500,
+    ORANGE_600,
+    ORANGE_700,
+    ORANGE_800,
+    ORANGE_900,
+    ORANGE_A100,
+    ORANGE_A200,
+    ORANGE_A400,
+    ORANGE_A700,
+    DEEP_ORANGE_50,
+    DEEP_ORANGE_100,
+    DEEP_ORANGE_200,
+    DEEP_ORANGE_300,
+    DEEP_ORANGE_400,
+    DEEP_ORANGE_500,
+    DEEP_ORANGE_600,
+    DEEP_ORANGE_700,
+    DEEP_ORANGE_800,
+    DEEP_ORANGE_900,
+    DEEP_ORANGE_A100,
+    DEEP_ORANGE_A200,
+    DEEP_ORANGE_A400,
+    DEEP_ORANGE_A700,
+    BROWN_50,
+    BROWN_100,
+    BROWN_200,
+    BROWN_300,
+    BROWN_400,
+    BROWN_500,
+    BROWN_600,
+    BROWN_700,
+    BROWN_800,
+    BROWN_900,
+    GREY_50,
+    GREY_100,
+    GREY_200,
+    GREY_300,
+    GREY_400,
+    GREY_500,
+    GREY_600,
+    GREY_700,
+    GREY_800,
+    GREY_900,
+    BLUE_GREY_50,
+    BLUE_GREY_100,
+    BLUE_GREY_200,
+    BLUE_GREY_300,
+    BLUE_GREY_400,
+    BLUE_GREY_500,
+    BLUE_GREY_600,
+    BLUE_GREY_700,
+    BLUE_GREY_800,
+    BLUE_GREY_900,
+    BLACK,
+    WHITE,
+];
+
+/// Material Design 2 palette with shades and accents of 19 hues
+pub const PALETTE: Palette = Palette::new("Material", "https://m2.material.io/design/color/the-color-system.html", &NAMES, &COLORS);
@@ -0,0 +1,220 @@
+//! Named color palettes.
+//!
+//! Every palette module exposes its colors as constants, like [`nord::NORD8`],
+//! and as a [`Palette`] value which keeps names and metadata.
+//!
+//! ```
+//! use primitives::foundation::palettes::{self, material, Palette};
+//! use primitives::prelude::Color;
+//!
+//! assert_eq!(palettes::MATERIAL.get("teal-500"), Some(material::TEAL_500));
+//!
+//! let (name, _) = palettes::PICO8.nearest(Color::rgb(250, 10, 80));
+//! assert_eq!(name, "red");
+//!
+//! for (name, color) in &palettes::GAME_BOY {
+//!     println!("{} {}", name, color);
+//! }
+//! ```
+
+use crate::foundation::colorspace::{Color, OklabColor};
+
+pub mod dracula;
+pub mod gameboy;
+pub mod material;
+pub mod nord;
+pub mod open_color;
+pub mod pico8;
+pub mod solarized;
+pub mod tailwind;
+pub mod x11;
+
+/// Open Color palette, the colors of [`crate::prelude::color`]
+pub const OPEN_COLOR: Palette = open_color::PALETTE;
+/// Material Design 2 palette
+pub const MATERIAL: Palette = material::PALETTE;
+/// Tailwind CSS v3 palette
+pub const TAILWIND: Palette = tailwind::PALETTE;
+/// Solarized palette
+pub const SOLARIZED: Palette = solarized::PALETTE;
+/// Nord palette
+pub const NORD: Palette = nord::PALETTE;
+/// Dracula palette
+pub const DRACULA: Palette = dracula::PALETTE;
+/// X11 named colors
+pub const X11: Palette = x11::PALETTE;
+/// Web-safe colors
+pub const WEB_SAFE: Palette = x11::WEB_SAFE;
+/// PICO-8 palette
+pub const PICO8: Palette = pico8::PALETTE;
+/// Game Boy palette
+pub const GAME_BOY: Palette = gameboy::PALETTE;
+
+/// All built-in palettes
+pub const ALL: [Palette; 10] = [
+    OPEN_COLOR, MATERIAL, TAILWIND, SOLARIZED, NORD, DRACULA, X11, WEB_SAFE, PICO8, GAME_BOY,
+];
+
+/// Find built-in palette by name, ignoring case
+pub fn by_name(name: &str) -> Option<Palette> {
+    ALL.iter()
+        .find(|palette| palette.name.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+/// Named list of colors with metadata
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    name: &'static str,
+    source: &'static str,
+    names: &'static [&'static str],
+    colors: &'static [Color],
+}
+
+impl Palette {
+    /// Create palette from color names and colors in the same order
+    pub const fn new(
+        name: &'static str,
+        source: &'static str,
+        names: &'static [&'static str],
+        colors: &'static [Color],
+    ) -> Self {
+        assert!(names.len() == colors.len(), "every color needs a name");
+        Self {
+            name,
+            source,
+            names,
+            colors,
+        }
+    }
+
+    /// Retrieve palette name
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Retrieve url of the palette specification
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Retrieve color names
+    pub fn names(&self) -> &'static [&'static str] {
+        self.names
+    }
+
+    /// Retrieve colors
+    pub fn colors(&self) -> &'static [Color] {
+        self.colors
+    }
+
+    /// Retrieve count of colors
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Check if palette has no colors
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Find color by name, ignoring case
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.iter()
+            .find(|(color_name, _)| color_name.eq_ignore_ascii_case(name))
+            .map(|(_, color)| color)
+    }
+
+    /// Retrieve name of color when palette contains it
+    pub fn name_of(&self, color: Color) -> Option<&'static str> {
+        self.iter()
+            .find(|(_, palette_color)| *palette_color == color)
+            .map(|(name, _)| name)
+    }
+
+    /// Find perceptually nearest color of palette, alpha is ignored.
+    ///
+    /// Panics when palette is empty.
+    pub fn nearest(&self, color: Color) -> (&'static str, Color) {
+        let lab = OklabColor::from(color);
+        self.iter()
+            .map(|(name, candidate)| {
+                (name, candidate, lab.delta_e(&OklabColor::from(candidate)))
+            })
+            .fold(None, |best: Option<(&'static str, Color, f32)>, item| match best {
+                Some(best) if best.2 <= item.2 => Some(best),
+                _ => Some(item),
+            })
+            .map(|(name, color, _)| (name, color))
+            .expect("palette is empty")
+    }
+
+    /// Iterate over names and colors
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Color)> + 'static {
+        self.names.iter().copied().zip(self.colors.iter().copied())
+    }
+}
+
+impl IntoIterator for Palette {
+    type Item = (&'static str, Color);
+    type IntoIter = std::iter::Zip<
+        std::iter::Copied<std::slice::Iter<'static, &'static str>>,
+        std::iter::Copied<std::slice::Iter<'static, Color>>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names.iter().copied().zip(self.colors.iter().copied())
+    }
+}
+
+impl IntoIterator for &Palette {
+    type Item = (&'static str, Color);
+    type IntoIter = <Palette as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::color;
+
+    #[test]
+    fn palettes() {
+        let sizes: Vec<usize> = ALL.iter().map(Palette::len).collect();
+        assert_eq!(sizes, vec![132, 256, 242, 16, 16, 11, 148, 216, 16, 4]);
+
+        for palette in ALL.iter() {
+            let mut names = palette.names().to_vec();
+            names.sort_unstable();
+            names.dedup();
+            assert_eq!(names.len(), palette.len(), "{}", palette.name());
+        }
+
+        assert_eq!(by_name("nord"), Some(NORD));
+        assert_eq!(by_name("unknown"), None);
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!(MATERIAL.get("Red-A200"), Some(material::RED_A200));
+        assert_eq!(material::RED_500, Color::rgb(0xf4, 0x43, 0x36));
+        assert_eq!(tailwind::SKY_500, Color::rgb(0x0e, 0xa5, 0xe9));
+        assert_eq!(OPEN_COLOR.get("teal-4"), Some(color::TEAL_4));
+        assert_eq!(X11.get("rebeccapurple"), Some(Color::rgb(0x66, 0x33, 0x99)));
+        assert_eq!(WEB_SAFE.get("#336699"), Some(Color::rgb(0x33, 0x66, 0x99)));
+        assert_eq!(WEB_SAFE.name_of(Color::rgb(255, 204, 0)), Some("#ffcc00"));
+        assert_eq!(SOLARIZED.name_of(solarized::BASE03), Some("base03"));
+    }
+
+    #[test]
+    fn nearest() {
+        assert_eq!(GAME_BOY.nearest(color::BLACK), ("darkest", gameboy::DARKEST));
+        assert_eq!(DRACULA.nearest(Color::rgb(250, 90, 90)).0, "red");
+
+        let colors: Vec<Color> = (&NORD).into_iter().map(|(_, color)| color).collect();
+        assert_eq!(colors, NORD.colors());
+    }
+}
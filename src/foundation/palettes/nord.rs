@@ -0,0 +1,66 @@
+//! [Nord](https://www.nordtheme.com/docs/colors-and-palettes) arctic palette.
+//!
+//! Polar Night `NORD0`-`NORD3`, Snow Storm `NORD4`-`NORD6`, Frost `NORD7`-`NORD10` and Aurora `NORD11`-`NORD15`.
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::Color;
+
+pub const NORD0: Color = Color::rgb(46, 52, 64);
+pub const NORD1: Color = Color::rgb(59, 66, 82);
+pub const NORD2: Color = Color::rgb(67, 76, 94);
+pub const NORD3: Color = Color::rgb(76, 86, 106);
+pub const NORD4: Color = Color::rgb(216, 222, 233);
+pub const NORD5: Color = Color::rgb(229, 233, 240);
+pub const NORD6: Color = Color::rgb(236, 239, 244);
+pub const NORD7: Color = Color::rgb(143, 188, 187);
+pub const NORD8: Color = Color::rgb(136, 192, 208);
+pub const NORD9: Color = Color::rgb(129, 161, 193);
+pub const NORD10: Color = Color::rgb(94, 129, 172);
+pub const NORD11: Color = Color::rgb(191, 97, 106);
+pub const NORD12: Color = Color::rgb(208, 135, 112);
+pub const NORD13: Color = Color::rgb(235, 203, 139);
+pub const NORD14: Color = Color::rgb(163, 190, 140);
+pub const NORD15: Color = Color::rgb(180, 142, 173);
+
+const NAMES: [&str; 16] = [
+    "nord0",
+    "nord1",
+    "nord2",
+    "nord3",
+    "nord4",
+    "nord5",
+    "nord6",
+    "nord7",
+    "nord8",
+    "nord9",
+    "nord10",
+    "nord11",
+    "nord12",
+    "nord13",
+    "nord14",
+    "nord15",
+];
+
+const COLORS: [Color; 16] = [
+    NORD0,
+    NORD1,
+    NORD2,
+    NORD3,
+    NORD4,
+    NORD5,
+    NORD6,
+    NORD7,
+    NORD8,
+    NORD9,
+    NORD10,
+    NORD11,
+    NORD12,
+    NORD13,
+    NORD14,
+    NORD15,
+];
+
+/// Nord palette with 16 colors
+pub const PALETTE: Palette = Palette::new("Nord", "https://www.nordtheme.com/docs/colors-and-palettes", &NAMES, &COLORS);
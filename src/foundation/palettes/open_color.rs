@@ -0,0 +1,280 @@
+//! [Open Color](https://yeun.github.io/open-color/) palette, the constants live in [`crate::prelude::color`].
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::Color;
+use crate::prelude::color::*;
+
+const NAMES: [&str; 132] = [
+    "black",
+    "white",
+    "gray-0",
+    "gray-1",
+    "gray-2",
+    "gray-3",
+    "gray-4",
+    "gray-5",
+    "gray-6",
+    "gray-7",
+    "gray-8",
+    "gray-9",
+    "red-0",
+    "red-1",
+    "red-2",
+    "red-3",
+    "red-4",
+    "red-5",
+    "red-6",
+    "red-7",
+    "red-8",
+    "red-9",
+    "pink-0",
+    "pink-1",
+    "pink-2",
+    "pink-3",
+    "pink-4",
+    "pink-5",
+    "pink-6",
+    "pink-7",
+    "pink-8",
+    "pink-9",
+    "grape-0",
+    "grape-1",
+    "grape-2",
+    "grape-3",
+    "grape-4",
+    "grape-5",
+    "grape-6",
+    "grape-7",
+    "grape-8",
+    "grape-9",
+    "violet-0",
+    "violet-1",
+    "violet-2",
+    "violet-3",
+    "violet-4",
+    "violet-5",
+    "violet-6",
+    "violet-7",
+    "violet-8",
+    "violet-9",
+    "indigo-0",
+    "indigo-1",
+    "indigo-2",
+    "indigo-3",
+    "indigo-4",
+    "indigo-5",
+    "indigo-6",
+    "indigo-7",
+    "indigo-8",
+    "indigo-9",
+    "blue-0",
+    "blue-1",
+    "blue-2",
+    "blue-3",
+    "blue-4",
+    "blue-5",
+    "blue-6",
+    "blue-7",
+    "blue-8",
+    "blue-9",
+    "cyan-0",
+    "cyan-1",
+    "cyan-2",
+    "cyan-3",
+    "cyan-4",
+    "cyan-5",
+    "cyan-6",
+    "cyan-7",
+    "cyan-8",
+    "cyan-9",
+    "teal-0",
+    "teal-1",
+    "teal-2",
+    "teal-3",
+    "teal-4",
+    "teal-5",
+    "teal-6",
+    "teal-7",
+    "teal-8",
+    "teal-9",
+    "green-0",
+    "green-1",
+    "green-2",
+    "green-3",
+    "green-4",
+    "green-5",
+    "green-6",
+    "green-7",
+    "green-8",
+    "green-9",
+    "lime-0",
+    "lime-1",
+    "lime-2",
+    "lime-3",
+    "lime-4",
+    "lime-5",
+    "lime-6",
+    "lime-7",
+    "lime-8",
+    "lime-9",
+    "yellow-0",
+    "yellow-1",
+    "yellow-2",
+    "yellow-3",
+    "yellow-4",
+    "yellow-5",
+    "yellow-6",
+    "yellow-7",
+    "yellow-8",
+    "yellow-9",
+    "orange-0",
+    "orange-1",
+    "orange-2",
+    "orange-3",
+    "orange-4",
+    "orange-5",
+    "orange-6",
+    "orange-7",
+    "orange-8",
+    "orange-9",
+];
+
+const COLORS: [Color; 132] = [
+    BLACK,
+    WHITE,
+    GRAY_0,
+    GRAY_1,
+    GRAY_2,
+    GRAY_3,
+    GRAY_4,
+    GRAY_5,
+    GRAY_6,
+    GRAY_7,
+    GRAY_8,
+    GRAY_9,
+    RED_0,
+    RED_1,
+    RED_2,
+    RED_3,
+    RED_4,
+    RED_5,
+    RED_6,
+    RED_7,
+    RED_8,
+    RED_9,
+    PINK_0,
+    PINK_1,
+    PINK_2,
+    PINK_3,
+    PINK_4,
+    PINK_5,
+    PINK_6,
+    PINK_7,
+    PINK_8,
+    PINK_9,
+    GRAPE_0,
+    GRAPE_1,
+    GRAPE_2,
+    GRAPE_3,
+    GRAPE_4,
+    GRAPE_5,
+    GRAPE_6,
+    GRAPE_7,
+    GRAPE_8,
+    GRAPE_9,
+    VIOLET_0,
+    VIOLET_1,
+    VIOLET_2,
+    VIOLET_3,
+    VIOLET_4,
+    VIOLET_5,
+    VIOLET_6,
+    VIOLET_7,
+    VIOLET_8,
+    VIOLET_9,
+    INDIGO_0,
+    INDIGO_1,
+    INDIGO_2,
+    INDIGO_3,
+    INDIGO_4,
+    INDIGO_5,
+    INDIGO_6,
+    INDIGO_7,
+    INDIGO_8,
+    INDIGO_9,
+    BLUE_0,
+    BLUE_1,
+    BLUE_2,
+    BLUE_3,
+    BLUE_4,
+    BLUE_5,
+    BLUE_6,
+    BLUE_7,
+    BLUE_8,
+    BLUE_9,
+    CYAN_0,
+    CYAN_1,
+    CYAN_2,
+    CYAN_3,
+    CYAN_4,
+    CYAN_5,
+    CYAN_6,
+    CYAN_7,
+    CYAN_8,
+    CYAN_9,
+    TEAL_0,
+    TEAL_1,
+    TEAL_2,
+    TEAL_3,
+    TEAL_4,
+    TEAL_5,
+    TEAL_6,
+    TEAL_7,
+    TEAL_8,
+    TEAL_9,
+    GREEN_0,
+    GREEN_1,
+    GREEN_2,
+    GREEN_3,
+    GREEN_4,
+    GREEN_5,
+    GREEN_6,
+    GREEN_7,
+    GREEN_8,
+    GREEN_9,
+    LIME_0,
+    LIME_1,
+    LIME_2,
+    LIME_3,
+    LIME_4,
+    LIME_5,
+    LIME_6,
+    LIME_7,
+    LIME_8,
+    LIME_9,
+    YELLOW_0,
+    YELLOW_1,
+    YELLOW_2,
+    YELLOW_3,
+    YELLOW_4,
+    YELLOW_5,
+    YELLOW_6,
+    YELLOW_7,
+    YELLOW_8,
+    YELLOW_9,
+    ORANGE_0,
+    ORANGE_1,
+    ORANGE_2,
+    ORANGE_3,
+    ORANGE_4,
+    ORANGE_5,
+    ORANGE_6,
+    ORANGE_7,
+    ORANGE_8,
+    ORANGE_9,
+];
+
+/// Open Color palette with black, white and 10 shades of 13 colors
+pub const PALETTE: Palette = Palette::new("Open Color", "https://yeun.github.io/open-color/", &NAMES, &COLORS);
@@ -0,0 +1,66 @@
+//! [PICO-8](https://pico-8.fandom.com/wiki/Palette) fantasy console palette.
+//!
+//! Colors are in order of their indices in the console.
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::Color;
+
+pub const BLACK: Color = Color::rgb(0, 0, 0);
+pub const DARK_BLUE: Color = Color::rgb(29, 43, 83);
+pub const DARK_PURPLE: Color = Color::rgb(126, 37, 83);
+pub const DARK_GREEN: Color = Color::rgb(0, 135, 81);
+pub const BROWN: Color = Color::rgb(171, 82, 54);
+pub const DARK_GREY: Color = Color::rgb(95, 87, 79);
+pub const LIGHT_GREY: Color = Color::rgb(194, 195, 199);
+pub const WHITE: Color = Color::rgb(255, 241, 232);
+pub const RED: Color = Color::rgb(255, 0, 77);
+pub const ORANGE: Color = Color::rgb(255, 163, 0);
+pub const YELLOW: Color = Color::rgb(255, 236, 39);
+pub const GREEN: Color = Color::rgb(0, 228, 54);
+pub const BLUE: Color = Color::rgb(41, 173, 255);
+pub const LAVENDER: Color = Color::rgb(131, 118, 156);
+pub const PINK: Color = Color::rgb(255, 119, 168);
+pub const LIGHT_PEACH: Color = Color::rgb(255, 204, 170);
+
+const NAMES: [&str; 16] = [
+    "black",
+    "dark-blue",
+    "dark-purple",
+    "dark-green",
+    "brown",
+    "dark-grey",
+    "light-grey",
+    "white",
+    "red",
+    "orange",
+    "yellow",
+    "green",
+    "blue",
+    "lavender",
+    "pink",
+    "light-peach",
+];
+
+const COLORS: [Color; 16] = [
+    BLACK,
+    DARK_BLUE,
+    DARK_PURPLE,
+    DARK_GREEN,
+    BROWN,
+    DARK_GREY,
+    LIGHT_GREY,
+    WHITE,
+    RED,
+    ORANGE,
+    YELLOW,
+    GREEN,
+    BLUE,
+    LAVENDER,
+    PINK,
+    LIGHT_PEACH,
+];
+
+/// PICO-8 palette with 16 colors
+pub const PALETTE: Palette = Palette::new("PICO-8", "https://pico-8.fandom.com/wiki/Palette", &NAMES, &COLORS);
@@ -0,0 +1,66 @@
+//! [Solarized](https://ethanschoonover.com/solarized/) palette by Ethan Schoonover.
+//!
+//! Dark themes use `BASE03` as background, light themes `BASE3`.
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::Color;
+
+pub const BASE03: Color = Color::rgb(0, 43, 54);
+pub const BASE02: Color = Color::rgb(7, 54, 66);
+pub const BASE01: Color = Color::rgb(88, 110, 117);
+pub const BASE00: Color = Color::rgb(101, 123, 131);
+pub const BASE0: Color = Color::rgb(131, 148, 150);
+pub const BASE1: Color = Color::rgb(147, 161, 161);
+pub const BASE2: Color = Color::rgb(238, 232, 213);
+pub const BASE3: Color = Color::rgb(253, 246, 227);
+pub const YELLOW: Color = Color::rgb(181, 137, 0);
+pub const ORANGE: Color = Color::rgb(203, 75, 22);
+pub const RED: Color = Color::rgb(220, 50, 47);
+pub const MAGENTA: Color = Color::rgb(211, 54, 130);
+pub const VIOLET: Color = Color::rgb(108, 113, 196);
+pub const BLUE: Color = Color::rgb(38, 139, 210);
+pub const CYAN: Color = Color::rgb(42, 161, 152);
+pub const GREEN: Color = Color::rgb(133, 153, 0);
+
+const NAMES: [&str; 16] = [
+    "base03",
+    "base02",
+    "base01",
+    "base00",
+    "base0",
+    "base1",
+    "base2",
+    "base3",
+    "yellow",
+    "orange",
+    "red",
+    "magenta",
+    "violet",
+    "blue",
+    "cyan",
+    "green",
+];
+
+const COLORS: [Color; 16] = [
+    BASE03,
+    BASE02,
+    BASE01,
+    BASE00,
+    BASE0,
+    BASE1,
+    BASE2,
+    BASE3,
+    YELLOW,
+    ORANGE,
+    RED,
+    MAGENTA,
+    VIOLET,
+    BLUE,
+    CYAN,
+    GREEN,
+];
+
+/// Solarized palette with 8 monotones and 8 accent colors
+pub const PALETTE: Palette = Palette::new("Solarized", "https://ethanschoonover.com/solarized/", &NAMES, &COLORS);
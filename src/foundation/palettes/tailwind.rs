@@ -0,0 +1,744 @@
+//! [Tailwind CSS v3](https://v3.tailwindcss.com/docs/customizing-colors) default color palette.
+//!
+//! Shades go from `50` to `950`.
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::Color;
+
+pub const SLATE_50: Color = Color::rgb(248, 250, 252);
+pub const SLATE_100: Color = Color::rgb(241, 245, 249);
+pub const SLATE_200: Color = Color::rgb(226, 232, 240);
+pub const SLATE_300: Color = Color::rgb(203, 213, 225);
+pub const SLATE_400: Color = Color::rgb(148, 163, 184);
+pub const SLATE_500: Color = Color::rgb(100, 116, 139);
+pub const SLATE_600: Color = Color::rgb(71, 85, 105);
+pub const SLATE_700: Color = Color::rgb(51, 65, 85);
+pub const SLATE_800: Color = Color::rgb(30, 41, 59);
+pub const SLATE_900: Color = Color::rgb(15, 23, 42);
+pub const SLATE_950: Color = Color::rgb(2, 6, 23);
+pub const GRAY_50: Color = Color::rgb(249, 250, 251);
+pub const GRAY_100: Color = Color::rgb(243, 244, 246);
+pub const GRAY_200: Color = Color::rgb(229, 231, 235);
+pub const GRAY_300: Color = Color::rgb(209, 213, 219);
+pub const GRAY_400: Color = Color::rgb(156, 163, 175);
+pub const GRAY_500: Color = Color::rgb(107, 114, 128);
+pub const GRAY_600: Color = Color::rgb(75, 85, 99);
+pub const GRAY_700: Color = Color::rgb(55, 65, 81);
+pub const GRAY_800: Color = Color::rgb(31, 41, 55);
+pub const GRAY_900: Color = Color::rgb(17, 24, 39);
+pub const GRAY_950: Color = Color::rgb(3, 7, 18);
+pub const ZINC_50: Color = Color::rgb(250, 250, 250);
+pub const ZINC_100: Color = Color::rgb(244, 244, 245);
+pub const ZINC_200: Color = Color::rgb(228, 228, 231);
+pub const ZINC_300: Color = Color::rgb(212, 212, 216);
+pub const ZINC_400: Color = Color::rgb(161, 161, 170);
+pub const ZINC_500: Color = Color::rgb(113, 113, 122);
+pub const ZINC_600: Color = Color::rgb(82, 82, 91);
+pub const ZINC_700: Color = Color::rgb(63, 63, 70);
+pub const ZINC_800: Color = Color::rgb(39, 39, 42);
+pub const ZINC_900: Color = Color::rgb(24, 24, 27);
+pub const ZINC_950: Color = Color::rgb(9, 9, 11);
+pub const NEUTRAL_50: Color = Color::rgb(250, 250, 250);
+pub const NEUTRAL_100: Color = Color::rgb(245, 245, 245);
+pub const NEUTRAL_200: Color = Color::rgb(229, 229, 229);
+pub const NEUTRAL_300: Color = Color::rgb(212, 212, 212);
+pub const NEUTRAL_400: Color = Color::rgb(163, 163, 163);
+pub const NEUTRAL_500: Color = Color::rgb(115, 115, 115);
+pub const NEUTRAL_600: Color = Color::rgb(82, 82, 82);
+pub const NEUTRAL_700: Color = Color::rgb(64, 64, 64);
+pub const NEUTRAL_800: Color = Color::rgb(38, 38, 38);
+pub const NEUTRAL_900: Color = Color::rgb(23, 23, 23);
+pub const NEUTRAL_950: Color = Color::rgb(10, 10, 10);
+pub const STONE_50: Color = Color::rgb(250, 250, 249);
+pub const STONE_100: Color = Color::rgb(245, 245, 244);
+pub const STONE_200: Color = Color::rgb(231, 229, 228);
+pub const STONE_300: Color = Color::rgb(214, 211, 209);
+pub const STONE_400: Color = Color::rgb(168, 162, 158);
+pub const STONE_500: Color = Color::rgb(120, 113, 108);
+pub const STONE_600: Color = Color::rgb(87, 83, 78);
+pub const STONE_700: Color = Color::rgb(68, 64, 60);
+pub const STONE_800: Color = Color::rgb(41, 37, 36);
+pub const STONE_900: Color = Color::rgb(28, 25, 23);
+pub const STONE_950: Color = Color::rgb(12, 10, 9);
+pub const RED_50: Color = Color::rgb(254, 242, 242);
+pub const RED_100: Color = Color::rgb(254, 226, 226);
+pub const RED_200: Color = Color::rgb(254, 202, 202);
+pub const RED_300: Color = Color::rgb(252, 165, 165);
+pub const RED_400: Color = Color::rgb(248, 113, 113);
+pub const RED_500: Color = Color::rgb(239, 68, 68);
+pub const RED_600: Color = Color::rgb(220, 38, 38);
+pub const RED_700: Color = Color::rgb(185, 28, 28);
+pub const RED_800: Color = Color::rgb(153, 27, 27);
+pub const RED_900: Color = Color::rgb(127, 29, 29);
+pub const RED_950: Color = Color::rgb(69, 10, 10);
+pub const ORANGE_50: Color = Color::rgb(255, 247, 237);
+pub const ORANGE_100: Color = Color::rgb(255, 237, 213);
+pub const ORANGE_200: Color = Color::rgb(254, 215, 170);
+pub const ORANGE_300: Color = Color::rgb(253, 186, 116);
+pub const ORANGE_400: Color = Color::rgb(251, 146, 60);
+pub const ORANGE_500: Color = Color::rgb(249, 115, 22);
+pub const ORANGE_600: Color = Color::rgb(234, 88, 12);
+pub const ORANGE_700: Color = Color::rgb(194, 65, 12);
+pub const ORANGE_800: Color = Color::rgb(154, 52, 18);
+pub const ORANGE_900: Color = Color::rgb(124, 45, 18);
+pub const ORANGE_950: Color = Color::rgb(67, 20, 7);
+pub const AMBER_50: Color = Color::rgb(255, 251, 235);
+pub const AMBER_100: Color = Color::rgb(254, 243, 199);
+pub const AMBER_200: Color = Color::rgb(253, 230, 138);
+pub const AMBER_300: Color = Color::rgb(252, 211, 77);
+pub const AMBER_400: Color = Color::rgb(251, 191, 36);
+pub const AMBER_500: Color = Color::rgb(245, 158, 11);
+pub const AMBER_600: Color = Color::rgb(217, 119, 6);
+pub const AMBER_700: Color = Color::rgb(180, 83, 9);
+pub const AMBER_800: Color = Color::rgb(146, 64, 14);
+pub const AMBER_900: Color = Color::rgb(120, 53, 15);
+pub const AMBER_950: Color = Color::rgb(69, 26, 3);
+pub const YELLOW_50: Color = Color::rgb(254, 252, 232);
+pub const YELLOW_100: Color = Color::rgb(254, 249, 195);
+pub const YELLOW_200: Color = Color::rgb(254, 240, 138);
+pub const YELLOW_300: Color = Color::rgb(253, 224, 71);
+pub const YELLOW_400: Color = Color::rgb(250, 204, 21);
+pub const YELLOW_500: Color = Color::rgb(234, 179, 8);
+pub const YELLOW_600: Color = Color::rgb(202, 138, 4);
+pub const YELLOW_700: Color = Color::rgb(161, 98, 7);
+pub const YELLOW_800: Color = Color::rgb(133, 77, 14);
+pub const YELLOW_900: Color = Color::rgb(113, 63, 18);
+pub const YELLOW_950: Color = Color::rgb(66, 32, 6);
+pub const LIME_50: Color = Color::rgb(247, 254, 231);
+pub const LIME_100: Color = Color::rgb(236, 252, 203);
+pub const LIME_200: Color = Color::rgb(217, 249, 157);
+pub const LIME_300: Color = Color::rgb(190, 242, 100);
+pub const LIME_400: Color = Color::rgb(163, 230, 53);
+pub const LIME_500: Color = Color::rgb(132, 204, 22);
+pub const LIME_600: Color = Color::rgb(101, 163, 13);
+pub const LIME_700: Color = Color::rgb(77, 124, 15);
+pub const LIME_800: Color = Color::rgb(63, 98, 18);
+pub const LIME_900: Color = Color::rgb(54, 83, 20);
+pub const LIME_950: Color = Color::rgb(26, 46, 5);
+pub const GREEN_50: Color = Color::rgb(240, 253, 244);
+pub const GREEN_100: Color = Color::rgb(220, 252, 231);
+pub const GREEN_200: Color = Color::rgb(187, 247, 208);
+pub const GREEN_300: Color = Color::rgb(134, 239, 172);
+pub const GREEN_400: Color = Color::rgb(74, 222, 128);
+pub const GREEN_500: Color = Color::rgb(34, 197, 94);
+pub const GREEN_600: Color = Color::rgb(22, 163, 74);
+pub const GREEN_700: Color = Color::rgb(21, 128, 61);
+pub const GREEN_800: Color = Color::rgb(22, 101, 52);
+pub const GREEN_900: Color = Color::rgb(20, 83, 45);
+pub const GREEN_950: Color = Color::rgb(5, 46, 22);
+pub const EMERALD_50: Color = Color::rgb(236, 253, 245);
+pub const EMERALD_100: Color = Color::rgb(209, 250, 229);
+pub const EMERALD_200: Color = Color::rgb(167, 243, 208);
+pub const EMERALD_300: Color = Color::rgb(110, 231, 183);
+pub const EMERALD_400: Color = Color::rgb(52, 211, 153);
+pub const EMERALD_500: Color = Color::rgb(16, 185, 129);
+pub const EMERALD_600: Color = Color::rgb(5, 150, 105);
+pub const EMERALD_700: Color = Color::rgb(4, 120, 87);
+pub const EMERALD_800: Color = Color::rgb(6, 95, 70);
+pub const EMERALD_900: Color = Color::rgb(6, 78, 59);
+pub const EMERALD_950: Color = Color::rgb(2, 44, 34);
+pub const TEAL_50: Color = Color::rgb(240, 253, 250);
+pub const TEAL_100: Color = Color::rgb(204, 251, 241);
+pub const TEAL_200: Color = Color::rgb(153, 246, 228);
+pub const TEAL_300: Color = Color::rgb(94, 234, 212);
+pub const TEAL_400: Color = Color::rgb(45, 212, 191);
+pub const TEAL_500: Color = Color::rgb(20, 184, 166);
+pub const TEAL_600: Color = Color::rgb(13, 148, 136);
+pub const TEAL_700: Color = Color::rgb(15, 118, 110);
+pub const TEAL_800: Color = Color::rgb(17, 94, 89);
+pub const TEAL_900: Color = Color::rgb(19, 78, 74);
+pub const TEAL_950: Color = Color::rgb(4, 47, 46);
+pub const CYAN_50: Color = Color::rgb(236, 254, 255);
+pub const CYAN_100: Color = Color::rgb(207, 250, 254);
+pub const CYAN_200: Color = Color::rgb(165, 243, 252);
+pub const CYAN_300: Color = Color::rgb(103, 232, 249);
+pub const CYAN_400: Color = Color::rgb(34, 211, 238);
+pub const CYAN_500: Color = Color::rgb(6, 182, 212);
+pub const CYAN_600: Color = Color::rgb(8, 145, 178);
+pub const CYAN_700: Color = Color::rgb(14, 116, 144);
+pub const CYAN_800: Color = Color::rgb(21, 94, 117);
+pub const CYAN_900: Color = Color::rgb(22, 78, 99);
+pub const CYAN_950: Color = Color::rgb(8, 51, 68);
+pub const SKY_50: Color = Color::rgb(240, 249, 255);
+pub const SKY_100: Color = Color::rgb(224, 242, 254);
+pub const SKY_200: Color = Color::rgb(186, 230, 253);
+pub const SKY_300: Color = Color::rgb(125, 211, 252);
+pub const SKY_400: Color = Color::rgb(56, 189, 248);
+pub const SKY_500: Color = Color::rgb(14, 165, 233);
+pub const SKY_600: Color = Color::rgb(2, 132, 199);
+pub const SKY_700: Color = Color::rgb(3, 105, 161);
+pub const SKY_800: Color = Color::rgb(7, 89, 133);
+pub const SKY_900: Color = Color::rgb(12, 74, 110);
+pub const SKY_950: Color = Color::rgb(8, 47, 73);
+pub const BLUE_50: Color = Color::rgb(239, 246, 255);
+pub const BLUE_100: Color = Color::rgb(219, 234, 254);
+pub const BLUE_200: Color = Color::rgb(191, 219, 254);
+pub const BLUE_300: Color = Color::rgb(147, 197, 253);
+pub const BLUE_400: Color = Color::rgb(96, 165, 250);
+pub const BLUE_500: Color = Color::rgb(59, 130, 246);
+pub const BLUE_600: Color = Color::rgb(37, 99, 235);
+pub const BLUE_700: Color = Color::rgb(29, 78, 216);
+pub const BLUE_800: Color = Color::rgb(30, 64, 175);
+pub const BLUE_900: Color = Color::rgb(30, 58, 138);
+pub const BLUE_950: Color = Color::rgb(23, 37, 84);
+pub const INDIGO_50: Color = Color::rgb(238, 242, 255);
+pub const INDIGO_100: Color = Color::rgb(224, 231, 255);
+pub const INDIGO_200: Color = Color::rgb(199, 210, 254);
+pub const INDIGO_300: Color = Color::rgb(165, 180, 252);
+pub const INDIGO_400: Color = Color::rgb(129, 140, 248);
+pub const INDIGO_500: Color = Color::rgb(99, 102, 241);
+pub const INDIGO_600: Color = Color::rgb(79, 70, 229);
+pub const INDIGO_700: Color = Color::rgb(67, 56, 202);
+pub const INDIGO_800: Color = Color::rgb(55, 48, 163);
+pub const INDIGO_900: Color = Color::rgb(49, 46, 129);
+pub const INDIGO_950: Color = Color::rgb(30, 27, 75);
+pub const VIOLET_50: Color = Color::rgb(245, 243, 255);
+pub const VIOLET_100: Color = Color::rgb(237, 233, 254);
+pub const VIOLET_200: Color = Color::rgb(221, 214, 254);
+pub const VIOLET_300: Color = Color::rgb(196, 181, 253);
+pub const VIOLET_400: Color = Color::rgb(167, 139, 250);
+pub const VIOLET_500: Color = Color::rgb(139, 92, 246);
+pub const VIOLET_600: Color = Color::rgb(124, 58, 237);
+pub const VIOLET_700: Color = Color::rgb(109, 40, 217);
+pub const VIOLET_800: Color = Color::rgb(91, 33, 182);
+pub const VIOLET_900: Color = Color::rgb(76, 29, 149);
+pub const VIOLET_950: Color = Color::rgb(46, 16, 101);
+pub const PURPLE_50: Color = Color::rgb(250, 245, 255);
+pub const PURPLE_100: Color = Color::rgb(243, 232, 255);
+pub const PURPLE_200: Color = Color::rgb(233, 213, 255);
+pub const PURPLE_300: Color = Color::rgb(216, 180, 254);
+pub const PURPLE_400: Color = Color::rgb(192, 132, 252);
+pub const PURPLE_500: Color = Color::rgb(168, 85, 247);
+pub const PURPLE_600: Color = Color::rgb(147, 51, 234);
+pub const PURPLE_700: Color = Color::rgb(126, 34, 206);
+pub const PURPLE_800: Color = Color::rgb(107, 33, 168);
+pub const PURPLE_900: Color = Color::rgb(88, 28, 135);
+pub const PURPLE_950: Color = Color::rgb(59, 7, 100);
+pub const FUCHSIA_50: Color = Color::rgb(253, 244, 255);
+pub const FUCHSIA_100: Color = Color::rgb(250, 232, 255);
+pub const FUCHSIA_200: Color = Color::rgb(245, 208, 254);
+pub const FUCHSIA_300: Color = Color::rgb(240, 171, 252);
+pub const FUCHSIA_400: Color = Color::rgb(232, 121, 249);
+pub const FUCHSIA_500: Color = Color::rgb(217, 70, 239);
+pub const FUCHSIA_600: Color = Color::rgb(192, 38, 211);
+pub const FUCHSIA_700: Color = Color::rgb(162, 28, 175);
+pub const FUCHSIA_800: Color = Color::rgb(134, 25, 143);
+pub const FUCHSIA_900: Color = Color::rgb(112, 26, 117);
+pub const FUCHSIA_950: Color = Color::rgb(74, 4, 78);
+pub const PINK_50: Color = Color::rgb(253, 242, 248);
+pub const PINK_100: Color = Color::rgb(252, 231, 243);
+pub const PINK_200: Color = Color::rgb(251, 207, 232);
+pub const PINK_300: Color = Color::rgb(249, 168, 212);
+pub const PINK_400: Color = Color::rgb(244, 114, 182);
+pub const PINK_500: Color = Color::rgb(236, 72, 153);
+pub const PINK_600: Color = Color::rgb(219, 39, 119);
+pub const PINK_700: Color = Color::rgb(190, 24, 93);
+pub const PINK_800: Color = Color::rgb(157, 23, 77);
+pub const PINK_900: Color = Color::rgb(131, 24, 67);
+pub const PINK_950: Color = Color::rgb(80, 7, 36);
+pub const ROSE_50: Color = Color::rgb(255, 241, 242);
+pub const ROSE_100: Color = Color::rgb(255, 228, 230);
+pub const ROSE_200: Color = Color::rgb(254, 205, 211);
+pub const ROSE_300: Color = Color::rgb(253, 164, 175);
+pub const ROSE_400: Color = Color::rgb(251, 113, 133);
+pub const ROSE_500: Color = Color::rgb(244, 63, 94);
+pub const ROSE_600: Color = Color::rgb(225, 29, 72);
+pub const ROSE_700: Color = Color::rgb(190, 18, 60);
+pub const ROSE_800: Color = Color::rgb(159, 18, 57);
+pub const ROSE_900: Color = Color::rgb(136, 19, 55);
+pub const ROSE_950: Color = Color::rgb(76, 5, 25);
+
+const NAMES: [&str; 242] = [
+    "slate-50",
+    "slate-100",
+    "slate-200",
+    "slate-300",
+    "slate-400",
+    "slate-500",
+    "slate-600",
+    "slate-700",
+    "slate-800",
+    "slate-900",
+    "slate-950",
+    "gray-50",
+    "gray-100",
+    "gray-200",
+    "gray-300",
+    "gray-400",
+    "gray-500",
+    "gray-600",
+    "gray-700",
+    "gray-800",
+    "gray-900",
+    "gray-950",
+    "zinc-50",
+    "zinc-100",
+    "zinc-200",
+    "zinc-300",
+    "zinc-400",
+    "zinc-500",
+    "zinc-600",
+    "zinc-700",
+    "zinc-800",
+    "zinc-900",
+    "zinc-950",
+    "neutral-50",
+    "neutral-100",
+    "neutral-200",
+    "neutral-300",
+    "neutral-400",
+    "neutral-500",
+    "neutral-600",
+    "neutral-700",
+    "neutral-800",
+    "neutral-900",
+    "neutral-950",
+    "stone-50",
+    "stone-100",
+    "stone-200",
+    "stone-300",
+    "stone-400",
+    "stone-500",
+    "stone-600",
+    "stone-700",
+    "stone-800",
+    "stone-900",
+    "stone-950",
+    "red-50",
+    "red-100",
+    "red-200",
+    "red-300",
+    "red-400",
+    "red-500",
+    "red-600",
+    "red-700",
+    "red-800",
+    "red-900",
+    "red-950",
+    "orange-50",
+    "orange-100",
+    "orange-200",
+    "orange-300",
+    "orange-400",
+    "orange-500",
+    "orange-600",
+    "orange-700",
+    "orange-800",
+    "orange-900",
+    "orange-950",
+    "amber-50",
+    "amber-100",
+    "amber-200",
+    "amber-300",
+    "amber-400",
+    "amber-500",
+    "amber-600",
+    "amber-700",
+    "amber-800",
+    "amber-900",
+    "amber-950",
+    "yellow-50",
+    "yellow-100",
+    "yellow-200",
+    "yellow-300",
+    "yellow-400",
+    "yellow-500",
+    "yellow-600",
+    "yellow-700",
+    "yellow-800",
+    "yellow-900",
+    "yellow-950",
+    "lime-50",
+    "lime-100",
+    "lime-200",
+    "lime-300",
+    "lime-400",
+    "lime-500",
+    "lime-600",
+    "lime-700",
+    "lime-800",
+    "lime-900",
+    "lime-950",
+    "green-50",
+    "green-100",
+    "green-200",
+    "green-300",
+    "green-400",
+    "green-500",
+    "green-600",
+    "green-700",
+    "green-800",
+    "green-900",
+    "green-950",
+    "emerald-50",
+    "emerald-100",
+    "emerald-200",
+    "emerald-300",
+    "emerald-400",
+    "emerald-500",
+    "emerald-600",
+    "emerald-700",
+    "emerald-800",
+    "emerald-900",
+    "emerald-950",
+    "teal-50",
+    "teal-100",
+    "teal-200",
+    "teal-300",
+    "teal-400",
+    "teal-500",
+    "teal-600",
+    "teal-700",
+    "teal-800",
+    "teal-900",
+    "teal-950",
+    "cyan-50",
+    "cyan-100",
+    "cyan-200",
+    "cyan-300",
+    "cyan-400",
+    "cyan-500",
+    "cyan-600",
+    "cyan-700",
+    "cyan-800",
+    "cyan-900",
+    "cyan-950",
+    "sky-50",
+    "sky-100",
+    "sky-200",
+    "sky-300",
+    "sky-400",
+    "sky-500",
+    "sky-600",
+    "sky-700",
+    "sky-800",
+    "sky-900",
+    "sky-950",
+    "blue-50",
+    "blue-100",
+    "blue-200",
+    "blue-300",
+    "blue-400",
+    "blue-500",
+    "blue-600",
+    "blue-700",
+    "blue-800",
+    "blue-900",
+    "blue-950",
+    "indigo-50",
+    "indigo-100",
+    "indigo-200",
+    "indigo-300",
+    "indigo-400",
+    "indigo-500",
+    "indigo-600",
+    "indigo-700",
+    "indigo-800",
+    "indigo-900",
+    "indigo-950",
+    "violet-50",
+    "violet-100",
+    "violet-200",
+    "violet-300",
+    "violet-400",
+    "violet-500",
+    "violet-600",
+    "violet-700",
+    "violet-800",
+    "violet-900",
+    "violet-950",
+    "purple-50",
+    "purple-100",
+    "purple-200",
+    "purple-300",
+    "purple-400",
+    "purple-500",
+    "purple-600",
+    "purple-700",
+    "purple-800",
+    "purple-900",
+    "purple-950",
+    "fuchsia-50",
+    "fuchsia-100",
+    "fuchsia-200",
+    "fuchsia-300",
+    "fuchsia-400",
+    "fuchsia-500",
+    "fuchsia-600",
+    "fuchsia-700",
+    "fuchsia-800",
+    "fuchsia-900",
+    "fuchsia-950",
+    "pink-50",
+    "pink-100",
+    "pink-200",
+    "pink-300",
+    "pink-400",
+    "pink-500",
+    "pink-600",
+    "pink-700",
+    "pink-800",
+    "pink-900",
+    "pink-950",
+    "rose-50",
+    "rose-100",
+    "rose-200",
+    "rose-300",
+    "rose-400",
+    "rose-500",
+    "rose-600",
+    "rose-700",
+    "rose-800",
+    "rose-900",
+    "rose-950",
+];
+
+const COLORS: [Color; 242] = [
+    SLATE_50,
+    SLATE_100,
+    SLATE_200,
+    SLATE_300,
+    SLATE_400,
+    SLATE_500,
+    SLATE_600,
+    SLATE_700,
+    SLATE_800,
+    SLATE_900,
+    SLATE_950,
+    GRAY_50,
+    GRAY_100,
+    GRAY_200,
+    GRAY_300,
+    GRAY_400,
+    GRAY_500,
+    GRAY_600,
+    GRAY_700,
+    GRAY_800,
+    GRAY_900,
+    GRAY_950,
+    ZINC_50,
+    ZINC_100,
+    ZINC_200,
+    ZINC_300,
+    ZINC_400,
+    ZINC_500,
+    ZINC_600,
+    ZINC_700,
+    ZINC_800,
+    ZINC_900,
+    ZINC_950,
+    NEUTRAL_50,
+    NEUTRAL_100,
+    NEUTRAL_200,
+    NEUTRAL_300,
+    NEUTRAL_400,
+    NEUTRAL_500,
+    NEUTRAL_600,
+    NEUTRAL_700,
+    NEUTRAL_800,
+    NEUTRAL_900,
+    NEUTRAL_950,
+    STONE_50,
+    STONE_100,
+    STONE_200,
+    STONE_300,
+    STONE_400,
+    STONE_500,
+    STONE_600,
+    STONE_700,
+    STONE_800,
+    STONE_900,
+    STONE_950,
+    RED_50,
+    RED_100,
+    RED_200,
+    RED_300,
+    RED_400,
+    RED_500,
+    RED_600,
+    RED_700,
+    RED_800,
+    RED_900,
+    RED_950,
+    ORANGE_50,
+    ORANGE_100,
+    ORANGE_200,
+    ORANGE_300,
+    ORANGE_400,
+    ORANGE_500,
+    ORANGE_600,
+    ORANGE_700,
+    ORANGE_800,
+    ORANGE_900,
+    ORANGE_950,
+    AMBER_50,
+    AMBER_100,
+    AMBER_200,
+    AMBER_300,
+    AMBER_400,
+    AMBER_500,
+    AMBER_600,
+    AMBER_700,
+    AMBER_800,
+    AMBER_900,
+    AMBER_950,
+    YELLOW_50,
+    YELLOW_100,
+    YELLOW_200,
+    YELLOW_300,
+    YELLOW_400,
+    YELLOW_500,
+    YELLOW_600,
+    YELLOW_700,
+    YELLOW_800,
+    YELLOW_900,
+    YELLOW_950,
+    LIME_50,
+    LIME_100,
+    LIME_200,
+    LIME_300,
+    LIME_400,
+    LIME_500,
+    LIME_600,
+    LIME_700,
+    LIME_800,
+    LIME_900,
+    LIME_950,
+    GREEN_50,
+    GREEN_100,
+    GREEN_200,
+    GREEN_300,
+    GREEN_400,
+    GREEN_500,
+    GREEN_600,
+    GREEN_700,
+    GREEN_800,
+    GREEN_900,
+    GREEN_950,
+    EMERALD_50,
+    EMERALD_100,
+    EMERALD_200,
+    EMERALD_300,
+    EMERALD_400,
+    EMERALD_500,
+    EMERALD_600,
+    EMERALD_700,
+    EMERALD_800,
+    EMERALD_900,
+    EMERALD_950,
+    TEAL_50,
+    TEAL_100,
+    TEAL_200,
+    TEAL_300,
+    TEAL_400,
+    TEAL_500,
+    TEAL_600,
+    TEAL_700,
+    TEAL_800,
+    TEAL_900,
+    TEAL_950,
+    CYAN_50,
+    CYAN_100,
+    CYAN_200,
+    CYAN_300,
+    CYAN_400,
+    CYAN_500,
+    CYAN_600,
+    CYAN_700,
+    CYAN_800,
+    CYAN_900,
+    CYAN_950,
+    SKY_50,
+    SKY_100,
+    SKY_200,
+    SKY_300,
+    SKY_400,
+    SKY_500,
+    SKY_600,
+    SKY_700,
+    SKY_800,
+    SKY_900,
+    SKY_950,
+    BLUE_50,
+    BLUE_100,
+    BLUE_200,
+    BLUE_300,
+    BLUE_400,
+    BLUE_500,
+    BLUE_600,
+    BLUE_700,
+    BLUE_800,
+    BLUE_900,
+    BLUE_950,
+    INDIGO_50,
+    INDIGO_100,
+    INDIGO_200,
+    INDIGO_300,
+    INDIGO_400,
+    INDIGO_500,
+    INDIGO_600,
+    INDIGO_700,
+    INDIGO_800,
+    INDIGO_900,
+    INDIGO_950,
+    VIOLET_50,
+    VIOLET_100,
+    VIOLET_200,
+    VIOLET_300,
+    VIOLET_400,
+    VIOLET_500,
+    VIOLET_600,
+    VIOLET_700,
+    VIOLET_800,
+    VIOLET_900,
+    VIOLET_950,
+    PURPLE_50,
+    PURPLE_100,
+    PURPLE_200,
+    PURPLE_300,
+    PURPLE_400,
+    PURPLE_500,
+    PURPLE_600,
+    PURPLE_700,
+    PURPLE_800,
+    PURPLE_900,
+    PURPLE_950,
+    FUCHSIA_50,
+    FUCHSIA_100,
+    FUCHSIA_200,
+    FUCHSIA_300,
+    FUCHSIA_400,
+    FUCHSIA_500,
+    FUCHSIA_600,
+    FUCHSIA_700,
+    FUCHSIA_800,
+    FUCHSIA_900,
+    FUCHSIA_950,
+    PINK_50,
+    PINK_100,
+    PINK_200,
+    PINK_300,
+    PINK_400,
+    PINK_500,
+    PINK_600,
+    PINK_700,
+    PINK_800,
+    PINK_900,
+    PINK_950,
+    ROSE_50,
+    ROSE_100,
+    ROSE_200,
+    ROSE_300,
+    ROSE_400,
+    ROSE_500,
+    ROSE_600,
+    ROSE_700,
+    ROSE_800,
+    ROSE_900,
+    ROSE_950,
+];
+
+/// Tailwind CSS v3 palette with 11 shades of 22 colors
+pub const PALETTE: Palette = Palette::new("Tailwind", "https://v3.tailwindcss.com/docs/customizing-colors", &NAMES, &COLORS);
@@ -0,0 +1,273 @@
+//! [X11 color names](https://www.w3.org/TR/css-color-4/#named-colors) as used by CSS, plus the web-safe palette.
+#![allow(missing_docs)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use super::Palette;
+use crate::foundation::colorspace::{Color, NAMED_COLORS};
+
+pub const BLACK: Color = named("black");
+pub const SILVER: Color = named("silver");
+pub const GRAY: Color = named("gray");
+pub const WHITE: Color = named("white");
+pub const MAROON: Color = named("maroon");
+pub const RED: Color = named("red");
+pub const PURPLE: Color = named("purple");
+pub const FUCHSIA: Color = named("fuchsia");
+pub const GREEN: Color = named("green");
+pub const LIME: Color = named("lime");
+pub const OLIVE: Color = named("olive");
+pub const YELLOW: Color = named("yellow");
+pub const NAVY: Color = named("navy");
+pub const BLUE: Color = named("blue");
+pub const TEAL: Color = named("teal");
+pub const AQUA: Color = named("aqua");
+pub const ORANGE: Color = named("orange");
+pub const ALICEBLUE: Color = named("aliceblue");
+pub const ANTIQUEWHITE: Color = named("antiquewhite");
+pub const AQUAMARINE: Color = named("aquamarine");
+pub const AZURE: Color = named("azure");
+pub const BEIGE: Color = named("beige");
+pub const BISQUE: Color = named("bisque");
+pub const BLANCHEDALMOND: Color = named("blanchedalmond");
+pub const BLUEVIOLET: Color = named("blueviolet");
+pub const BROWN: Color = named("brown");
+pub const BURLYWOOD: Color = named("burlywood");
+pub const CADETBLUE: Color = named("cadetblue");
+pub const CHARTREUSE: Color = named("chartreuse");
+pub const CHOCOLATE: Color = named("chocolate");
+pub const CORAL: Color = named("coral");
+pub const CORNFLOWERBLUE: Color = named("cornflowerblue");
+pub const CORNSILK: Color = named("cornsilk");
+pub const CRIMSON: Color = named("crimson");
+pub const CYAN: Color = named("cyan");
+pub const DARKBLUE: Color = named("darkblue");
+pub const DARKCYAN: Color = named("darkcyan");
+pub const DARKGOLDENROD: Color = named("darkgoldenrod");
+pub const DARKGRAY: Color = named("darkgray");
+pub const DARKGREEN: Color = named("darkgreen");
+pub const DARKGREY: Color = named("darkgrey");
+pub const DARKKHAKI: Color = named("darkkhaki");
+pub const DARKMAGENTA: Color = named("darkmagenta");
+pub const DARKOLIVEGREEN: Color = named("darkolivegreen");
+pub const DARKORANGE: Color = named("darkorange");
+pub const DARKORCHID: Color = named("darkorchid");
+pub const DARKRED: Color = named("darkred");
+pub const DARKSALMON: Color = named("darksalmon");
+pub const DARKSEAGREEN: Color = named("darkseagreen");
+pub const DARKSLATEBLUE: Color = named("darkslateblue");
+pub const DARKSLATEGRAY: Color = named("darkslategray");
+pub const DARKSLATEGREY: Color = named("darkslategrey");
+pub const DARKTURQUOISE: Color = named("darkturquoise");
+pub const DARKVIOLET: Color = named("darkviolet");
+pub const DEEPPINK: Color = named("deeppink");
+pub const DEEPSKYBLUE: Color = named("deepskyblue");
+pub const DIMGRAY: Color = named("dimgray");
+pub const DIMGREY: Color = named("dimgrey");
+pub const DODGERBLUE: Color = named("dodgerblue");
+pub const FIREBRICK: Color = named("firebrick");
+pub const FLORALWHITE: Color = named("floralwhite");
+pub const FORESTGREEN: Color = named("forestgreen");
+pub const GAINSBORO: Color = named("gainsboro");
+pub const GHOSTWHITE: Color = named("ghostwhite");
+pub const GOLD: Color = named("gold");
+pub const GOLDENROD: Color = named("goldenrod");
+pub const GREENYELLOW: Color = named("greenyellow");
+pub const GREY: Color = named("grey");
+pub const HONEYDEW: Color = named("honeydew");
+pub const HOTPINK: Color = named("hotpink");
+pub const INDIANRED: Color = named("indianred");
+pub const INDIGO: Color = named("indigo");
+pub const IVORY: Color = named("ivory");
+pub const KHAKI: Color = named("khaki");
+pub const LAVENDER: Color = named("lavender");
+pub const LAVENDERBLUSH: Color = named("lavenderblush");
+pub const LAWNGREEN: Color = named("lawngreen");
+pub const LEMONCHIFFON: Color = named("lemonchiffon");
+pub const LIGHTBLUE: Color = named("lightblue");
+pub const LIGHTCORAL: Color = named("lightcoral");
+pub const LIGHTCYAN: Color = named("lightcyan");
+pub const LIGHTGOLDENRODYELLOW: Color = named("lightgoldenrodyellow");
+pub const LIGHTGRAY: Color = named("lightgray");
+pub const LIGHTGREEN: Color = named("lightgreen");
+pub const LIGHTGREY: Color = named("lightgrey");
+pub const LIGHTPINK: Color = named("lightpink");
+pub const LIGHTSALMON: Color = named("lightsalmon");
+pub const LIGHTSEAGREEN: Color = named("lightseagreen");
+pub const LIGHTSKYBLUE: Color = named("lightskyblue");
+pub const LIGHTSLATEGRAY: Color = named("lightslategray");
+pub const LIGHTSLATEGREY: Color = named("lightslategrey");
+pub const LIGHTSTEELBLUE: Color = named("lightsteelblue");
+pub const LIGHTYELLOW: Color = named("lightyellow");
+pub const LIMEGREEN: Color = named("limegreen");
+pub const LINEN: Color = named("linen");
+pub const MAGENTA: Color = named("magenta");
+pub const MEDIUMAQUAMARINE: Color = named("mediumaquamarine");
+pub const MEDIUMBLUE: Color = named("mediumblue");
+pub const MEDIUMORCHID: Color = named("mediumorchid");
+pub const MEDIUMPURPLE: Color = named("mediumpurple");
+pub const MEDIUMSEAGREEN: Color = named("mediumseagreen");
+pub const MEDIUMSLATEBLUE: Color = named("mediumslateblue");
+pub const MEDIUMSPRINGGREEN: Color = named("mediumspringgreen");
+pub const MEDIUMTURQUOISE: Color = named("mediumturquoise");
+pub const MEDIUMVIOLETRED: Color = named("mediumvioletred");
+pub const MIDNIGHTBLUE: Color = named("midnightblue");
+pub const MINTCREAM: Color = named("mintcream");
+pub const MISTYROSE: Color = named("mistyrose");
+pub const MOCCASIN: Color = named("moccasin");
+pub const NAVAJOWHITE: Color = named("navajowhite");
+pub const OLDLACE: Color = named("oldlace");
+pub const OLIVEDRAB: Color = named("olivedrab");
+pub const ORANGERED: Color = named("orangered");
+pub const ORCHID: Color = named("orchid");
+pub const PALEGOLDENROD: Color = named("palegoldenrod");
+pub const PALEGREEN: Color = named("palegreen");
+pub const PALETURQUOISE: Color = named("paleturquoise");
+pub const PALEVIOLETRED: Color = named("palevioletred");
+pub const PAPAYAWHIP: Color = named("papayawhip");
+pub const PEACHPUFF: Color = named("peachpuff");
+pub const PERU: Color = named("peru");
+pub const PINK: Color = named("pink");
+pub const PLUM: Color = named("plum");
+pub const POWDERBLUE: Color = named("powderblue");
+pub const ROSYBROWN: Color = named("rosybrown");
+pub const ROYALBLUE: Color = named("royalblue");
+pub const SADDLEBROWN: Color = named("saddlebrown");
+pub const SALMON: Color = named("salmon");
+pub const SANDYBROWN: Color = named("sandybrown");
+pub const SEAGREEN: Color = named("seagreen");
+pub const SEASHELL: Color = named("seashell");
+pub const SIENNA: Color = named("sienna");
+pub const SKYBLUE: Color = named("skyblue");
+pub const SLATEBLUE: Color = named("slateblue");
+pub const SLATEGRAY: Color = named("slategray");
+pub const SLATEGREY: Color = named("slategrey");
+pub const SNOW: Color = named("snow");
+pub const SPRINGGREEN: Color = named("springgreen");
+pub const STEELBLUE: Color = named("steelblue");
+pub const TAN: Color = named("tan");
+pub const THISTLE: Color = named("thistle");
+pub const TOMATO: Color = named("tomato");
+pub const TURQUOISE: Color = named("turquoise");
+pub const VIOLET: Color = named("violet");
+pub const WHEAT: Color = named("wheat");
+pub const WHITESMOKE: Color = named("whitesmoke");
+pub const YELLOWGREEN: Color = named("yellowgreen");
+pub const REBECCAPURPLE: Color = named("rebeccapurple");
+
+const COUNT: usize = NAMED_COLORS.len();
+
+const NAMES: [&str; COUNT] = names();
+
+const COLORS: [Color; COUNT] = colors();
+
+/// Convert `0xRRGGBB` value of css table
+const fn rgb(value: u32) -> Color {
+    Color::rgb((value >> 16) as u8, (value >> 8) as u8, value as u8)
+}
+
+/// Find color of css name, unknown names fail to compile
+const fn named(name: &str) -> Color {
+    let mut idx = 0;
+    while idx < COUNT {
+        let (candidate, value) = NAMED_COLORS[idx];
+        if equal(candidate.as_bytes(), name.as_bytes()) {
+            return rgb(value);
+        }
+        idx += 1;
+    }
+    panic!("unknown css color name")
+}
+
+const fn equal(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut idx = 0;
+    while idx < left.len() {
+        if left[idx] != right[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+/// Take names of css table in its order
+const fn names() -> [&'static str; COUNT] {
+    let mut names = [""; COUNT];
+    let mut idx = 0;
+    while idx < COUNT {
+        names[idx] = NAMED_COLORS[idx].0;
+        idx += 1;
+    }
+    names
+}
+
+/// Take colors of css table in its order
+const fn colors() -> [Color; COUNT] {
+    let mut colors = [BLACK; COUNT];
+    let mut idx = 0;
+    while idx < COUNT {
+        colors[idx] = rgb(NAMED_COLORS[idx].1);
+        idx += 1;
+    }
+    colors
+}
+
+/// X11 palette with 148 named colors of CSS
+pub const PALETTE: Palette = Palette::new("X11", "https://www.w3.org/TR/css-color-4/#named-colors", &NAMES, &COLORS);
+
+const WEB_SAFE_NAMES: [&str; 216] = [
+    "#000000", "#000033", "#000066", "#000099", "#0000cc", "#0000ff",
+    "#003300", "#003333", "#003366", "#003399", "#0033cc", "#0033ff",
+    "#006600", "#006633", "#006666", "#006699", "#0066cc", "#0066ff",
+    "#009900", "#009933", "#009966", "#009999", "#0099cc", "#0099ff",
+    "#00cc00", "#00cc33", "#00cc66", "#00cc99", "#00cccc", "#00ccff",
+    "#00ff00", "#00ff33", "#00ff66", "#00ff99", "#00ffcc", "#00ffff",
+    "#330000", "#330033", "#330066", "#330099", "#3300cc", "#3300ff",
+    "#333300", "#333333", "#333366", "#333399", "#3333cc", "#3333ff",
+    "#336600", "#336633", "#336666", "#336699", "#3366cc", "#3366ff",
+    "#339900", "#339933", "#339966", "#339999", "#3399cc", "#3399ff",
+    "#33cc00", "#33cc33", "#33cc66", "#33cc99", "#33cccc", "#33ccff",
+    "#33ff00", "#33ff33", "#33ff66", "#33ff99", "#33ffcc", "#33ffff",
+    "#660000", "#660033", "#660066", "#660099", "#6600cc", "#6600ff",
+    "#663300", "#663333", "#663366", "#663399", "#6633cc", "#6633ff",
+    "#666600", "#666633", "#666666", "#666699", "#6666cc", "#6666ff",
+    "#669900", "#669933", "#669966", "#669999", "#6699cc", "#6699ff",
+    "#66cc00", "#66cc33", "#66cc66", "#66cc99", "#66cccc", "#66ccff",
+    "#66ff00", "#66ff33", "#66ff66", "#66ff99", "#66ffcc", "#66ffff",
+    "#990000", "#990033", "#990066", "#990099", "#9900cc", "#9900ff",
+    "#993300", "#993333", "#993366", "#993399", "#9933cc", "#9933ff",
+    "#996600", "#996633", "#996666", "#996699", "#9966cc", "#9966ff",
+    "#999900", "#999933", "#999966", "#999999", "#9999cc", "#9999ff",
+    "#99cc00", "#99cc33", "#99cc66", "#99cc99", "#99cccc", "#99ccff",
+    "#99ff00", "#99ff33", "#99ff66", "#99ff99", "#99ffcc", "#99ffff",
+    "#cc0000", "#cc0033", "#cc0066", "#cc0099", "#cc00cc", "#cc00ff",
+    "#cc3300", "#cc3333", "#cc3366", "#cc3399", "#cc33cc", "#cc33ff",
+    "#cc6600", "#cc6633", "#cc6666", "#cc6699", "#cc66cc", "#cc66ff",
+    "#cc9900", "#cc9933", "#cc9966", "#cc9999", "#cc99cc", "#cc99ff",
+    "#cccc00", "#cccc33", "#cccc66", "#cccc99", "#cccccc", "#ccccff",
+    "#ccff00", "#ccff33", "#ccff66", "#ccff99", "#ccffcc", "#ccffff",
+    "#ff0000", "#ff0033", "#ff0066", "#ff0099", "#ff00cc", "#ff00ff",
+    "#ff3300", "#ff3333", "#ff3366", "#ff3399", "#ff33cc", "#ff33ff",
+    "#ff6600", "#ff6633", "#ff6666", "#ff6699", "#ff66cc", "#ff66ff",
+    "#ff9900", "#ff9933", "#ff9966", "#ff9999", "#ff99cc", "#ff99ff",
+    "#ffcc00", "#ffcc33", "#ffcc66", "#ffcc99", "#ffcccc", "#ffccff",
+    "#ffff00", "#ffff33", "#ffff66", "#ffff99", "#ffffcc", "#ffffff",
+];
+
+const WEB_SAFE_COLORS: [Color; 216] = web_safe_colors();
+
+/// Build the 6x6x6 cube of web-safe colors
+const fn web_safe_colors() -> [Color; 216] {
+    let mut colors = [BLACK; 216];
+    let mut idx = 0;
+    while idx < 216 {
+        colors[idx] = Color::rgb((idx / 36) as u8 * 51, (idx / 6 % 6) as u8 * 51, (idx % 6) as u8 * 51);
+        idx += 1;
+    }
+    colors
+}
+
+/// Web-safe palette with 216 colors of 6x6x6 cube, named by hex code
+pub const WEB_SAFE: Palette = Palette::new("Web-safe", "https://en.wikipedia.org/wiki/Web_colors#Web-safe_colors", &WEB_SAFE_NAMES, &WEB_SAFE_COLORS);
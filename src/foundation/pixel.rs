@@ -0,0 +1,292 @@
+use super::{colorspace::Color, PixelFormat};
+
+/// Byte order used to store multi-byte pixels
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Endian {
+    /// Byte order of the target platform, used by most graphic backends
+    #[default]
+    Native,
+    /// Least significant byte first
+    Little,
+    /// Most significant byte first
+    Big,
+}
+
+impl Endian {
+    /// Check if bytes are stored least significant first
+    pub fn is_little(&self) -> bool {
+        match self {
+            Self::Native => cfg!(target_endian = "little"),
+            Self::Little => true,
+            Self::Big => false,
+        }
+    }
+}
+
+/// Order of 8-bit channels in memory for 4-byte pixels.
+///
+/// Unlike [PixelFormat::ARgb32] these orders are independent of platform endianness
+/// and keep straight (not premultiplied) alpha, e.g. `Rgba` is the layout of PNG or WebGL textures.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChannelOrder {
+    /// Alpha, red, green and blue bytes
+    Argb,
+    /// Red, green, blue and alpha bytes
+    #[default]
+    Rgba,
+    /// Blue, green, red and alpha bytes
+    Bgra,
+    /// Alpha, blue, green and red bytes
+    Abgr,
+}
+
+impl ChannelOrder {
+    /// Encode color into bytes in this order
+    pub fn encode(&self, color: Color) -> [u8; 4] {
+        let [r, g, b, a] = color.cast::<u8>().to_array();
+        match self {
+            Self::Argb => [a, r, g, b],
+            Self::Rgba => [r, g, b, a],
+            Self::Bgra => [b, g, r, a],
+            Self::Abgr => [a, b, g, r],
+        }
+    }
+
+    /// Decode color from bytes in this order
+    pub fn decode(&self, bytes: [u8; 4]) -> Color {
+        let [r, g, b, a] = match self {
+            Self::Argb => [bytes[1], bytes[2], bytes[3], bytes[0]],
+            Self::Rgba => bytes,
+            Self::Bgra => [bytes[2], bytes[1], bytes[0], bytes[3]],
+            Self::Abgr => [bytes[3], bytes[2], bytes[1], bytes[0]],
+        };
+        Color::rgba(r, g, b, a)
+    }
+}
+
+/// Quantize normalized value to unsigned integer with `bits` width
+fn quantize(value: f32, bits: u32) -> u32 {
+    let max = (1u32 << bits) - 1;
+    (value.clamp(0.0, 1.0) * max as f32).round() as u32
+}
+
+/// Expand unsigned integer with `bits` width to normalized value
+fn expand(value: u32, bits: u32) -> f32 {
+    let max = (1u32 << bits) - 1;
+    (value & max) as f32 / max as f32
+}
+
+impl PixelFormat {
+    /// Retrieve count of bits used by one pixel
+    pub fn bits_per_pixel(&self) -> usize {
+        match self {
+            Self::Invalid => 0,
+            Self::ARgb32 | Self::Rgb24 | Self::Rgb30 => 32,
+            Self::Rgb16_565 => 16,
+            Self::A8 => 8,
+            Self::A1 => 1,
+        }
+    }
+
+    /// Retrieve count of bytes used by one pixel, `0` for formats smaller than byte
+    pub fn bytes_per_pixel(&self) -> usize {
+        self.bits_per_pixel() / 8
+    }
+
+    /// Retrieve count of bytes used by pixels of one row, without padding
+    pub fn row_bytes(&self, width: u32) -> usize {
+        (width as usize * self.bits_per_pixel()).div_ceil(8)
+    }
+
+    /// Retrieve minimal row stride in bytes for the width, rows are aligned to 4 bytes
+    pub fn stride_for_width(&self, width: u32) -> usize {
+        (self.row_bytes(width) + 3) & !3
+    }
+
+    /// Check if format stores color channels
+    pub fn has_color(&self) -> bool {
+        !matches!(self, Self::Invalid | Self::A8 | Self::A1)
+    }
+
+    /// Check if format stores alpha channel
+    pub fn has_alpha(&self) -> bool {
+        matches!(self, Self::ARgb32 | Self::A8 | Self::A1)
+    }
+
+    /// Encode color into pixel bits, stored in the lowest `bits_per_pixel` bits.
+    ///
+    /// Follows the layout of cairo image surfaces:
+    ///
+    /// * `ARgb32` alpha in upper 8 bits, then red, green and blue premultiplied by alpha.
+    /// * `Rgb24` 8 bits per channel, upper 8 bits unused.
+    /// * `A8`, `A1` alpha only, colors are dropped.
+    /// * `Rgb16_565` 5 bits of red, 6 bits of green and 5 bits of blue.
+    /// * `Rgb30` 10 bits per channel, upper 2 bits unused.
+    pub fn encode(&self, color: Color) -> u32 {
+        match self {
+            Self::Invalid => 0,
+            Self::ARgb32 => {
+                let alpha = color.alpha.clamp(0.0, 1.0);
+                quantize(alpha, 8) << 24
+                    | quantize(color.red * alpha, 8) << 16
+                    | quantize(color.green * alpha, 8) << 8
+                    | quantize(color.blue * alpha, 8)
+            }
+            Self::Rgb24 => {
+                quantize(color.red, 8) << 16 | quantize(color.green, 8) << 8 | quantize(color.blue, 8)
+            }
+            Self::A8 => quantize(color.alpha, 8),
+            Self::A1 => (color.alpha >= 0.5) as u32,
+            Self::Rgb16_565 => {
+                quantize(color.red, 5) << 11 | quantize(color.green, 6) << 5 | quantize(color.blue, 5)
+            }
+            Self::Rgb30 => {
+                quantize(color.red, 10) << 20
+                    | quantize(color.green, 10) << 10
+                    | quantize(color.blue, 10)
+            }
+        }
+    }
+
+    /// Decode color from pixel bits, see [PixelFormat::encode] for layouts.
+    ///
+    /// Formats without alpha decode opaque colors, alpha only formats decode black.
+    pub fn decode(&self, bits: u32) -> Color {
+        match self {
+            Self::Invalid => Color::new(0.0, 0.0, 0.0, 0.0),
+            Self::ARgb32 => {
+                let alpha = expand(bits >> 24, 8);
+                let channel = |value: u32| {
+                    if alpha > 0.0 {
+                        (expand(value, 8) / alpha).min(1.0)
+                    } else {
+                        0.0
+                    }
+                };
+                Color::new(channel(bits >> 16), channel(bits >> 8), channel(bits), alpha)
+            }
+            Self::Rgb24 => Color::new(
+                expand(bits >> 16, 8),
+                expand(bits >> 8, 8),
+                expand(bits, 8),
+                1.0,
+            ),
+            Self::A8 => Color::new(0.0, 0.0, 0.0, expand(bits, 8)),
+            Self::A1 => Color::new(0.0, 0.0, 0.0, expand(bits, 1)),
+            Self::Rgb16_565 => Color::new(
+                expand(bits >> 11, 5),
+                expand(bits >> 5, 6),
+                expand(bits, 5),
+                1.0,
+            ),
+            Self::Rgb30 => Color::new(
+                expand(bits >> 20, 10),
+                expand(bits >> 10, 10),
+                expand(bits, 10),
+                1.0,
+            ),
+        }
+    }
+
+    /// Write encoded pixel into first `bytes_per_pixel` bytes of `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is too short or format is smaller than byte (`A1`, `Invalid`),
+    /// such pixels are packed by bits, use [PixelFormat::encode] instead.
+    pub fn write_pixel(&self, color: Color, endian: Endian, dst: &mut [u8]) {
+        let size = self.bytes_per_pixel();
+        assert!(size > 0, "{:?} pixels are not byte addressable", self);
+
+        let bits = self.encode(color);
+        if endian.is_little() {
+            dst[..size].copy_from_slice(&bits.to_le_bytes()[..size]);
+        } else {
+            dst[..size].copy_from_slice(&bits.to_be_bytes()[4 - size..]);
+        }
+    }
+
+    /// Read pixel from first `bytes_per_pixel` bytes of `src` and decode it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` is too short or format is smaller than byte (`A1`, `Invalid`).
+    pub fn read_pixel(&self, src: &[u8], endian: Endian) -> Color {
+        let size = self.bytes_per_pixel();
+        assert!(size > 0, "{:?} pixels are not byte addressable", self);
+
+        let mut bytes = [0u8; 4];
+        let bits = if endian.is_little() {
+            bytes[..size].copy_from_slice(&src[..size]);
+            u32::from_le_bytes(bytes)
+        } else {
+            bytes[4 - size..].copy_from_slice(&src[..size]);
+            u32::from_be_bytes(bytes)
+        };
+        self.decode(bits)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_formats() {
+        let color = Color::rgba(255, 128, 0, 128);
+
+        assert_eq!(PixelFormat::ARgb32.encode(color), 0x8080_4000);
+        assert_eq!(PixelFormat::Rgb24.encode(color), 0x00ff_8000);
+        assert_eq!(PixelFormat::A8.encode(color), 0x80);
+        assert_eq!(PixelFormat::A1.encode(color), 1);
+        assert_eq!(PixelFormat::Rgb16_565.encode(color), 0xf800 | 32 << 5);
+        assert_eq!(PixelFormat::Rgb30.encode(color), 0x3ff << 20 | 514 << 10);
+    }
+
+    #[test]
+    fn decode_roundtrip() {
+        let color = Color::rgb(18, 184, 134);
+        for format in [PixelFormat::ARgb32, PixelFormat::Rgb24, PixelFormat::Rgb30].iter() {
+            let decoded = format.decode(format.encode(color));
+            assert_eq!(decoded.cast::<u8>(), color.cast::<u8>(), "{:?}", format);
+        }
+
+        let decoded = PixelFormat::Rgb16_565.decode(PixelFormat::Rgb16_565.encode(color));
+        assert!((decoded.green - color.green).abs() < 1.0 / 63.0);
+
+        let translucent = PixelFormat::ARgb32.decode(0x8040_2000);
+        assert_eq!(translucent.cast::<u8>().to_array(), [128, 64, 0, 128]);
+    }
+
+    #[test]
+    fn endianness() {
+        let color = Color::rgba(1, 2, 3, 255);
+        let mut little = [0u8; 4];
+        let mut big = [0u8; 4];
+        PixelFormat::ARgb32.write_pixel(color, Endian::Little, &mut little);
+        PixelFormat::ARgb32.write_pixel(color, Endian::Big, &mut big);
+        assert_eq!(little, [3, 2, 1, 255]);
+        assert_eq!(big, [255, 1, 2, 3]);
+        assert_eq!(PixelFormat::ARgb32.read_pixel(&big, Endian::Big), color);
+
+        let mut short = [0u8; 2];
+        PixelFormat::Rgb16_565.write_pixel(Color::rgb(255, 0, 0), Endian::Big, &mut short);
+        assert_eq!(short, [0xf8, 0x00]);
+        assert_eq!(PixelFormat::Rgb16_565.stride_for_width(3), 8);
+        assert_eq!(PixelFormat::A1.stride_for_width(33), 8);
+    }
+
+    #[test]
+    fn channel_orders() {
+        let color = Color::rgba(1, 2, 3, 4);
+        assert_eq!(ChannelOrder::Rgba.encode(color), [1, 2, 3, 4]);
+        assert_eq!(ChannelOrder::Bgra.encode(color), [3, 2, 1, 4]);
+        assert_eq!(ChannelOrder::Abgr.encode(color), [4, 3, 2, 1]);
+        assert_eq!(ChannelOrder::Argb.encode(color), [4, 1, 2, 3]);
+        for order in [ChannelOrder::Argb, ChannelOrder::Rgba, ChannelOrder::Bgra, ChannelOrder::Abgr].iter() {
+            assert_eq!(order.decode(order.encode(color)), color);
+        }
+    }
+}
@@ -0,0 +1,841 @@
+//! Software rasterizer drawing canvas operations into image data.
+//!
+//! [RasterCanvas] implements [CanvasContext] on the CPU, which makes it
+//! a reference backend for rendering tests without a window or GPU.
+
+use std::cell::RefCell;
+
+use crate::{
+    foundation::{
+        colorspace::{BlendMode, Color},
+        BaseLine, FontStyle, FontWeight, Gradient, ImageData, ImageDataMut, PixelFormat,
+    },
+    prelude::{CanvasContext, Direction, LineCap, LineJoin, TextAlign, TextMetrics},
+};
+
+mod paint;
+pub use paint::*;
+
+mod path;
+use path::Path;
+
+mod rasterize;
+pub use rasterize::FillRule;
+use rasterize::{rasterize, Mask};
+
+mod stroke;
+use stroke::{stroke, StrokeStyle};
+
+mod transform;
+pub use transform::*;
+
+/// Drawing state saved and restored by `save` and `restore`
+#[derive(Debug, Clone)]
+struct State {
+    transform: Transform,
+    fill: Paint,
+    stroke: Paint,
+    line_width: f64,
+    line_cap: LineCap,
+    line_join: LineJoin,
+    miter_limit: f64,
+    line_dash: Vec<f64>,
+    line_dash_offset: f64,
+    global_alpha: f64,
+    composite: BlendMode,
+    image_smoothing: bool,
+    font_family: String,
+    font_style: FontStyle,
+    font_weight: FontWeight,
+    font_size: f64,
+    text_align: TextAlign,
+    text_baseline: BaseLine,
+    direction: Direction,
+    filter: String,
+    shadow_blur: f64,
+    shadow_color: Color,
+    shadow_offset_x: f64,
+    shadow_offset_y: f64,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            transform: Transform::IDENTITY,
+            fill: Paint::default(),
+            stroke: Paint::default(),
+            line_width: 1.0,
+            line_cap: LineCap::default(),
+            line_join: LineJoin::default(),
+            miter_limit: 10.0,
+            line_dash: Vec::new(),
+            line_dash_offset: 0.0,
+            global_alpha: 1.0,
+            composite: BlendMode::Normal,
+            image_smoothing: true,
+            font_family: "sans-serif".into(),
+            font_style: FontStyle::default(),
+            font_weight: FontWeight::default(),
+            font_size: 10.0,
+            text_align: TextAlign::default(),
+            text_baseline: BaseLine::default(),
+            direction: Direction::Inherit,
+            filter: "none".into(),
+            shadow_blur: 0.0,
+            shadow_color: Color::rgba(0, 0, 0, 0),
+            shadow_offset_x: 0.0,
+            shadow_offset_y: 0.0,
+        }
+    }
+}
+
+/// Canvas rendering into [ImageDataMut] on the CPU.
+///
+/// Paths are flattened and filled with anti-aliasing using nonzero or even-odd rule,
+/// strokes support caps, joins and dashes, paints are colors, gradients and image patterns,
+/// all drawn with global alpha and composite operation of canvas.
+///
+/// Text is not rasterized, `measure_text` only estimates its size from font size.
+/// Shadows, filters and `direction` are kept in state but not rendered.
+/// Composite operation `copy` replaces only pixels covered by the shape.
+#[derive(Debug)]
+pub struct RasterCanvas {
+    image: RefCell<ImageDataMut>,
+    state: RefCell<State>,
+    stack: RefCell<Vec<State>>,
+    path: RefCell<Path>,
+}
+
+impl RasterCanvas {
+    /// Create transparent canvas in `ARgb32` format
+    pub fn new(width: u32, height: u32) -> Self {
+        Self::with_image(ImageDataMut::new(PixelFormat::ARgb32, width, height))
+    }
+
+    /// Create canvas drawing onto existing image of any format
+    pub fn with_image(image: ImageDataMut) -> Self {
+        Self {
+            image: RefCell::new(image),
+            state: Default::default(),
+            stack: Default::default(),
+            path: Default::default(),
+        }
+    }
+
+    /// Retrieve canvas width
+    pub fn width(&self) -> u32 {
+        self.image.borrow().width
+    }
+
+    /// Retrieve canvas height
+    pub fn height(&self) -> u32 {
+        self.image.borrow().height
+    }
+
+    /// Copy current content of canvas
+    pub fn image(&self) -> ImageData {
+        self.image.borrow().clone().freeze()
+    }
+
+    /// Convert into rendered image without copying
+    pub fn into_image(self) -> ImageData {
+        self.image.into_inner().freeze()
+    }
+
+    /// Retrieve current transformation
+    pub fn current_transform(&self) -> Transform {
+        self.state.borrow().transform
+    }
+
+    /// Fill current path with fill rule, `fill` uses nonzero rule
+    pub fn fill_with_rule(&self, rule: FillRule) {
+        let path = self.path.borrow();
+        let polygons = path.subpaths.iter().map(|subpath| &subpath.points[..]);
+        self.draw(polygons, rule, false);
+    }
+
+    fn stroke_path(&self, path: &Path) {
+        let polygons = {
+            let state = self.state.borrow();
+            if state.line_width <= 0.0 {
+                return;
+            }
+            let style = StrokeStyle {
+                width: state.line_width,
+                cap: state.line_cap,
+                join: state.line_join,
+                miter_limit: state.miter_limit,
+                dash: state.line_dash.clone(),
+                dash_offset: state.line_dash_offset,
+            };
+            stroke(path, &style, &state.transform)
+        };
+        self.draw(
+            polygons.iter().map(|polygon| &polygon[..]),
+            FillRule::NonZero,
+            true,
+        );
+    }
+
+    fn coverage<'a, I>(&self, polygons: I, rule: FillRule) -> Option<Mask>
+    where
+        I: IntoIterator<Item = &'a [(f64, f64)]>,
+    {
+        let image = self.image.borrow();
+        rasterize(polygons, rule, image.width, image.height)
+    }
+
+    /// Composite paint of fill or stroke inside polygons in device space
+    fn draw<'a, I>(&self, polygons: I, rule: FillRule, stroke: bool)
+    where
+        I: IntoIterator<Item = &'a [(f64, f64)]>,
+    {
+        let mask = match self.coverage(polygons, rule) {
+            Some(mask) => mask,
+            None => return,
+        };
+        let state = self.state.borrow();
+        let paint = if stroke { &state.stroke } else { &state.fill };
+        let shader = Shader::new(paint, &state.transform, state.image_smoothing);
+        let global_alpha = state.global_alpha as f32;
+        let mut image = self.image.borrow_mut();
+
+        for (x, y, coverage) in mask.pixels() {
+            let mut source = shader.color_at(x as f64 + 0.5, y as f64 + 0.5);
+            source.alpha *= global_alpha;
+            // pixels missing in data are skipped
+            let backdrop = match image.try_get_pixel(x, y) {
+                Some(color) => color,
+                None => continue,
+            };
+            let color = if state.composite == BlendMode::Copy {
+                mix(backdrop, source, coverage)
+            } else {
+                source.alpha *= coverage;
+                source.blend(backdrop, state.composite)
+            };
+            image.set_pixel(x, y, color);
+        }
+    }
+
+    fn rect_path(&self, x: f64, y: f64, width: f64, height: f64) -> Path {
+        let mut path = Path::default();
+        path.rect(&self.state.borrow().transform, x, y, width, height);
+        path
+    }
+}
+
+/// Interpolate premultiplied colors
+fn mix(from: Color, to: Color, weight: f32) -> Color {
+    let from_alpha = from.alpha * (1.0 - weight);
+    let to_alpha = to.alpha * weight;
+    let alpha = from_alpha + to_alpha;
+    if alpha <= 0.0 {
+        return Color::new(0.0, 0.0, 0.0, 0.0);
+    }
+    let channel = |from: f32, to: f32| (from * from_alpha + to * to_alpha) / alpha;
+    Color::new(
+        channel(from.red, to.red),
+        channel(from.green, to.green),
+        channel(from.blue, to.blue),
+        alpha,
+    )
+}
+
+impl CanvasContext for RasterCanvas {
+    type Pattern = ImagePattern;
+
+    fn get_direction(&self) -> Direction {
+        self.state.borrow().direction
+    }
+
+    fn set_direction(&self, value: Direction) -> String {
+        self.state.borrow_mut().direction = value;
+        match value {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+            Direction::Inherit => "inherit",
+        }
+        .into()
+    }
+
+    fn set_fill_color(&self, value: Color) {
+        self.state.borrow_mut().fill = Paint::Color(value);
+    }
+
+    fn set_fill_gradient(&self, value: &Gradient) {
+        self.state.borrow_mut().fill = Paint::Gradient(value.clone());
+    }
+
+    fn set_fill_pattern(&self, value: &Self::Pattern) {
+        self.state.borrow_mut().fill = Paint::Pattern(value.clone());
+    }
+
+    fn get_filter(&self) -> String {
+        self.state.borrow().filter.clone()
+    }
+
+    fn set_filter(&self, value: &str) {
+        self.state.borrow_mut().filter = value.into();
+    }
+
+    fn get_font(&self) -> String {
+        let state = self.state.borrow();
+        let mut font = String::new();
+        match state.font_style {
+            FontStyle::Italic => font.push_str("italic "),
+            FontStyle::Oblique => font.push_str("oblique "),
+            FontStyle::Normal => {}
+        }
+        if state.font_weight != FontWeight::Normal {
+            font.push_str(&format!("{} ", state.font_weight as i32));
+        }
+        font.push_str(&format!("{}px {}", state.font_size, state.font_family));
+        font
+    }
+
+    fn set_font(&self, family: &str, style: FontStyle, weight: FontWeight, size: f64) {
+        let mut state = self.state.borrow_mut();
+        state.font_family = family.into();
+        state.font_style = style;
+        state.font_weight = weight;
+        state.font_size = size;
+    }
+
+    fn get_global_alpha(&self) -> f64 {
+        self.state.borrow().global_alpha
+    }
+
+    fn set_global_alpha(&self, value: f64) {
+        if (0.0..=1.0).contains(&value) {
+            self.state.borrow_mut().global_alpha = value;
+        }
+    }
+
+    fn get_global_composite_operation(&self) -> String {
+        self.state.borrow().composite.as_str().into()
+    }
+
+    fn set_global_composite_operation(&self, value: &str) {
+        if let Ok(mode) = value.parse() {
+            self.state.borrow_mut().composite = mode;
+        }
+    }
+
+    fn is_image_smoothing_enabled(&self) -> bool {
+        self.state.borrow().image_smoothing
+    }
+
+    fn set_image_smoothing(&self, value: bool) {
+        self.state.borrow_mut().image_smoothing = value;
+    }
+
+    fn get_line_cap(&self) -> LineCap {
+        self.state.borrow().line_cap
+    }
+
+    fn set_line_cap(&self, value: LineCap) {
+        self.state.borrow_mut().line_cap = value;
+    }
+
+    fn get_line_dash_offset(&self) -> f64 {
+        self.state.borrow().line_dash_offset
+    }
+
+    fn set_line_dash_offset(&self, value: f64) {
+        if value.is_finite() {
+            self.state.borrow_mut().line_dash_offset = value;
+        }
+    }
+
+    fn get_line_join(&self) -> LineJoin {
+        self.state.borrow().line_join
+    }
+
+    fn set_line_join(&self, value: LineJoin) {
+        self.state.borrow_mut().line_join = value;
+    }
+
+    fn get_line_width(&self) -> f64 {
+        self.state.borrow().line_width
+    }
+
+    fn set_line_width(&self, value: f64) {
+        if value > 0.0 && value.is_finite() {
+            self.state.borrow_mut().line_width = value;
+        }
+    }
+
+    fn get_miter_limit(&self) -> f64 {
+        self.state.borrow().miter_limit
+    }
+
+    fn set_miter_limit(&self, value: f64) {
+        if value > 0.0 && value.is_finite() {
+            self.state.borrow_mut().miter_limit = value;
+        }
+    }
+
+    fn get_shadow_blur(&self) -> f64 {
+        self.state.borrow().shadow_blur
+    }
+
+    fn set_shadow_blur(&self, value: f64) {
+        if value >= 0.0 && value.is_finite() {
+            self.state.borrow_mut().shadow_blur = value;
+        }
+    }
+
+    fn get_shadow_color(&self) -> Color {
+        self.state.borrow().shadow_color
+    }
+
+    fn set_shadow_color(&self, value: Color) {
+        self.state.borrow_mut().shadow_color = value;
+    }
+
+    fn get_shadow_offset_x(&self) -> f64 {
+        self.state.borrow().shadow_offset_x
+    }
+
+    fn set_shadow_offset_x(&self, value: f64) {
+        self.state.borrow_mut().shadow_offset_x = value;
+    }
+
+    fn get_shadow_offset_y(&self) -> f64 {
+        self.state.borrow().shadow_offset_y
+    }
+
+    fn set_shadow_offset_y(&self, value: f64) {
+        self.state.borrow_mut().shadow_offset_y = value;
+    }
+
+    fn set_stroke_color(&self, value: Color) {
+        self.state.borrow_mut().stroke = Paint::Color(value);
+    }
+
+    fn set_stroke_gradient(&self, value: &Gradient) {
+        self.state.borrow_mut().stroke = Paint::Gradient(value.clone());
+    }
+
+    fn set_stroke_pattern(&self, value: &Self::Pattern) {
+        self.state.borrow_mut().stroke = Paint::Pattern(value.clone());
+    }
+
+    fn get_text_align(&self) -> TextAlign {
+        self.state.borrow().text_align
+    }
+
+    fn set_text_align(&self, value: TextAlign) {
+        self.state.borrow_mut().text_align = value;
+    }
+
+    fn get_text_baseline(&self) -> BaseLine {
+        self.state.borrow().text_baseline
+    }
+
+    fn set_text_baseline(&self, value: BaseLine) {
+        self.state.borrow_mut().text_baseline = value;
+    }
+
+    fn arc(
+        &self,
+        x: f64,
+        y: f64,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+        anticlockwise: bool,
+    ) {
+        self.ellipse(
+            x,
+            y,
+            radius,
+            radius,
+            0.0,
+            start_angle,
+            end_angle,
+            anticlockwise,
+        );
+    }
+
+    fn arc_to(&self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) {
+        let transform = self.current_transform();
+        self.path
+            .borrow_mut()
+            .arc_to(&transform, (x1, y1), (x2, y2), radius);
+    }
+
+    fn begin_path(&self) {
+        self.path.borrow_mut().clear();
+    }
+
+    fn bezier_curve_to(&self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
+        let transform = self.current_transform();
+        self.path
+            .borrow_mut()
+            .cubic_to(&transform, [(cp1x, cp1y), (cp2x, cp2y), (x, y)]);
+    }
+
+    fn clear_rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        let path = self.rect_path(x, y, width, height);
+        let polygons = path.subpaths.iter().map(|subpath| &subpath.points[..]);
+        if let Some(mask) = self.coverage(polygons, FillRule::NonZero) {
+            let mut image = self.image.borrow_mut();
+            for (x, y, coverage) in mask.pixels() {
+                let mut color = match image.try_get_pixel(x, y) {
+                    Some(color) => color,
+                    None => continue,
+                };
+                color.alpha *= 1.0 - coverage;
+                if color.alpha <= 0.0 {
+                    color = Color::new(0.0, 0.0, 0.0, 0.0);
+                }
+                image.set_pixel(x, y, color);
+            }
+        }
+    }
+
+    fn close_path(&self) {
+        self.path.borrow_mut().close();
+    }
+
+    fn ellipse(
+        &self,
+        x: f64,
+        y: f64,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+        anticlockwise: bool,
+    ) {
+        let transform = self.current_transform();
+        self.path.borrow_mut().ellipse(
+            &transform,
+            (x, y),
+            (radius_x, radius_y),
+            rotation,
+            start_angle,
+            end_angle,
+            anticlockwise,
+        );
+    }
+
+    fn fill(&self) {
+        self.fill_with_rule(FillRule::NonZero);
+    }
+
+    fn fill_rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        let path = self.rect_path(x, y, width, height);
+        let polygons = path.subpaths.iter().map(|subpath| &subpath.points[..]);
+        self.draw(polygons, FillRule::NonZero, false);
+    }
+
+    fn fill_text(&self, _text: &str, _x: f64, _y: f64) {}
+
+    fn get_line_dash(&self) -> Vec<f64> {
+        self.state.borrow().line_dash.clone()
+    }
+
+    fn line_to(&self, x: f64, y: f64) {
+        let transform = self.current_transform();
+        self.path.borrow_mut().line_to(&transform, x, y);
+    }
+
+    fn measure_text(&self, text: &str) -> TextMetrics {
+        let size = self.state.borrow().font_size;
+        TextMetrics {
+            width: text.chars().count() as f64 * size / 2.0,
+            height: size,
+        }
+    }
+
+    fn move_to(&self, x: f64, y: f64) {
+        let transform = self.current_transform();
+        self.path.borrow_mut().move_to(&transform, x, y);
+    }
+
+    fn quadratic_curve_to(&self, cpx: f64, cpy: f64, x: f64, y: f64) {
+        let transform = self.current_transform();
+        self.path
+            .borrow_mut()
+            .quadratic_to(&transform, cpx, cpy, x, y);
+    }
+
+    fn rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        let transform = self.current_transform();
+        let mut path = self.path.borrow_mut();
+        path.rect(&transform, x, y, width, height);
+        path.move_to(&transform, x, y);
+    }
+
+    fn reset_transform(&self) {
+        self.state.borrow_mut().transform = Transform::IDENTITY;
+    }
+
+    fn restore(&self) {
+        if let Some(state) = self.stack.borrow_mut().pop() {
+            *self.state.borrow_mut() = state;
+        }
+    }
+
+    fn rotate(&self, angle: f64) {
+        self.transform_with(Transform::rotation(angle));
+    }
+
+    fn save(&self) {
+        let state = self.state.borrow().clone();
+        self.stack.borrow_mut().push(state);
+    }
+
+    fn scale(&self, x: f64, y: f64) {
+        self.transform_with(Transform::scaling(x, y));
+    }
+
+    fn set_line_dash(&self, dash: &[f64]) {
+        // like canvas, invalid lists are ignored and odd lists are repeated
+        if dash.iter().any(|value| *value < 0.0 || !value.is_finite()) {
+            return;
+        }
+        let mut dash = dash.to_vec();
+        if dash.len() % 2 == 1 {
+            dash.extend_from_within(..);
+        }
+        self.state.borrow_mut().line_dash = dash;
+    }
+
+    fn set_transform(&self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) {
+        self.state.borrow_mut().transform = Transform::new(a, b, c, d, e, f);
+    }
+
+    fn stroke(&self) {
+        let path = self.path.borrow().clone();
+        self.stroke_path(&path);
+    }
+
+    fn stroke_rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        let path = self.rect_path(x, y, width, height);
+        self.stroke_path(&path);
+    }
+
+    fn stroke_text(&self, _text: &str, _x: f64, _y: f64) {}
+
+    fn transform(&self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) {
+        self.transform_with(Transform::new(a, b, c, d, e, f));
+    }
+
+    fn translate(&self, x: f64, y: f64) {
+        self.transform_with(Transform::translation(x, y));
+    }
+}
+
+impl RasterCanvas {
+    /// Apply transformation before the current one
+    fn transform_with(&self, transform: Transform) {
+        let mut state = self.state.borrow_mut();
+        state.transform = transform.then(&state.transform);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::{ColorStop, GradientType, LinearGradient};
+    use std::f64::consts::PI;
+
+    fn bytes(canvas: &RasterCanvas, x: u32, y: u32) -> [u8; 4] {
+        canvas.image().get_pixel(x, y).cast::<u8>().to_array()
+    }
+
+    fn alpha(canvas: &RasterCanvas, x: u32, y: u32) -> u8 {
+        bytes(canvas, x, y)[3]
+    }
+
+    #[test]
+    fn fill_paths() {
+        let canvas = RasterCanvas::new(20, 20);
+        canvas.set_fill_color(Color::rgb(255, 0, 0));
+        canvas.fill_rect(2.0, 2.0, 4.0, 4.0);
+        assert_eq!(bytes(&canvas, 2, 2), [255, 0, 0, 255]);
+        assert_eq!(bytes(&canvas, 5, 5), [255, 0, 0, 255]);
+        assert_eq!(alpha(&canvas, 6, 6), 0);
+        assert_eq!(alpha(&canvas, 1, 3), 0);
+
+        // anti-aliased edge covers half of pixels
+        canvas.fill_rect(10.5, 0.0, 5.0, 1.0);
+        assert_eq!(alpha(&canvas, 10, 0), 128);
+        assert_eq!(alpha(&canvas, 11, 0), 255);
+
+        // circle covers its area
+        let canvas = RasterCanvas::new(20, 20);
+        canvas.begin_path();
+        canvas.arc(10.0, 10.0, 8.0, 0.0, 2.0 * PI, false);
+        canvas.fill();
+        let area: f64 = canvas
+            .image()
+            .pixels()
+            .map(|color| color.alpha as f64)
+            .sum();
+        // flattening keeps polygon inside of circle
+        assert!(area < PI * 64.0 && area > PI * 64.0 * 0.99, "{}", area);
+        assert_eq!(alpha(&canvas, 10, 10), 255);
+        assert_eq!(alpha(&canvas, 0, 0), 0);
+    }
+
+    #[test]
+    fn fill_rules() {
+        let canvas = RasterCanvas::new(10, 10);
+        canvas.rect(0.0, 0.0, 10.0, 10.0);
+        canvas.rect(3.0, 3.0, 4.0, 4.0);
+        canvas.fill_with_rule(FillRule::EvenOdd);
+        assert_eq!(alpha(&canvas, 1, 1), 255);
+        assert_eq!(alpha(&canvas, 5, 5), 0);
+        canvas.fill();
+        assert_eq!(alpha(&canvas, 5, 5), 255);
+    }
+
+    #[test]
+    fn strokes() {
+        let canvas = RasterCanvas::new(20, 20);
+        canvas.set_line_width(2.0);
+        canvas.move_to(4.0, 10.0);
+        canvas.line_to(16.0, 10.0);
+        canvas.stroke();
+        assert_eq!(alpha(&canvas, 4, 9), 255);
+        assert_eq!(alpha(&canvas, 15, 10), 255);
+        assert_eq!(alpha(&canvas, 3, 10), 0);
+        assert_eq!(alpha(&canvas, 10, 8), 0);
+
+        let canvas = RasterCanvas::new(20, 20);
+        canvas.set_line_width(2.0);
+        canvas.set_line_cap(LineCap::Square);
+        canvas.move_to(4.0, 10.0);
+        canvas.line_to(16.0, 10.0);
+        canvas.stroke();
+        assert_eq!(alpha(&canvas, 3, 10), 255);
+        assert_eq!(alpha(&canvas, 16, 9), 255);
+
+        // dashes of 2 pixels with gaps of 2 pixels
+        let canvas = RasterCanvas::new(20, 20);
+        canvas.set_line_width(2.0);
+        canvas.set_line_dash(&[2.0]);
+        assert_eq!(canvas.get_line_dash(), vec![2.0, 2.0]);
+        canvas.move_to(0.0, 10.0);
+        canvas.line_to(20.0, 10.0);
+        canvas.stroke();
+        let row: Vec<u8> = (0..8).map(|x| alpha(&canvas, x, 10)).collect();
+        assert_eq!(row, [255, 255, 0, 0, 255, 255, 0, 0]);
+
+        // miter reaches the corner, bevel cuts it
+        for (join, corner) in [(LineJoin::Miter, 255), (LineJoin::Bevel, 0)] {
+            let canvas = RasterCanvas::new(20, 20);
+            canvas.set_line_width(4.0);
+            canvas.set_line_join(join);
+            canvas.move_to(2.0, 4.0);
+            canvas.line_to(14.0, 4.0);
+            canvas.line_to(14.0, 18.0);
+            canvas.stroke();
+            assert_eq!(alpha(&canvas, 15, 2), corner);
+            assert_eq!(alpha(&canvas, 13, 3), 255);
+        }
+
+        let canvas = RasterCanvas::new(10, 10);
+        canvas.set_line_width(2.0);
+        canvas.set_stroke_color(Color::rgb(0, 0, 255));
+        canvas.stroke_rect(1.0, 1.0, 5.0, 5.0);
+        assert_eq!(bytes(&canvas, 1, 1), [0, 0, 255, 255]);
+        assert_eq!(alpha(&canvas, 3, 3), 0);
+    }
+
+    #[test]
+    fn paints_and_alpha() {
+        let canvas = RasterCanvas::new(10, 2);
+        let gradient = Gradient::new(GradientType::Linear(LinearGradient::new(
+            0.0, 0.0, 10.0, 0.0,
+        )));
+        gradient.add_color_stop(ColorStop::new(0.0, Color::rgb(0, 0, 0)));
+        gradient.add_color_stop(ColorStop::new(1.0, Color::rgb(0, 255, 0)));
+        canvas.set_fill_gradient(&gradient);
+        canvas.fill_rect(0.0, 0.0, 10.0, 2.0);
+        assert_eq!(bytes(&canvas, 0, 0), [0, 13, 0, 255]);
+        assert_eq!(bytes(&canvas, 9, 1), [0, 242, 0, 255]);
+
+        let canvas = RasterCanvas::new(4, 4);
+        canvas.set_fill_color(Color::rgb(255, 255, 255));
+        canvas.set_global_alpha(0.5);
+        canvas.fill_rect(0.0, 0.0, 4.0, 4.0);
+        assert_eq!(bytes(&canvas, 0, 0), [255, 255, 255, 128]);
+
+        canvas.set_global_alpha(1.0);
+        canvas.set_global_composite_operation("copy");
+        assert_eq!(canvas.get_global_composite_operation(), "copy");
+        canvas.set_fill_color(Color::rgba(0, 0, 255, 51));
+        canvas.fill_rect(0.0, 0.0, 2.0, 4.0);
+        assert_eq!(bytes(&canvas, 0, 0), [0, 0, 255, 51]);
+        assert_eq!(bytes(&canvas, 3, 0), [255, 255, 255, 128]);
+
+        canvas.clear_rect(3.0, 0.0, 1.0, 4.0);
+        assert_eq!(bytes(&canvas, 3, 0), [0, 0, 0, 0]);
+        assert_eq!(alpha(&canvas, 2, 0), 128);
+    }
+
+    #[test]
+    fn short_data() {
+        // the last row is missing in data
+        let mut image = ImageDataMut::new(PixelFormat::ARgb32, 4, 4);
+        image.data.truncate(image.stride * 3 + 4);
+        let canvas = RasterCanvas::with_image(image);
+        canvas.fill_rect(0.0, 0.0, 4.0, 4.0);
+        canvas.clear_rect(0.0, 2.0, 4.0, 2.0);
+        let image = canvas.into_image();
+        assert_eq!(image.get_pixel(3, 1).alpha, 1.0);
+        assert_eq!(image.get_pixel(0, 3).alpha, 0.0);
+        assert_eq!(image.try_get_pixel(1, 3), None);
+
+        // pattern over short image samples missing pixels as transparent
+        let canvas = RasterCanvas::new(4, 1);
+        let mut pattern = ImageDataMut::new(PixelFormat::ARgb32, 2, 1);
+        pattern.fill(Color::rgb(255, 0, 0));
+        pattern.data.truncate(4);
+        canvas.set_fill_pattern(&ImagePattern::new(
+            pattern.freeze(),
+            crate::prelude::PatternExtend::Repeat,
+        ));
+        canvas.set_image_smoothing(false);
+        canvas.fill_rect(0.0, 0.0, 4.0, 1.0);
+        assert_eq!(alpha(&canvas, 0, 0), 255);
+        assert_eq!(alpha(&canvas, 1, 0), 0);
+    }
+
+    #[test]
+    fn transforms_and_state() {
+        let canvas = RasterCanvas::new(20, 20);
+        canvas.save();
+        canvas.translate(10.0, 10.0);
+        canvas.scale(2.0, 2.0);
+        canvas.set_fill_color(Color::rgb(255, 0, 0));
+        canvas.fill_rect(0.0, 0.0, 2.0, 2.0);
+        assert_eq!(
+            canvas.current_transform(),
+            Transform::new(2.0, 0.0, 0.0, 2.0, 10.0, 10.0)
+        );
+        canvas.restore();
+        assert_eq!(canvas.current_transform(), Transform::IDENTITY);
+        assert_eq!(bytes(&canvas, 13, 13), [255, 0, 0, 255]);
+        assert_eq!(alpha(&canvas, 14, 14), 0);
+        assert_eq!(alpha(&canvas, 9, 9), 0);
+
+        // restored fill color is black
+        canvas.rotate(PI / 2.0);
+        canvas.fill_rect(0.0, -4.0, 2.0, 2.0);
+        assert_eq!(bytes(&canvas, 3, 1), [0, 0, 0, 255]);
+
+        canvas.set_font("serif", FontStyle::Italic, FontWeight::Bold, 16.0);
+        assert_eq!(canvas.get_font(), "italic 700 16px serif");
+        assert_eq!(canvas.measure_text("abcd").width, 32.0);
+        canvas.set_line_width(-1.0);
+        assert_eq!(canvas.get_line_width(), 1.0);
+    }
+}
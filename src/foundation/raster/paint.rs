@@ -0,0 +1,335 @@
+use super::Transform;
+use crate::{
+    foundation::{
+        colorspace::Color,
+        image::{premultiply, unpremultiply},
+        Gradient, GradientType, ImageData,
+    },
+    prelude::PatternExtend,
+};
+
+/// Represents image repeated over canvas for filling and stroking, see `CanvasContext::Pattern`
+#[derive(Debug, Clone)]
+pub struct ImagePattern {
+    /// Image of pattern, its pixel (0, 0) is at origin of user space
+    pub image: ImageData,
+    /// How to extend image outside of its bounds
+    pub extend: PatternExtend,
+}
+
+impl ImagePattern {
+    /// Create pattern with image and extend mode
+    pub fn new(image: ImageData, extend: PatternExtend) -> Self {
+        Self { image, extend }
+    }
+}
+
+/// Source of colors for filling and stroking
+#[derive(Debug, Clone)]
+pub(crate) enum Paint {
+    Color(Color),
+    Gradient(Gradient),
+    Pattern(ImagePattern),
+}
+
+impl Default for Paint {
+    fn default() -> Self {
+        Paint::Color(Color::rgb(0, 0, 0))
+    }
+}
+
+/// Premultiplied color components
+type Premultiplied = [f32; 4];
+
+const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+
+/// Paint prepared for sampling colors at device pixels
+pub(crate) enum Shader<'a> {
+    Solid(Color),
+    Linear {
+        inverse: Transform,
+        start: (f64, f64),
+        delta: (f64, f64),
+        stops: Vec<(f64, Premultiplied)>,
+    },
+    Radial {
+        inverse: Transform,
+        gradient: [f64; 6],
+        stops: Vec<(f64, Premultiplied)>,
+    },
+    Pattern {
+        inverse: Transform,
+        pattern: &'a ImagePattern,
+        smoothing: bool,
+    },
+}
+
+impl<'a> Shader<'a> {
+    /// Prepare paint drawn with transformation from user to device space
+    pub fn new(paint: &'a Paint, transform: &Transform, smoothing: bool) -> Shader<'a> {
+        let inverse = match transform.inverse() {
+            Some(inverse) => inverse,
+            None => return Shader::Solid(TRANSPARENT),
+        };
+        match paint {
+            Paint::Color(color) => Shader::Solid(*color),
+            Paint::Gradient(gradient) => {
+                let mut stops: Vec<(f64, Premultiplied)> = gradient
+                    .stops
+                    .borrow()
+                    .iter()
+                    .map(|stop| (stop.offset.clamp(0.0, 1.0), premultiply(stop.color)))
+                    .collect();
+                // stable sort keeps stops with the same offset in order of addition
+                stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+                if stops.is_empty() {
+                    return Shader::Solid(TRANSPARENT);
+                }
+                match gradient.kind {
+                    GradientType::Linear(linear) => {
+                        let delta = (linear.x1 - linear.x0, linear.y1 - linear.y0);
+                        if delta == (0.0, 0.0) {
+                            return Shader::Solid(TRANSPARENT);
+                        }
+                        Shader::Linear {
+                            inverse,
+                            start: (linear.x0, linear.y0),
+                            delta,
+                            stops,
+                        }
+                    }
+                    GradientType::Radial(radial) => {
+                        if (radial.x0, radial.y0, radial.r0) == (radial.x1, radial.y1, radial.r1) {
+                            return Shader::Solid(TRANSPARENT);
+                        }
+                        Shader::Radial {
+                            inverse,
+                            gradient: [
+                                radial.x0, radial.y0, radial.r0, radial.x1, radial.y1, radial.r1,
+                            ],
+                            stops,
+                        }
+                    }
+                }
+            }
+            Paint::Pattern(pattern) => Shader::Pattern {
+                inverse,
+                pattern,
+                smoothing,
+            },
+        }
+    }
+
+    /// Retrieve color at device point
+    pub fn color_at(&self, x: f64, y: f64) -> Color {
+        match self {
+            Shader::Solid(color) => *color,
+            Shader::Linear {
+                inverse,
+                start,
+                delta,
+                stops,
+            } => {
+                let (x, y) = inverse.apply(x, y);
+                let t = ((x - start.0) * delta.0 + (y - start.1) * delta.1)
+                    / (delta.0 * delta.0 + delta.1 * delta.1);
+                gradient_color(stops, t)
+            }
+            Shader::Radial {
+                inverse,
+                gradient,
+                stops,
+            } => {
+                let (x, y) = inverse.apply(x, y);
+                match radial_parameter(gradient, x, y) {
+                    Some(t) => gradient_color(stops, t),
+                    None => TRANSPARENT,
+                }
+            }
+            Shader::Pattern {
+                inverse,
+                pattern,
+                smoothing,
+            } => {
+                let (x, y) = inverse.apply(x, y);
+                if *smoothing {
+                    sample_bilinear(pattern, x - 0.5, y - 0.5)
+                } else {
+                    sample(pattern, x.floor(), y.floor())
+                }
+            }
+        }
+    }
+}
+
+fn gradient_color(stops: &[(f64, Premultiplied)], t: f64) -> Color {
+    let first = stops[0];
+    let last = stops[stops.len() - 1];
+    if t.is_nan() || t <= first.0 {
+        return unpremultiply(first.1);
+    }
+    if t >= last.0 {
+        return unpremultiply(last.1);
+    }
+    // the last stop with offset not above t starts the interval
+    let idx = stops.partition_point(|stop| stop.0 <= t);
+    let (from, to) = (stops[idx - 1], stops[idx]);
+    let weight = ((t - from.0) / (to.0 - from.0)) as f32;
+    let mut color = [0.0; 4];
+    for (channel, value) in color.iter_mut().enumerate() {
+        *value = from.1[channel] + (to.1[channel] - from.1[channel]) * weight;
+    }
+    unpremultiply(color)
+}
+
+/// Find the largest `t` where point lies on circle interpolated between the circles of gradient
+/// with non-negative radius, like canvas `createRadialGradient`
+fn radial_parameter(&[x0, y0, r0, x1, y1, r1]: &[f64; 6], x: f64, y: f64) -> Option<f64> {
+    let (cdx, cdy, dr) = (x1 - x0, y1 - y0, r1 - r0);
+    let (pdx, pdy) = (x - x0, y - y0);
+    let a = cdx * cdx + cdy * cdy - dr * dr;
+    let b = pdx * cdx + pdy * cdy + r0 * dr;
+    let c = pdx * pdx + pdy * pdy - r0 * r0;
+    let valid = |t: f64| r0 + t * dr >= 0.0;
+
+    if a.abs() < 1e-12 {
+        if b == 0.0 {
+            return None;
+        }
+        let t = c / (2.0 * b);
+        return if valid(t) { Some(t) } else { None };
+    }
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let (t1, t2) = ((b + root) / a, (b - root) / a);
+    let (larger, smaller) = if t1 > t2 { (t1, t2) } else { (t2, t1) };
+    if valid(larger) {
+        Some(larger)
+    } else if valid(smaller) {
+        Some(smaller)
+    } else {
+        None
+    }
+}
+
+/// Map coordinate into image bounds by extend mode
+fn extend_coordinate(value: f64, size: u32, extend: PatternExtend) -> Option<u32> {
+    let size = size as f64;
+    let value = match extend {
+        PatternExtend::None if value < 0.0 || value >= size => return None,
+        PatternExtend::None => value,
+        PatternExtend::Repeat => value.rem_euclid(size),
+        PatternExtend::Reflect => {
+            let value = value.rem_euclid(2.0 * size);
+            if value >= size {
+                2.0 * size - 1.0 - value
+            } else {
+                value
+            }
+        }
+        PatternExtend::Pad => value.clamp(0.0, size - 1.0),
+    };
+    Some((value as u32).min(size as u32 - 1))
+}
+
+fn sample(pattern: &ImagePattern, x: f64, y: f64) -> Color {
+    let image = &pattern.image;
+    if image.width == 0 || image.height == 0 || !(x.is_finite() && y.is_finite()) {
+        return TRANSPARENT;
+    }
+    match (
+        extend_coordinate(x, image.width, pattern.extend),
+        extend_coordinate(y, image.height, pattern.extend),
+    ) {
+        (Some(x), Some(y)) => image.try_get_pixel(x, y).unwrap_or(TRANSPARENT),
+        _ => TRANSPARENT,
+    }
+}
+
+fn sample_bilinear(pattern: &ImagePattern, x: f64, y: f64) -> Color {
+    let (left, top) = (x.floor(), y.floor());
+    let (fx, fy) = ((x - left) as f32, (y - top) as f32);
+    let mut color = [0.0; 4];
+    for (dx, dy, weight) in [
+        (0.0, 0.0, (1.0 - fx) * (1.0 - fy)),
+        (1.0, 0.0, fx * (1.0 - fy)),
+        (0.0, 1.0, (1.0 - fx) * fy),
+        (1.0, 1.0, fx * fy),
+    ] {
+        if weight > 0.0 {
+            let texel = premultiply(sample(pattern, left + dx, top + dy));
+            for (channel, value) in color.iter_mut().enumerate() {
+                *value += texel[channel] * weight;
+            }
+        }
+    }
+    unpremultiply(color)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::foundation::{ColorStop, ImageDataMut, LinearGradient, PixelFormat, RadialGradient};
+
+    fn bytes(color: Color) -> [u8; 4] {
+        color.cast::<u8>().to_array()
+    }
+
+    #[test]
+    fn gradients() {
+        let gradient = Gradient::new(GradientType::Linear(LinearGradient::new(
+            0.0, 0.0, 10.0, 0.0,
+        )));
+        gradient.add_color_stop(ColorStop::new(0.0, Color::rgb(0, 0, 0)));
+        gradient.add_color_stop(ColorStop::new(1.0, Color::rgb(255, 0, 0)));
+        let paint = Paint::Gradient(gradient);
+        let shader = Shader::new(&paint, &Transform::scaling(2.0, 2.0), false);
+        assert_eq!(bytes(shader.color_at(-5.0, 0.0)), [0, 0, 0, 255]);
+        assert_eq!(bytes(shader.color_at(10.0, 7.0)), [128, 0, 0, 255]);
+        assert_eq!(bytes(shader.color_at(25.0, 0.0)), [255, 0, 0, 255]);
+
+        // premultiplied interpolation towards transparent keeps color
+        let gradient = Gradient::new(GradientType::Radial(RadialGradient::new(
+            5.0, 5.0, 0.0, 5.0, 5.0, 4.0,
+        )));
+        gradient.add_color_stop(ColorStop::new(0.0, Color::rgb(0, 0, 255)));
+        gradient.add_color_stop(ColorStop::new(1.0, Color::rgba(255, 255, 255, 0)));
+        let paint = Paint::Gradient(gradient);
+        let shader = Shader::new(&paint, &Transform::IDENTITY, false);
+        assert_eq!(bytes(shader.color_at(5.0, 5.0)), [0, 0, 255, 255]);
+        assert_eq!(bytes(shader.color_at(7.0, 5.0)), [0, 0, 255, 128]);
+        assert_eq!(shader.color_at(15.0, 5.0).alpha, 0.0);
+
+        let paint = Paint::Gradient(Gradient::new(Default::default()));
+        let shader = Shader::new(&paint, &Transform::IDENTITY, false);
+        assert_eq!(shader.color_at(1.0, 1.0).alpha, 0.0);
+    }
+
+    #[test]
+    fn patterns() {
+        let mut image = ImageDataMut::new(PixelFormat::ARgb32, 2, 1);
+        image.set_pixel(0, 0, Color::rgb(255, 0, 0));
+        image.set_pixel(1, 0, Color::rgb(0, 0, 255));
+        let image = image.freeze();
+
+        let colors = |extend| {
+            let paint = Paint::Pattern(ImagePattern::new(image.clone(), extend));
+            let shader = Shader::new(&paint, &Transform::IDENTITY, false);
+            (-2..4)
+                .map(|x| bytes(shader.color_at(x as f64 + 0.5, 0.5))[0])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(colors(PatternExtend::Repeat), [255, 0, 255, 0, 255, 0]);
+        assert_eq!(colors(PatternExtend::Reflect), [0, 255, 255, 0, 0, 255]);
+        assert_eq!(colors(PatternExtend::Pad), [255, 255, 255, 0, 0, 0]);
+        let paint = Paint::Pattern(ImagePattern::new(image.clone(), PatternExtend::None));
+        let shader = Shader::new(&paint, &Transform::IDENTITY, false);
+        assert_eq!(shader.color_at(-0.5, 0.5).alpha, 0.0);
+
+        let shader = Shader::new(&paint, &Transform::IDENTITY, true);
+        assert_eq!(bytes(shader.color_at(1.0, 0.5)), [128, 0, 128, 255]);
+    }
+}
@@ -0,0 +1,323 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use super::Transform;
+
+/// Maximal distance in pixels between curve and its flattened polyline
+const TOLERANCE: f64 = 0.1;
+
+/// Maximal depth of curve subdivision, limits segment count to 2^16
+const MAX_DEPTH: u32 = 16;
+
+/// Polyline of path with points in device space
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Subpath {
+    pub points: Vec<(f64, f64)>,
+    pub closed: bool,
+}
+
+/// Canvas path flattened into polylines while it is being built.
+///
+/// Points are mapped with the transformation current when they are added,
+/// as canvas does, so curves are flattened with device space tolerance.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Path {
+    pub subpaths: Vec<Subpath>,
+}
+
+impl Path {
+    pub fn clear(&mut self) {
+        self.subpaths.clear();
+    }
+
+    /// Last point in device space
+    pub fn current_point(&self) -> Option<(f64, f64)> {
+        self.subpaths
+            .last()
+            .and_then(|subpath| subpath.points.last().copied())
+    }
+
+    fn push_point(&mut self, point: (f64, f64)) {
+        if !(point.0.is_finite() && point.1.is_finite()) {
+            return;
+        }
+        match self.subpaths.last_mut() {
+            Some(subpath) if !subpath.closed => {
+                if subpath.points.last() != Some(&point) {
+                    subpath.points.push(point);
+                }
+            }
+            _ => self.start(point),
+        }
+    }
+
+    fn start(&mut self, point: (f64, f64)) {
+        if let Some(subpath) = self.subpaths.last_mut() {
+            if !subpath.closed && subpath.points.len() < 2 {
+                subpath.points = vec![point];
+                return;
+            }
+        }
+        self.subpaths.push(Subpath {
+            points: vec![point],
+            closed: false,
+        });
+    }
+
+    pub fn move_to(&mut self, transform: &Transform, x: f64, y: f64) {
+        let point = transform.apply(x, y);
+        if point.0.is_finite() && point.1.is_finite() {
+            self.start(point);
+        }
+    }
+
+    pub fn line_to(&mut self, transform: &Transform, x: f64, y: f64) {
+        self.push_point(transform.apply(x, y));
+    }
+
+    pub fn close(&mut self) {
+        if let Some(subpath) = self.subpaths.last_mut() {
+            if !subpath.closed {
+                subpath.closed = true;
+                let first = subpath.points[0];
+                // next segments start at the first point of the closed subpath
+                self.subpaths.push(Subpath {
+                    points: vec![first],
+                    closed: false,
+                });
+            }
+        }
+    }
+
+    pub fn quadratic_to(&mut self, transform: &Transform, cpx: f64, cpy: f64, x: f64, y: f64) {
+        let start = match self.current_point() {
+            Some(point) => point,
+            None => {
+                self.move_to(transform, cpx, cpy);
+                transform.apply(cpx, cpy)
+            }
+        };
+        let control = transform.apply(cpx, cpy);
+        let end = transform.apply(x, y);
+        let control1 = lerp(start, control, 2.0 / 3.0);
+        let control2 = lerp(end, control, 2.0 / 3.0);
+        self.flatten_cubic([start, control1, control2, end], 0);
+    }
+
+    pub fn cubic_to(&mut self, transform: &Transform, points: [(f64, f64); 3]) {
+        let start = match self.current_point() {
+            Some(point) => point,
+            None => {
+                self.move_to(transform, points[0].0, points[0].1);
+                transform.apply(points[0].0, points[0].1)
+            }
+        };
+        let [control1, control2, end] = points.map(|(x, y)| transform.apply(x, y));
+        self.flatten_cubic([start, control1, control2, end], 0);
+    }
+
+    fn flatten_cubic(&mut self, curve: [(f64, f64); 4], depth: u32) {
+        let [p0, p1, p2, p3] = curve;
+        // distance of control points from the chord bounds distance of the curve
+        let flatness = distance_to_line(p1, p0, p3).max(distance_to_line(p2, p0, p3));
+        if !flatness.is_finite() {
+            return;
+        }
+        if flatness <= TOLERANCE || depth >= MAX_DEPTH {
+            self.push_point(p3);
+            return;
+        }
+        let p01 = lerp(p0, p1, 0.5);
+        let p12 = lerp(p1, p2, 0.5);
+        let p23 = lerp(p2, p3, 0.5);
+        let p012 = lerp(p01, p12, 0.5);
+        let p123 = lerp(p12, p23, 0.5);
+        let middle = lerp(p012, p123, 0.5);
+        self.flatten_cubic([p0, p01, p012, middle], depth + 1);
+        self.flatten_cubic([middle, p123, p23, p3], depth + 1);
+    }
+
+    /// Add elliptical arc, angles are in radians from the x axis of ellipse
+    #[allow(clippy::too_many_arguments)]
+    pub fn ellipse(
+        &mut self,
+        transform: &Transform,
+        center: (f64, f64),
+        radius: (f64, f64),
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+        anticlockwise: bool,
+    ) {
+        if !(start_angle.is_finite() && end_angle.is_finite()) || radius.0 < 0.0 || radius.1 < 0.0 {
+            return;
+        }
+        let sweep = arc_sweep(start_angle, end_angle, anticlockwise);
+        let ellipse = Transform::scaling(radius.0, radius.1)
+            .then(&Transform::rotation(rotation))
+            .then(&Transform::translation(center.0, center.1))
+            .then(transform);
+
+        let (sin, cos) = start_angle.sin_cos();
+        let (x, y) = ellipse.apply(cos, sin);
+        if self.current_point().is_some() {
+            self.push_point((x, y));
+        } else {
+            self.start((x, y));
+        }
+
+        // cubic approximation of unit circle arcs up to quarter turn
+        let count = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep / count as f64;
+        let handle = 4.0 / 3.0 * (step / 4.0).tan();
+        let mut angle = start_angle;
+        for _ in 0..count {
+            let next = angle + step;
+            let (sin0, cos0) = angle.sin_cos();
+            let (sin1, cos1) = next.sin_cos();
+            let curve = [
+                (cos0, sin0),
+                (cos0 - handle * sin0, sin0 + handle * cos0),
+                (cos1 + handle * sin1, sin1 - handle * cos1),
+                (cos1, sin1),
+            ]
+            .map(|(x, y)| ellipse.apply(x, y));
+            let start = self.current_point().unwrap_or(curve[0]);
+            self.flatten_cubic([start, curve[1], curve[2], curve[3]], 0);
+            angle = next;
+        }
+    }
+
+    /// Add arc tangent to lines from current point to first point and from first to second point
+    pub fn arc_to(&mut self, transform: &Transform, p1: (f64, f64), p2: (f64, f64), radius: f64) {
+        if radius < 0.0 || !radius.is_finite() {
+            return;
+        }
+        let current = match self.current_point() {
+            Some(point) => point,
+            None => {
+                self.move_to(transform, p1.0, p1.1);
+                return;
+            }
+        };
+        // the arc is built in user space, where the current point is mapped back
+        let p0 = match transform.inverse() {
+            Some(inverse) => inverse.apply(current.0, current.1),
+            None => return,
+        };
+
+        let (dx0, dy0) = (p0.0 - p1.0, p0.1 - p1.1);
+        let (dx2, dy2) = (p2.0 - p1.0, p2.1 - p1.1);
+        let (len0, len2) = (dx0.hypot(dy0), dx2.hypot(dy2));
+        let cross = dx0 * dy2 - dy0 * dx2;
+        if radius == 0.0 || len0 == 0.0 || len2 == 0.0 || cross.abs() < 1e-12 * len0 * len2 {
+            self.line_to(transform, p1.0, p1.1);
+            return;
+        }
+
+        // half of angle between the lines at first point
+        let cos = ((dx0 * dx2 + dy0 * dy2) / (len0 * len2)).clamp(-1.0, 1.0);
+        let half = cos.acos() / 2.0;
+        let tangent = radius / half.tan();
+        let (ux0, uy0) = (dx0 / len0, dy0 / len0);
+        let (ux2, uy2) = (dx2 / len2, dy2 / len2);
+        let start = (p1.0 + ux0 * tangent, p1.1 + uy0 * tangent);
+        let end = (p1.0 + ux2 * tangent, p1.1 + uy2 * tangent);
+
+        // center lies on the bisector, at distance radius from both lines
+        let (bx, by) = (ux0 + ux2, uy0 + uy2);
+        let bisector = bx.hypot(by);
+        let distance = radius / half.sin();
+        let center = (
+            p1.0 + bx / bisector * distance,
+            p1.1 + by / bisector * distance,
+        );
+        let start_angle = (start.1 - center.1).atan2(start.0 - center.0);
+        let end_angle = (end.1 - center.1).atan2(end.0 - center.0);
+
+        self.line_to(transform, start.0, start.1);
+        self.ellipse(
+            transform,
+            center,
+            (radius, radius),
+            0.0,
+            start_angle,
+            end_angle,
+            cross > 0.0,
+        );
+    }
+
+    pub fn rect(&mut self, transform: &Transform, x: f64, y: f64, width: f64, height: f64) {
+        self.move_to(transform, x, y);
+        self.line_to(transform, x + width, y);
+        self.line_to(transform, x + width, y + height);
+        self.line_to(transform, x, y + height);
+        self.close();
+    }
+}
+
+/// Signed sweep of arc like canvas, turns of at least full circle are clamped to one turn
+fn arc_sweep(start: f64, end: f64, anticlockwise: bool) -> f64 {
+    let delta = end - start;
+    if !anticlockwise {
+        if delta >= TAU {
+            TAU
+        } else {
+            delta.rem_euclid(TAU)
+        }
+    } else if -delta >= TAU {
+        -TAU
+    } else {
+        let sweep = (-delta).rem_euclid(TAU);
+        -sweep
+    }
+}
+
+#[inline]
+fn lerp(from: (f64, f64), to: (f64, f64), t: f64) -> (f64, f64) {
+    (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t)
+}
+
+fn distance_to_line(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length = dx.hypot(dy);
+    if length < 1e-12 {
+        return (point.0 - start.0).hypot(point.1 - start.1);
+    }
+    ((point.0 - start.0) * dy - (point.1 - start.1) * dx).abs() / length
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn sweeps() {
+        assert!((arc_sweep(0.0, PI, false) - PI).abs() < 1e-12);
+        assert!((arc_sweep(0.0, PI / 2.0, true) + PI * 1.5).abs() < 1e-12);
+        assert_eq!(arc_sweep(0.0, 10.0, false), TAU);
+        assert_eq!(arc_sweep(1.0, -10.0, true), -TAU);
+        assert_eq!(arc_sweep(1.0, 1.0, false), 0.0);
+    }
+
+    #[test]
+    fn flattening() {
+        let mut path = Path::default();
+        let transform = Transform::scaling(2.0, 2.0);
+        path.ellipse(&transform, (10.0, 10.0), (5.0, 5.0), 0.0, 0.0, TAU, false);
+        let points = &path.subpaths[0].points;
+        assert!(points.len() > 8);
+        for &(x, y) in points {
+            let radius = (x - 20.0).hypot(y - 20.0);
+            assert!((radius - 10.0).abs() < TOLERANCE, "{}", radius);
+        }
+
+        path.clear();
+        path.move_to(&transform, 0.0, 0.0);
+        path.arc_to(&transform, (10.0, 0.0), (10.0, 10.0), 5.0);
+        let points = &path.subpaths[0].points;
+        assert!((points[1].0 - 10.0).abs() < 1e-9 && points[1].1.abs() < 1e-9);
+        let last = points[points.len() - 1];
+        assert!((last.0 - 20.0).abs() < 1e-9 && (last.1 - 10.0).abs() < 1e-9);
+    }
+}
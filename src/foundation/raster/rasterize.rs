@@ -0,0 +1,247 @@
+/// Specifies how to determine whether point is inside of path with crossing subpaths
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FillRule {
+    /// Point is inside when subpaths wind around it non-zero times, `nonzero`
+    #[default]
+    NonZero,
+    /// Point is inside when ray from it crosses odd count of edges, `evenodd`
+    EvenOdd,
+}
+
+impl FillRule {
+    #[inline]
+    fn is_inside(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// Count of sample scanlines per pixel row, coverage along scanlines is exact
+const SAMPLES: usize = 16;
+
+/// Coverage of pixels inside bounding box, from 0 to 1
+#[derive(Debug, Clone)]
+pub(crate) struct Mask {
+    pub left: u32,
+    pub top: u32,
+    pub width: u32,
+    pub coverage: Vec<f32>,
+}
+
+impl Mask {
+    /// Iterate over pixels with non-zero coverage
+    pub fn pixels(&self) -> impl Iterator<Item = (u32, u32, f32)> + '_ {
+        let width = self.width as usize;
+        self.coverage
+            .iter()
+            .enumerate()
+            .filter(|(_, coverage)| **coverage > 0.0)
+            .map(move |(idx, coverage)| {
+                let x = self.left + (idx % width) as u32;
+                let y = self.top + (idx / width) as u32;
+                (x, y, coverage.min(1.0))
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    top: f64,
+    bottom: f64,
+    x: f64,
+    slope: f64,
+    direction: i32,
+}
+
+/// Compute anti-aliased coverage of polygons in device space, clipped to image size.
+///
+/// Polygons are closed implicitly, each row is sampled by several scanlines
+/// and spans inside of polygons are accumulated with exact horizontal coverage.
+pub(crate) fn rasterize<'a, I>(polygons: I, rule: FillRule, width: u32, height: u32) -> Option<Mask>
+where
+    I: IntoIterator<Item = &'a [(f64, f64)]>,
+{
+    let mut edges = Vec::new();
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for polygon in polygons {
+        if polygon.len() < 3 {
+            continue;
+        }
+        for (idx, &start) in polygon.iter().enumerate() {
+            let end = polygon[(idx + 1) % polygon.len()];
+            if start.1 == end.1 {
+                continue;
+            }
+            let (upper, lower, direction) = if start.1 < end.1 {
+                (start, end, 1)
+            } else {
+                (end, start, -1)
+            };
+            let slope = (lower.0 - upper.0) / (lower.1 - upper.1);
+            if !slope.is_finite() {
+                continue;
+            }
+            min_x = min_x.min(start.0.min(end.0));
+            max_x = max_x.max(start.0.max(end.0));
+            min_y = min_y.min(upper.1);
+            max_y = max_y.max(lower.1);
+            edges.push(Edge {
+                top: upper.1,
+                bottom: lower.1,
+                x: upper.0,
+                slope,
+                direction,
+            });
+        }
+    }
+
+    let left = min_x.floor().max(0.0);
+    let top = min_y.floor().max(0.0);
+    let right = max_x.ceil().min(width as f64);
+    let bottom = max_y.ceil().min(height as f64);
+    if edges.is_empty() || left >= right || top >= bottom {
+        return None;
+    }
+    let (left, top) = (left as u32, top as u32);
+    let (mask_width, mask_height) = (right as u32 - left, bottom as u32 - top);
+    let mut coverage = vec![0f32; mask_width as usize * mask_height as usize];
+
+    edges.sort_by(|a, b| a.top.total_cmp(&b.top));
+    let mut next_edge = 0;
+    let mut active: Vec<Edge> = Vec::new();
+    let mut crossings: Vec<(f64, i32)> = Vec::new();
+    let weight = 1.0 / SAMPLES as f64;
+    let extent = mask_width as f64;
+
+    for row in 0..mask_height {
+        let y = (top + row) as f64;
+        active.retain(|edge| edge.bottom > y);
+        while next_edge < edges.len() && edges[next_edge].top < y + 1.0 {
+            if edges[next_edge].bottom > y {
+                active.push(edges[next_edge]);
+            }
+            next_edge += 1;
+        }
+        if active.is_empty() {
+            continue;
+        }
+
+        let line = &mut coverage[(row * mask_width) as usize..][..mask_width as usize];
+        for sample in 0..SAMPLES {
+            let sample_y = y + (sample as f64 + 0.5) * weight;
+            crossings.clear();
+            crossings.extend(
+                active
+                    .iter()
+                    .filter(|edge| edge.top <= sample_y && sample_y < edge.bottom)
+                    .map(|edge| {
+                        let x = edge.x + (sample_y - edge.top) * edge.slope;
+                        (x - left as f64, edge.direction)
+                    }),
+            );
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut winding = 0;
+            for pair in crossings.windows(2) {
+                winding += pair[0].1;
+                if rule.is_inside(winding) {
+                    let start = pair[0].0.clamp(0.0, extent);
+                    let end = pair[1].0.clamp(0.0, extent);
+                    accumulate(line, start, end, weight as f32);
+                }
+            }
+        }
+    }
+
+    Some(Mask {
+        left,
+        top,
+        width: mask_width,
+        coverage,
+    })
+}
+
+/// Add coverage of span to pixels of row
+#[inline]
+fn accumulate(line: &mut [f32], start: f64, end: f64, weight: f32) {
+    if end <= start {
+        return;
+    }
+    let first = start.floor() as usize;
+    let last = end.floor() as usize;
+    if first == last {
+        line[first.min(line.len() - 1)] += (end - start) as f32 * weight;
+        return;
+    }
+    line[first] += (first as f64 + 1.0 - start) as f32 * weight;
+    for value in &mut line[first + 1..last] {
+        *value += weight;
+    }
+    if last < line.len() {
+        line[last] += (end - last as f64) as f32 * weight;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    impl Mask {
+        fn height(&self) -> u32 {
+            self.coverage.len() as u32 / self.width
+        }
+    }
+
+    fn coverage_at(mask: &Mask, x: u32, y: u32) -> f32 {
+        if x < mask.left
+            || y < mask.top
+            || x >= mask.left + mask.width
+            || y >= mask.top + mask.height()
+        {
+            return 0.0;
+        }
+        mask.coverage[((y - mask.top) * mask.width + x - mask.left) as usize]
+    }
+
+    #[test]
+    fn fill_rules() {
+        // two nested squares with the same orientation
+        let outer: &[(f64, f64)] = &[(0.0, 0.0), (8.0, 0.0), (8.0, 8.0), (0.0, 8.0)];
+        let inner: &[(f64, f64)] = &[(2.0, 2.0), (6.0, 2.0), (6.0, 6.0), (2.0, 6.0)];
+
+        let mask = rasterize([outer, inner], FillRule::NonZero, 10, 10).unwrap();
+        assert_eq!((mask.left, mask.top, mask.width, mask.height()), (0, 0, 8, 8));
+        assert!((coverage_at(&mask, 4, 4) - 1.0).abs() < 1e-6);
+        assert!((coverage_at(&mask, 1, 1) - 1.0).abs() < 1e-6);
+
+        let mask = rasterize([outer, inner], FillRule::EvenOdd, 10, 10).unwrap();
+        assert_eq!(coverage_at(&mask, 4, 4), 0.0);
+        assert!((coverage_at(&mask, 1, 1) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn anti_aliasing() {
+        let square: &[(f64, f64)] = &[(1.5, 1.25), (4.0, 1.25), (4.0, 3.0), (1.5, 3.0)];
+        let mask = rasterize([square], FillRule::NonZero, 10, 10).unwrap();
+        assert!((coverage_at(&mask, 1, 1) - 0.375).abs() < 1e-6);
+        assert!((coverage_at(&mask, 2, 2) - 1.0).abs() < 1e-6);
+        assert!((coverage_at(&mask, 3, 1) - 0.75).abs() < 1e-6);
+
+        // half of pixel below diagonal
+        let triangle: &[(f64, f64)] = &[(0.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let mask = rasterize([triangle], FillRule::NonZero, 10, 10).unwrap();
+        assert!((coverage_at(&mask, 0, 0) - 0.5).abs() < 1e-6);
+
+        // clipped to image and empty outside of it
+        let square: &[(f64, f64)] = &[(-5.0, -5.0), (5.0, -5.0), (5.0, 5.0), (-5.0, 5.0)];
+        let mask = rasterize([square], FillRule::NonZero, 3, 3).unwrap();
+        assert_eq!((mask.left, mask.top, mask.width, mask.height()), (0, 0, 3, 3));
+        assert!(mask.pixels().all(|(_, _, coverage)| coverage == 1.0));
+        let square: &[(f64, f64)] = &[(20.0, 0.0), (25.0, 0.0), (25.0, 5.0), (20.0, 5.0)];
+        assert!(rasterize([square], FillRule::NonZero, 10, 10).is_none());
+    }
+}
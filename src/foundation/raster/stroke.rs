@@ -0,0 +1,359 @@
+use std::f64::consts::TAU;
+
+use super::{Path, Transform};
+use crate::prelude::{LineCap, LineJoin};
+
+/// Line parameters of stroke in user space
+#[derive(Debug, Clone)]
+pub(crate) struct StrokeStyle {
+    pub width: f64,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    pub miter_limit: f64,
+    pub dash: Vec<f64>,
+    pub dash_offset: f64,
+}
+
+type Point = (f64, f64);
+
+/// Outline stroke of path as polygons in device space, to be filled with nonzero rule.
+///
+/// Path is mapped back to user space with the current transformation, so the pen
+/// follows it like in canvas. Every segment, join and cap becomes separate polygon
+/// with the same orientation, thus overlapping parts do not cancel each other.
+pub(crate) fn stroke(path: &Path, style: &StrokeStyle, transform: &Transform) -> Vec<Vec<Point>> {
+    let inverse = match transform.inverse() {
+        Some(inverse) => inverse,
+        None => return Vec::new(),
+    };
+    let mut stroker = Stroker {
+        half_width: style.width / 2.0,
+        style,
+        tolerance: 0.1 / transform.max_scale(),
+        polygons: Vec::new(),
+    };
+
+    for subpath in &path.subpaths {
+        let mut points: Vec<Point> = Vec::with_capacity(subpath.points.len());
+        for &(x, y) in &subpath.points {
+            let point = inverse.apply(x, y);
+            if points.last() != Some(&point) {
+                points.push(point);
+            }
+        }
+        if subpath.closed && points.len() > 2 && points.first() == points.last() {
+            points.pop();
+        }
+        if points.len() < 2 {
+            continue;
+        }
+
+        if dashed(&points, subpath.closed, style, transform) {
+            for dash in dashes(&points, subpath.closed, &style.dash, style.dash_offset) {
+                stroker.polyline(&dash, false);
+            }
+        } else {
+            stroker.polyline(&points, subpath.closed);
+        }
+    }
+
+    let mut polygons = stroker.polygons;
+    for polygon in &mut polygons {
+        for point in polygon.iter_mut() {
+            *point = transform.apply(point.0, point.1);
+        }
+    }
+    polygons
+}
+
+/// Shortest dash pattern in device pixels, shorter patterns are stroked solid
+const MIN_DASH_PERIOD: f64 = 1e-6;
+
+/// Maximal count of dash segments per subpath, denser patterns are stroked solid
+const MAX_DASH_SEGMENTS: f64 = 1e5;
+
+/// Check if subpath is split into dashes, degenerate or too dense patterns are not
+fn dashed(points: &[Point], closed: bool, style: &StrokeStyle, transform: &Transform) -> bool {
+    let period: f64 = style.dash.iter().sum();
+    if period * transform.max_scale() < MIN_DASH_PERIOD {
+        return false;
+    }
+    let mut length: f64 = points
+        .windows(2)
+        .map(|pair| (pair[1].0 - pair[0].0).hypot(pair[1].1 - pair[0].1))
+        .sum();
+    if closed {
+        let (first, last) = (points[0], points[points.len() - 1]);
+        length += (first.0 - last.0).hypot(first.1 - last.1);
+    }
+    length / period * style.dash.len() as f64 <= MAX_DASH_SEGMENTS
+}
+
+/// Split polyline into visible dashes, dash pattern is expected to be of even length
+fn dashes(points: &[Point], closed: bool, pattern: &[f64], offset: f64) -> Vec<Vec<Point>> {
+    let total: f64 = pattern.iter().sum();
+    let mut index = 0;
+    let mut remaining = pattern[0];
+    // skip offset inside of pattern
+    let mut skip = offset.rem_euclid(total);
+    while skip > 0.0 {
+        if skip >= remaining {
+            skip -= remaining;
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        } else {
+            remaining -= skip;
+            skip = 0.0;
+        }
+    }
+
+    let mut result = Vec::new();
+    let mut current: Vec<Point> = Vec::new();
+    if index % 2 == 0 {
+        current.push(points[0]);
+    }
+    let count = if closed {
+        points.len()
+    } else {
+        points.len() - 1
+    };
+    for idx in 0..count {
+        let start = points[idx];
+        let end = points[(idx + 1) % points.len()];
+        let length = (end.0 - start.0).hypot(end.1 - start.1);
+        let mut position = 0.0;
+        while length - position > remaining {
+            position += remaining;
+            let t = position / length;
+            let point = (
+                start.0 + (end.0 - start.0) * t,
+                start.1 + (end.1 - start.1) * t,
+            );
+            if current.last() != Some(&point) {
+                current.push(point);
+            }
+            if index % 2 == 0 {
+                result.push(std::mem::take(&mut current));
+            }
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+        remaining -= length - position;
+        if index % 2 == 0 && current.last() != Some(&end) {
+            current.push(end);
+        }
+    }
+    if current.len() > 1 {
+        result.push(current);
+    }
+    result
+}
+
+struct Stroker<'a> {
+    style: &'a StrokeStyle,
+    half_width: f64,
+    /// Flattening tolerance in user space
+    tolerance: f64,
+    polygons: Vec<Vec<Point>>,
+}
+
+impl Stroker<'_> {
+    fn push(&mut self, mut polygon: Vec<Point>) {
+        let area: f64 = (0..polygon.len())
+            .map(|idx| {
+                let (x0, y0) = polygon[idx];
+                let (x1, y1) = polygon[(idx + 1) % polygon.len()];
+                x0 * y1 - x1 * y0
+            })
+            .sum();
+        if area < 0.0 {
+            polygon.reverse();
+        }
+        self.polygons.push(polygon);
+    }
+
+    fn circle(&mut self, center: Point) {
+        let radius = self.half_width;
+        let step = 2.0 * (1.0 - self.tolerance / radius).clamp(-1.0, 1.0).acos();
+        let count = (TAU / step).ceil().clamp(8.0, 1024.0) as usize;
+        let polygon = (0..count)
+            .map(|idx| {
+                let (sin, cos) = (TAU * idx as f64 / count as f64).sin_cos();
+                (center.0 + cos * radius, center.1 + sin * radius)
+            })
+            .collect();
+        self.push(polygon);
+    }
+
+    fn polyline(&mut self, points: &[Point], closed: bool) {
+        let mut points = points.to_vec();
+        points.dedup();
+        if points.len() < 2 {
+            return;
+        }
+        let width = self.half_width;
+        let count = if closed {
+            points.len()
+        } else {
+            points.len() - 1
+        };
+        let directions: Vec<Point> = (0..count)
+            .map(|idx| {
+                let (start, end) = (points[idx], points[(idx + 1) % points.len()]);
+                let length = (end.0 - start.0).hypot(end.1 - start.1);
+                ((end.0 - start.0) / length, (end.1 - start.1) / length)
+            })
+            .collect();
+
+        for idx in 0..count {
+            let mut start = points[idx];
+            let mut end = points[(idx + 1) % points.len()];
+            let direction = directions[idx];
+            if !closed && matches!(self.style.cap, LineCap::Square) {
+                if idx == 0 {
+                    start = (start.0 - direction.0 * width, start.1 - direction.1 * width);
+                }
+                if idx == count - 1 {
+                    end = (end.0 + direction.0 * width, end.1 + direction.1 * width);
+                }
+            }
+            let normal = (-direction.1 * width, direction.0 * width);
+            self.push(vec![
+                (start.0 + normal.0, start.1 + normal.1),
+                (end.0 + normal.0, end.1 + normal.1),
+                (end.0 - normal.0, end.1 - normal.1),
+                (start.0 - normal.0, start.1 - normal.1),
+            ]);
+        }
+
+        let joins = if closed { 0..count } else { 1..count };
+        for idx in joins {
+            let incoming = directions[(idx + count - 1) % count];
+            self.join(points[idx], incoming, directions[idx]);
+        }
+
+        if !closed && matches!(self.style.cap, LineCap::Round) {
+            self.circle(points[0]);
+            self.circle(points[points.len() - 1]);
+        }
+    }
+
+    fn join(&mut self, point: Point, incoming: Point, outgoing: Point) {
+        let cross = incoming.0 * outgoing.1 - incoming.1 * outgoing.0;
+        let dot = incoming.0 * outgoing.0 + incoming.1 * outgoing.1;
+        if cross.abs() < 1e-12 && dot > 0.0 {
+            return;
+        }
+        if matches!(self.style.join, LineJoin::Round) {
+            self.circle(point);
+            return;
+        }
+
+        // offsets on the outer side of turn
+        let side = if cross > 0.0 {
+            -self.half_width
+        } else {
+            self.half_width
+        };
+        let outer0 = (-incoming.1 * side, incoming.0 * side);
+        let outer1 = (-outgoing.1 * side, outgoing.0 * side);
+        let first = (point.0 + outer0.0, point.1 + outer0.1);
+        let second = (point.0 + outer1.0, point.1 + outer1.1);
+
+        // ratio of miter length to line width is 1 / sin(angle / 2)
+        let ratio = 1.0 / ((1.0 + dot) / 2.0).sqrt();
+        if matches!(self.style.join, LineJoin::Miter) && ratio <= self.style.miter_limit {
+            let (mx, my) = (outer0.0 + outer1.0, outer0.1 + outer1.1);
+            let length = mx.hypot(my);
+            let distance = self.half_width * ratio;
+            let tip = (
+                point.0 + mx / length * distance,
+                point.1 + my / length * distance,
+            );
+            self.push(vec![point, first, tip, second]);
+        } else {
+            self.push(vec![point, first, second]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dash_pattern() {
+        let points = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)];
+        let result = dashes(&points, false, &[4.0, 2.0], 0.0);
+        let lengths: Vec<f64> = result
+            .iter()
+            .map(|dash| {
+                dash.windows(2)
+                    .map(|pair| (pair[1].0 - pair[0].0).hypot(pair[1].1 - pair[0].1))
+                    .sum()
+            })
+            .collect();
+        assert_eq!(lengths, vec![4.0, 4.0, 4.0, 2.0]);
+        // dash crossing the corner keeps the vertex
+        assert_eq!(result[1], vec![(6.0, 0.0), (10.0, 0.0)]);
+        assert_eq!(result[2], vec![(10.0, 2.0), (10.0, 6.0)]);
+
+        let result = dashes(&points, false, &[4.0, 2.0], 5.0);
+        assert_eq!(result[0], vec![(1.0, 0.0), (5.0, 0.0)]);
+    }
+
+    #[test]
+    fn dense_dashes() {
+        let style = |dash: Vec<f64>| StrokeStyle {
+            width: 2.0,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            miter_limit: 10.0,
+            dash,
+            dash_offset: 0.0,
+        };
+        let mut path = Path::default();
+        let transform = Transform::IDENTITY;
+        path.move_to(&transform, 0.0, 0.0);
+        path.line_to(&transform, 1000.0, 0.0);
+
+        // patterns too short or too dense for the line are stroked solid
+        for dash in [vec![1e-7, 1e-7], vec![1e-3, 1e-3]] {
+            let polygons = stroke(&path, &style(dash), &transform);
+            assert_eq!(polygons.len(), 1);
+        }
+        let polygons = stroke(&path, &style(vec![1.0, 1.0]), &transform);
+        assert_eq!(polygons.len(), 500);
+    }
+
+    #[test]
+    fn joins() {
+        let style = StrokeStyle {
+            width: 2.0,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            miter_limit: 10.0,
+            dash: Vec::new(),
+            dash_offset: 0.0,
+        };
+        let mut path = Path::default();
+        let transform = Transform::IDENTITY;
+        path.move_to(&transform, 0.0, 0.0);
+        path.line_to(&transform, 10.0, 0.0);
+        path.line_to(&transform, 10.0, 10.0);
+
+        let polygons = stroke(&path, &style, &transform);
+        // two segments and miter join reaching the outer corner
+        let is_tip = |&(x, y): &Point| (x - 11.0).abs() < 1e-9 && (y + 1.0).abs() < 1e-9;
+        assert_eq!(polygons.len(), 3);
+        assert!(polygons[2].iter().any(is_tip));
+
+        let style = StrokeStyle {
+            join: LineJoin::Bevel,
+            ..style
+        };
+        let polygons = stroke(&path, &style, &transform);
+        assert_eq!(polygons[2].len(), 3);
+        assert!(!polygons[2].iter().any(is_tip));
+    }
+}
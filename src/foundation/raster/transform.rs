@@ -0,0 +1,139 @@
+/// Represents 2D affine transformation matrix like canvas `setTransform(a, b, c, d, e, f)`.
+///
+/// Point `(x, y)` is mapped to `(a * x + c * y + e, b * x + d * y + f)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform {
+    /// Horizontal scaling
+    pub a: f64,
+    /// Vertical skewing
+    pub b: f64,
+    /// Horizontal skewing
+    pub c: f64,
+    /// Vertical scaling
+    pub d: f64,
+    /// Horizontal translation
+    pub e: f64,
+    /// Vertical translation
+    pub f: f64,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform {
+    /// Transformation keeping points in place
+    pub const IDENTITY: Transform = Transform::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    /// Create transformation with matrix components
+    pub const fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+
+    /// Create translation
+    pub const fn translation(x: f64, y: f64) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    /// Create scaling
+    pub const fn scaling(x: f64, y: f64) -> Self {
+        Self::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Create clockwise rotation by angle in radians, as y axis points down
+    pub fn rotation(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Combine transformations, the result applies this transformation first and then next
+    pub fn then(&self, next: &Transform) -> Transform {
+        Transform::new(
+            self.a * next.a + self.b * next.c,
+            self.a * next.b + self.b * next.d,
+            self.c * next.a + self.d * next.c,
+            self.c * next.b + self.d * next.d,
+            self.e * next.a + self.f * next.c + next.e,
+            self.e * next.b + self.f * next.d + next.f,
+        )
+    }
+
+    /// Map point
+    #[inline]
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+
+    /// Map vector, ignoring translation
+    #[inline]
+    pub fn apply_vector(&self, x: f64, y: f64) -> (f64, f64) {
+        (self.a * x + self.c * y, self.b * x + self.d * y)
+    }
+
+    /// Retrieve determinant of linear part
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Retrieve inverse transformation, or `None` when transformation is singular
+    pub fn inverse(&self) -> Option<Transform> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let (a, b, c, d) = (self.d / det, -self.b / det, -self.c / det, self.a / det);
+        Some(Transform::new(
+            a,
+            b,
+            c,
+            d,
+            -(a * self.e + c * self.f),
+            -(b * self.e + d * self.f),
+        ))
+    }
+
+    /// Retrieve the largest factor by which lengths are scaled
+    pub fn max_scale(&self) -> f64 {
+        // square root of the largest eigenvalue of M^T * M
+        let p = self.a * self.a + self.b * self.b;
+        let q = self.c * self.c + self.d * self.d;
+        let r = self.a * self.c + self.b * self.d;
+        let half_sum = (p + q) / 2.0;
+        let root = (((p - q) / 2.0).powi(2) + r * r).sqrt();
+        (half_sum + root).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_near(left: (f64, f64), right: (f64, f64)) {
+        assert!(
+            (left.0 - right.0).abs() < 1e-9 && (left.1 - right.1).abs() < 1e-9,
+            "{:?} != {:?}",
+            left,
+            right
+        );
+    }
+
+    #[test]
+    fn compose_and_invert() {
+        let transform = Transform::scaling(2.0, 3.0)
+            .then(&Transform::rotation(std::f64::consts::FRAC_PI_2))
+            .then(&Transform::translation(10.0, 0.0));
+        assert_near(transform.apply(1.0, 0.0), (10.0, 2.0));
+        assert_near(transform.apply(0.0, 1.0), (7.0, 0.0));
+
+        let inverse = transform.inverse().unwrap();
+        assert_near(inverse.apply(10.0, 2.0), (1.0, 0.0));
+        assert!((transform.max_scale() - 3.0).abs() < 1e-9);
+        assert_eq!(Transform::scaling(0.0, 1.0).inverse(), None);
+    }
+}
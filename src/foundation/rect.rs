@@ -11,6 +11,9 @@ use crate::prelude::{One, Zero};
 
 use super::{Box2D, Point, Size};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// A 2d Rectangle optionally tagged with a unit.
 ///
 /// # Representation
@@ -815,4 +818,21 @@ mod tests {
 
     //     assert_eq!(r1.intersection(&r2), None);
     // }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use crate::foundation::{Vector2D, Vector3D};
+
+        let r = Rect::new(Point::new(1.0, 2.0), Size::new(30.0, 40.5));
+        let json = serde_json::to_string(&r).unwrap();
+        assert_eq!(json, r#"{"origin":[1.0,2.0],"size":[30.0,40.5]}"#);
+        assert_eq!(serde_json::from_str::<Rect<f64>>(&json).unwrap(), r);
+
+        let v = Vector2D::new(-1, 7);
+        assert_eq!(serde_json::from_str::<Vector2D<i32>>(&serde_json::to_string(&v).unwrap()).unwrap(), v);
+
+        let v = Vector3D::new(1.5f32, 0.0, -2.0);
+        assert_eq!(serde_json::from_str::<Vector3D<f32>>(&serde_json::to_string(&v).unwrap()).unwrap(), v);
+    }
 }
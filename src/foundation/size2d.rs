@@ -39,11 +39,7 @@ where
         D: serde::Deserializer<'de>,
     {
         let (width, height) = serde::Deserialize::deserialize(deserializer)?;
-        Ok(Size2D {
-            width,
-            height,
-            _unit: PhantomData,
-        })
+        Ok(Size2D { width, height })
     }
 }
 
@@ -68,11 +64,7 @@ where
 {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
         let (width, height) = arbitrary::Arbitrary::arbitrary(u)?;
-        Ok(Size2D {
-            width,
-            height,
-            _unit: PhantomData,
-        })
+        Ok(Size2D { width, height })
     }
 }
 
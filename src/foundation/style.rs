@@ -4,6 +4,7 @@ use crate::foundation::colorspace::Color;
 
 /// Represents radial gradient specified by six parameters
 #[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RadialGradient {
     /// Represents first point x position of gradient
     pub x0: f64,
@@ -36,6 +37,7 @@ impl RadialGradient {
 /// Represents radial gradient specified by four parameters
 
 #[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinearGradient {
     /// Represents first point x position of gradient
     pub x0: f64,
@@ -57,6 +59,7 @@ impl LinearGradient {
 
 /// Define the an offset and a color, to a given canvas gradient. 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorStop {
     /// Reperesent the offset of color stop
     pub offset: f64,
@@ -73,6 +76,7 @@ impl ColorStop {
 
 /// Define the gradient type with parameters
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GradientType {
     /// Linear gradient
     Linear(LinearGradient),
@@ -88,6 +92,7 @@ impl Default for GradientType {
 
 /// Represents an opaque object describing a gradient.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Gradient {
     /// Kind of gradient
     pub kind: GradientType,
@@ -131,3 +136,24 @@ impl Default for Gradient {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gradient_serde() {
+        let gradient = Gradient::new(GradientType::Radial(RadialGradient::new(
+            0.0, 0.0, 10.0, 5.0, 5.0, 50.0,
+        )));
+        gradient.add_color_stop(ColorStop::new(0.0, Color::rgb(255, 0, 0)));
+        gradient.add_color_stop(ColorStop::new(1.0, Color::new(0.0, 0.0, 1.0, 0.5)));
+
+        let json = serde_json::to_string(&gradient).unwrap();
+        let restored: Gradient = serde_json::from_str(&json).unwrap();
+
+        assert!(matches!(restored.kind, GradientType::Radial(RadialGradient { r1, .. }) if r1 == 50.0));
+        assert_eq!(restored.color_count(), 2);
+        assert_eq!(restored.get_color_stop(1).unwrap().color, Color::new(0.0, 0.0, 1.0, 0.5));
+    }
+}
@@ -1,6 +1,7 @@
 /// The font-weight property sets how thick or thin characters in text should be displayed
 ///
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontWeight {
     /// Thin text weight 
     Thin = 100,
@@ -37,6 +38,7 @@ impl Default for FontWeight {
 /// Whether to slant the glyphs in the font.
 ///
 #[derive(Clone, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontStyle {
     /// Normal font
     Normal = 0,
@@ -56,6 +58,7 @@ impl Default for FontStyle {
 /// 
 /// see <https://developer.mozilla.org/ru/docs/Web/API/Canvas_API/Tutorial/Drawing_text>
 #[derive(Clone, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BaseLine {
     /// Top baseline
     Top,
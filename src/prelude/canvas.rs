@@ -8,6 +8,7 @@ use crate::{
 /// Specifies the current text direction used to draw text
 ///
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     /// Left to right text direction
     Ltr,
@@ -20,6 +21,7 @@ pub enum Direction {
 /// Represents the dimensions of a piece of text in the canvas
 ///
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextMetrics {
     /// Text width
     pub width: f64,
@@ -30,6 +32,7 @@ pub struct TextMetrics {
 /// Specifies how the ends of the drawn lines will look.
 ///
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LineCap {
     /// Butt line cap
     Butt,
@@ -48,6 +51,7 @@ impl Default for LineCap {
 /// Specifies the shape of the vertices at which the lines converge
 ///
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LineJoin {
     /// Miter line join
     Miter,
@@ -67,6 +71,7 @@ impl Default for LineJoin {
 ///
 #[allow(dead_code)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PatternExtend {
     /// None pattern extend
     None,
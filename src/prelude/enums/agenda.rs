@@ -5,6 +5,7 @@ use std::any::TypeId;
 /// Can be extended with Custom by using concrete project values.
 ///
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Agenda {
     /// The default Agenda.  Anything assigned to this will be run each update irrespective of what agenda the parent is assigned.
     Always,
@@ -20,6 +21,9 @@ pub enum Agenda {
     Defend,
 
     /// Allows Agenda to be extended (e.g. for using entity specific enumerated agendas).
+    ///
+    /// Is not serializable as `TypeId` is not stable between builds.
+    #[cfg_attr(feature = "serde", serde(skip))]
     Custom {
         /// Custom value
         value: TypeId,
@@ -1,6 +1,7 @@
 /// Representing the touch input modes for a Touchscreen Joypad for use in the `Joypad` virtual controller.
 ///
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JoypadTouch {
     /// Touch input is disabled for joypad.  The default.
     /// It is advised that [JoypadTouch] be set in Factory based on device interrogation.
@@ -1,6 +1,7 @@
 /// These messages are dispatched internally.
 ///
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MessageKind {
     /// Dispose message type
     Dispose,
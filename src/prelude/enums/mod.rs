@@ -36,3 +36,20 @@ pub use self::text_align::*;
 
 mod text_style;
 pub use self::text_style::*;
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serde_roundtrip() {
+        let scene = SceneKind::Custom { value: 42 };
+        let json = serde_json::to_string(&scene).unwrap();
+        assert_eq!(json, r#"{"Custom":{"value":42}}"#);
+        assert_eq!(serde_json::from_str::<SceneKind>(&json).unwrap(), scene);
+
+        let agenda: Agenda = serde_json::from_str(r#""Attack""#).unwrap();
+        assert_eq!(agenda, Agenda::Attack);
+        assert!(serde_json::to_string(&Agenda::Custom { value: std::any::TypeId::of::<u8>() }).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 /// Representing the buttons on a Mouse for use in the InputMouse virtual controller.
 ///
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseButton {
     /// Left mouse button
     Left,
@@ -1,6 +1,7 @@
 /// Representing the mouse cursor for use in the `InputMouse` virtual controller.
 ///
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseCursor {
     /// Arrow mouse cursor
     Arrow,
@@ -1,6 +1,7 @@
 /// Representing the control buttons on the `Overlay`.
 ///
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OverlayButton {
     /// Back overlay button
     Back,
@@ -2,6 +2,7 @@
 /// `TextStyle` implementations and layout of multiple lines of text
 ///
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextAlign {
     /// Justified text
     Justify,
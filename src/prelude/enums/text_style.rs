@@ -3,6 +3,7 @@
 /// Can be extended with Custom by using concrete project values.
 ///
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextStyleKind {
     /// Button text style
     Button,
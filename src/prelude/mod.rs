@@ -32,6 +32,8 @@ pub use crate::foundation::colorspace::{
     prelude::*
 };
 
+pub use crate::foundation::colorschemes::ColorScheme;
+
 /// Creates a structure with zero values.
 ///
 /// This interface is obsolete.